@@ -28,10 +28,25 @@ pub struct InternalMetrics {
     latency_ms: u64,
 }
 
+// This type is collected into the `billing` group, which can be exported on its own with
+// `specta::collect_group("billing")`. It is still included in `specta::collect()`.
+#[derive(Type)]
+#[specta(collect = "billing")]
+pub struct Invoice {
+    id: String,
+    customer: User,
+    amount_cents: u32,
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let output = Typescript::default().export(&specta::collect(), specta_serde::Format)?;
 
     println!("{output}");
 
+    let billing =
+        Typescript::default().export(&specta::collect_group("billing"), specta_serde::Format)?;
+
+    println!("{billing}");
+
     Ok(())
 }
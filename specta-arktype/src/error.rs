@@ -0,0 +1,358 @@
+use std::{borrow::Cow, error, fmt, io, panic::Location, path::PathBuf};
+
+use specta::datatype::{Literal, OpaqueReference};
+
+use crate::Layout;
+
+/// The error type for the ArkType exporter.
+#[non_exhaustive]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+type FrameworkSource = Box<dyn error::Error + Send + Sync + 'static>;
+
+#[allow(dead_code)]
+enum ErrorKind {
+    InvalidMapKey {
+        path: String,
+        reason: Cow<'static, str>,
+    },
+    BigIntForbidden {
+        path: String,
+    },
+    InvalidName {
+        path: String,
+        name: Cow<'static, str>,
+    },
+    ForbiddenName {
+        path: String,
+        name: Cow<'static, str>,
+    },
+    DuplicateTypeName {
+        name: Cow<'static, str>,
+        first: String,
+        second: String,
+    },
+    DuplicateExportName(String),
+    Io(io::Error),
+    ReadDir {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Metadata {
+        path: PathBuf,
+        source: io::Error,
+    },
+    RemoveFile {
+        path: PathBuf,
+        source: io::Error,
+    },
+    RemoveDir {
+        path: PathBuf,
+        source: io::Error,
+    },
+    UnsupportedOpaqueReference(OpaqueReference),
+    UnsupportedLiteral {
+        path: String,
+        literal: Literal,
+        reason: &'static str,
+    },
+    DanglingNamedReference {
+        reference: String,
+    },
+    Framework {
+        message: Cow<'static, str>,
+        source: FrameworkSource,
+    },
+    UnableToExport(Layout),
+    Unsupported {
+        path: String,
+        construct: &'static str,
+    },
+}
+
+impl Error {
+    /// Construct an error for framework-specific logic.
+    pub fn framework(
+        message: impl Into<Cow<'static, str>>,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::Framework {
+                message: message.into(),
+                source: source.into(),
+            },
+        }
+    }
+
+    pub(crate) fn bigint_forbidden(path: String) -> Self {
+        Self {
+            kind: ErrorKind::BigIntForbidden { path },
+        }
+    }
+
+    pub(crate) fn invalid_map_key(
+        path: impl Into<String>,
+        reason: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::InvalidMapKey {
+                path: path.into(),
+                reason: reason.into(),
+            },
+        }
+    }
+
+    pub(crate) fn invalid_name(path: String, name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            kind: ErrorKind::InvalidName {
+                path,
+                name: name.into(),
+            },
+        }
+    }
+
+    pub(crate) fn forbidden_name(path: String, name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            kind: ErrorKind::ForbiddenName {
+                path,
+                name: name.into(),
+            },
+        }
+    }
+
+    pub(crate) fn duplicate_type_name(
+        name: Cow<'static, str>,
+        first: Location<'static>,
+        second: Location<'static>,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::DuplicateTypeName {
+                name,
+                first: format_location(first),
+                second: format_location(second),
+            },
+        }
+    }
+
+    pub(crate) fn duplicate_export_name(name: String) -> Self {
+        Self {
+            kind: ErrorKind::DuplicateExportName(name),
+        }
+    }
+
+    pub(crate) fn read_dir(path: PathBuf, source: io::Error) -> Self {
+        Self {
+            kind: ErrorKind::ReadDir { path, source },
+        }
+    }
+
+    pub(crate) fn metadata(path: PathBuf, source: io::Error) -> Self {
+        Self {
+            kind: ErrorKind::Metadata { path, source },
+        }
+    }
+
+    pub(crate) fn remove_file(path: PathBuf, source: io::Error) -> Self {
+        Self {
+            kind: ErrorKind::RemoveFile { path, source },
+        }
+    }
+
+    pub(crate) fn remove_dir(path: PathBuf, source: io::Error) -> Self {
+        Self {
+            kind: ErrorKind::RemoveDir { path, source },
+        }
+    }
+
+    pub(crate) fn unsupported_literal(
+        path: String,
+        literal: Literal,
+        reason: &'static str,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::UnsupportedLiteral {
+                path,
+                literal,
+                reason,
+            },
+        }
+    }
+
+    pub(crate) fn unsupported_opaque_reference(reference: OpaqueReference) -> Self {
+        Self {
+            kind: ErrorKind::UnsupportedOpaqueReference(reference),
+        }
+    }
+
+    pub(crate) fn dangling_named_reference(reference: String) -> Self {
+        Self {
+            kind: ErrorKind::DanglingNamedReference { reference },
+        }
+    }
+
+    pub(crate) fn unable_to_export(layout: Layout) -> Self {
+        Self {
+            kind: ErrorKind::UnableToExport(layout),
+        }
+    }
+
+    pub(crate) fn unsupported(path: String, construct: &'static str) -> Self {
+        Self {
+            kind: ErrorKind::Unsupported { path, construct },
+        }
+    }
+
+    pub(crate) fn format(
+        message: impl Into<Cow<'static, str>>,
+        source: specta::FormatError,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::Framework {
+                message: message.into(),
+                source,
+            },
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self {
+            kind: ErrorKind::Io(error),
+        }
+    }
+}
+
+impl From<std::fmt::Error> for Error {
+    fn from(source: std::fmt::Error) -> Self {
+        Self {
+            kind: ErrorKind::Framework {
+                message: Cow::Borrowed("Formatting error"),
+                source: Box::new(source),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::InvalidMapKey { path, reason } => {
+                write!(f, "Invalid map key at '{path}': {reason}")
+            }
+            ErrorKind::BigIntForbidden { path } => write!(
+                f,
+                "Attempted to export {path:?} but Specta forbids exporting BigInt-style types (usize, isize, i64, u64, i128, u128, f128) to avoid precision loss. Remap them to an ArkType schema such as `specta_arktype::define(\"type('string')\")` to override this."
+            ),
+            ErrorKind::InvalidName { path, name } => write!(
+                f,
+                "Attempted to export {path:?} but was unable to due to name {name:?} containing an invalid character. Try renaming it or using `#[specta(rename = \"new_name\")]`"
+            ),
+            ErrorKind::ForbiddenName { path, name } => write!(
+                f,
+                "Attempted to export {path:?} but was unable to due to name {name:?} being a reserved keyword in TypeScript. Try renaming it or using `#[specta(rename = \"new_name\")]`"
+            ),
+            ErrorKind::DuplicateTypeName {
+                name,
+                first,
+                second,
+            } => write!(
+                f,
+                "Detected multiple types with the same name: {name:?} at {first} and {second}"
+            ),
+            ErrorKind::DuplicateExportName(name) => write!(
+                f,
+                "Detected multiple namespace exports with the name {name:?}"
+            ),
+            ErrorKind::Io(err) => write!(f, "IO error: {err}"),
+            ErrorKind::ReadDir { path, source } => {
+                write!(f, "Failed to read directory '{}': {source}", path.display())
+            }
+            ErrorKind::Metadata { path, source } => {
+                write!(
+                    f,
+                    "Failed to read metadata for '{}': {source}",
+                    path.display()
+                )
+            }
+            ErrorKind::RemoveFile { path, source } => {
+                write!(f, "Failed to remove file '{}': {source}", path.display())
+            }
+            ErrorKind::RemoveDir { path, source } => {
+                write!(
+                    f,
+                    "Failed to remove directory '{}': {source}",
+                    path.display()
+                )
+            }
+            ErrorKind::UnsupportedLiteral {
+                path,
+                literal,
+                reason,
+            } => write!(
+                f,
+                "Attempted to export {path:?} but the literal {literal:?} can't be represented by the ArkType exporter as {reason}."
+            ),
+            ErrorKind::UnsupportedOpaqueReference(reference) => write!(
+                f,
+                "Found unsupported opaque reference '{}'. It is not supported by the ArkType exporter.",
+                reference.type_name()
+            ),
+            ErrorKind::DanglingNamedReference { reference } => write!(
+                f,
+                "Found dangling named reference {reference}. The referenced type is missing from the resolved type collection."
+            ),
+            ErrorKind::Framework { message, source } => {
+                let source = source.to_string();
+                if message.is_empty() && source.is_empty() {
+                    write!(f, "Framework error")
+                } else if source.is_empty() {
+                    write!(f, "Framework error: {message}")
+                } else {
+                    write!(f, "Framework error: {message}: {source}")
+                }
+            }
+            ErrorKind::UnableToExport(layout) => {
+                write!(
+                    f,
+                    "Unable to export layout {layout} with `ArkType::export`. Use `ArkType::export_to` or change layout."
+                )
+            }
+            ErrorKind::Unsupported { path, construct } => write!(
+                f,
+                "Attempted to export {path:?} but ArkType has no way to express {construct}. Change the type or remap it with `specta_arktype::define`."
+            ),
+        }
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(error) => Some(error),
+            ErrorKind::ReadDir { source, .. }
+            | ErrorKind::Metadata { source, .. }
+            | ErrorKind::RemoveFile { source, .. }
+            | ErrorKind::RemoveDir { source, .. } => Some(source),
+            ErrorKind::Framework { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+fn format_location(location: Location<'static>) -> String {
+    format!(
+        "{}:{}:{}",
+        location.file(),
+        location.line(),
+        location.column()
+    )
+}
@@ -7,8 +7,8 @@ use std::{
 use specta::{
     Format, Types,
     datatype::{
-        DataType, Deprecated, Field, Fields, NamedDataType, NamedReference, NamedReferenceType,
-        Primitive, Reference, Variant,
+        Attributes, DataType, Deprecated, Field, Fields, Literal, LiteralValue, NamedDataType,
+        NamedReference, NamedReferenceType, Primitive, Reference, Variant,
     },
 };
 
@@ -417,7 +417,7 @@ fn render_fields(
                 let Some(ty) = field.ty.as_ref() else {
                     continue;
                 };
-                let mut property = property_name(wire_name);
+                let mut property = property_name(wire_name, &field.attributes);
                 if !used.insert(property.clone()) {
                     let base = property.clone();
                     let mut suffix = 2;
@@ -1006,6 +1006,11 @@ fn render_simple_enum(
     out.push_str(exporter.visibility.keyword());
     out.push_str(" enum ");
     out.push_str(name);
+    let numeric = numeric_enum_type(enm);
+    if let Some(underlying) = numeric {
+        out.push_str(" : ");
+        out.push_str(underlying);
+    }
     out.push('\n');
     out.push_str(base);
     out.push_str("{\n");
@@ -1015,9 +1020,15 @@ fn render_simple_enum(
     for (wire_name, variant) in enm.variants.iter().filter(|(_, variant)| !variant.skip) {
         xml_docs(out, &indent, &variant.docs);
         obsolete(out, &indent, variant.deprecated.as_ref());
-        let member = unique_identifier(property_name(wire_name), &mut used);
+        let member = unique_identifier(property_name(wire_name, &variant.attributes), &mut used);
         out.push_str(&indent);
         out.push_str(&member);
+        if numeric.is_some()
+            && let Some(discriminant) = variant.attributes.discriminant()
+        {
+            out.push_str(" = ");
+            out.push_str(&discriminant_value(discriminant));
+        }
         out.push_str(",\n");
         mappings.push((wire_name, member));
     }
@@ -1136,8 +1147,11 @@ fn render_union(
     );
     let reserved_type_names = reserved_type_names(exporter, types);
     for (wire_name, variant) in enm.variants.iter().filter(|(_, variant)| !variant.skip) {
-        let variant_name =
-            unique_type_identifier(property_name(wire_name), &mut used, &reserved_type_names);
+        let variant_name = unique_type_identifier(
+            property_name(wire_name, &variant.attributes),
+            &mut used,
+            &reserved_type_names,
+        );
         render_variant(
             out,
             exporter,
@@ -1354,6 +1368,38 @@ fn is_simple_enum(enm: &specta::datatype::Enum) -> bool {
         .iter()
         .all(|(_, variant)| matches!(variant.fields, Fields::Unit))
         || is_rewritten_string_enum(enm)
+        || numeric_enum_type(enm).is_some()
+}
+
+fn discriminant_value(discriminant: &Literal) -> String {
+    match discriminant.value() {
+        LiteralValue::Int(value) => value.to_string(),
+        LiteralValue::UInt(value) => value.to_string(),
+        _ => unreachable!("numeric enums only have integer discriminants"),
+    }
+}
+
+/// The underlying type of an enum whose variants all carry a discriminant, like `serde_repr`
+/// enums. `System.Text.Json` already reads and writes these enums as numbers.
+fn numeric_enum_type(enm: &specta::datatype::Enum) -> Option<&'static str> {
+    let mut variants = enm.variants.iter().filter(|(_, variant)| !variant.skip);
+    let primitive = variants.next()?.1.attributes.discriminant()?.primitive();
+    if !variants.all(|(_, variant)| variant.attributes.discriminant().is_some()) {
+        return None;
+    }
+    match primitive {
+        Primitive::isize => Some("long"),
+        Primitive::usize => Some("ulong"),
+        Primitive::i8
+        | Primitive::i16
+        | Primitive::i32
+        | Primitive::i64
+        | Primitive::u8
+        | Primitive::u16
+        | Primitive::u32
+        | Primitive::u64 => Some(primitive_name(&primitive)),
+        _ => None,
+    }
 }
 
 fn is_rewritten_string_enum(enm: &specta::datatype::Enum) -> bool {
@@ -2227,8 +2273,11 @@ fn identifier(name: &str, path: &str) -> Result<String, Error> {
     })
 }
 
-fn property_name(name: &str) -> String {
-    let name = pascal_case(name);
+/// The C# member name for a field or variant, preferring `#[specta(rename = "...")]` over PascalCase.
+fn property_name(name: &str, attributes: &Attributes) -> String {
+    let name = attributes
+        .rename()
+        .map_or_else(|| pascal_case(name), str::to_string);
     identifier(&name, "property").unwrap_or_else(|_| {
         let mut value = String::from("Field");
         for ch in name.chars() {
@@ -11,8 +11,8 @@ use std::{
 use specta::{
     Types,
     datatype::{
-        DataType, Deprecated, Enum, Fields, Generic, NamedDataType, NamedReferenceType, Primitive,
-        Reference, Struct,
+        DataType, Deprecated, Enum, Field, Fields, Generic, Literal, LiteralValue, NamedDataType,
+        NamedReferenceType, Primitive, Reference, Struct, Variant,
     },
 };
 use unicode_general_category::{GeneralCategory, get_general_category};
@@ -45,12 +45,19 @@ pub fn export<'a>(
         }
         if ndt.generics.is_empty()
             && let Some(DataType::Enum(enm)) = &ndt.ty
-            && let Some(variants) = string_enum_variants(enm)
+            && let Some(variants) = string_enum_variants(enm).or_else(|| {
+                numeric_enum_variants(enm).map(|(_, variants)| {
+                    variants
+                        .into_iter()
+                        .map(|(name, variant, _)| (name, variant))
+                        .collect()
+                })
+            })
         {
-            for (index, (variant, _)) in variants.into_iter().enumerate() {
+            for (index, (value, variant)) in variants.into_iter().enumerate() {
                 let constant = format!(
                     "{name}{}",
-                    enum_constant_suffix(variant, index, &rust_type_path(ndt))
+                    enum_constant_suffix(value, variant, index, &rust_type_path(ndt))
                 );
                 if !names.insert(constant.clone()) {
                     return Err(Error::DuplicateName {
@@ -137,9 +144,27 @@ fn named_datatype(
 
     if ndt.generics.is_empty()
         && let DataType::Enum(enm) = ty
-        && string_enum_variants(enm).is_some()
+        && let Some(variants) = string_enum_variants(enm)
+    {
+        let constants = variants
+            .into_iter()
+            .map(|(value, variant)| (value, variant, format!("\"{}\"", escape_go_string(value))))
+            .collect();
+        render_enum_constants(&mut out, &name, "string", ndt, constants)?;
+        return Ok(out);
+    }
+
+    if ndt.generics.is_empty()
+        && let DataType::Enum(enm) = ty
+        && let Some((primitive, variants)) = numeric_enum_variants(enm)
     {
-        render_string_enum(&mut out, &name, ndt, enm)?;
+        let mut ctx = Context::default();
+        let underlying = primitive_type(&primitive, &mut ctx);
+        let constants = variants
+            .into_iter()
+            .map(|(name, variant, discriminant)| (name, variant, discriminant_value(discriminant)))
+            .collect();
+        render_enum_constants(&mut out, &name, &underlying, ndt, constants)?;
         return Ok(out);
     }
 
@@ -243,23 +268,22 @@ fn datatype_relies_on_json_methods(
     }
 }
 
-fn render_string_enum(
+fn render_enum_constants(
     out: &mut String,
     name: &str,
+    underlying: &str,
     ndt: &NamedDataType,
-    enm: &Enum,
+    constants: Vec<(&str, &specta::datatype::Variant, String)>,
 ) -> Result<(), Error> {
     out.push_str("type ");
     out.push_str(name);
-    out.push_str(" string\n\nconst (\n");
+    out.push(' ');
+    out.push_str(underlying);
+    out.push_str("\n\nconst (\n");
     let mut names = BTreeSet::new();
     let mut rows = Vec::new();
-    for (index, (variant, metadata)) in string_enum_variants(enm)
-        .expect("checked string enum")
-        .into_iter()
-        .enumerate()
-    {
-        let variant_name = enum_constant_suffix(variant, index, &rust_type_path(ndt));
+    for (index, (variant, metadata, value)) in constants.into_iter().enumerate() {
+        let variant_name = enum_constant_suffix(variant, metadata, index, &rust_type_path(ndt));
         let constant = format!("{name}{variant_name}");
         if !names.insert(constant.clone()) {
             return Err(Error::DuplicateName {
@@ -279,11 +303,7 @@ fn render_string_enum(
             push_aligned_rows(out, &mut rows);
             out.push_str(&comments);
         }
-        rows.push((
-            constant,
-            name.to_string(),
-            format!("= \"{}\"", escape_go_string(variant)),
-        ));
+        rows.push((constant, name.to_string(), format!("= {value}")));
     }
     push_aligned_rows(out, &mut rows);
     out.push_str(")\n");
@@ -341,6 +361,9 @@ fn render_datatype(
         },
         DataType::Struct(strct) => render_struct(exporter, types, strct, generics, path, ctx)?,
         DataType::Enum(enm) if string_enum_variants(enm).is_some() => "string".into(),
+        DataType::Enum(enm) if let Some((primitive, _)) = numeric_enum_variants(enm) => {
+            primitive_type(&primitive, ctx)
+        }
         // Go has no structural union type. `any` is the only type that accepts
         // every externally, internally, adjacently, and untagged Serde shape.
         DataType::Enum(_) => "any".into(),
@@ -421,7 +444,7 @@ fn render_struct(
                         ),
                     });
                 }
-                let field_name = field_name(json_name, index, &path.join("."))?;
+                let field_name = field_name(json_name, field, index, &path.join("."))?;
                 if !names.insert(field_name.clone()) {
                     return Err(Error::DuplicateName {
                         path: path.join("."),
@@ -800,7 +823,9 @@ fn map_key_is_valid_inner(types: &Types, dt: &DataType, visited: &mut BTreeSet<S
             | Primitive::usize,
         ) => true,
         DataType::Generic(_) => false,
-        DataType::Enum(enm) => string_enum_variants(enm).is_some(),
+        DataType::Enum(enm) => {
+            string_enum_variants(enm).is_some() || numeric_enum_variants(enm).is_some()
+        }
         DataType::Struct(strct) => match &strct.fields {
             Fields::Unnamed(fields) => matches!(
                 fields.fields.as_slice(),
@@ -1012,6 +1037,34 @@ fn string_enum_variants(enm: &Enum) -> Option<Vec<(&str, &specta::datatype::Vari
     (!variants.is_empty()).then_some(variants)
 }
 
+/// The variants of an enum which all carry a discriminant, like `serde_repr` enums, along with
+/// the integer type of the discriminants.
+#[allow(clippy::type_complexity)]
+fn numeric_enum_variants(
+    enm: &Enum,
+) -> Option<(Primitive, Vec<(&str, &specta::datatype::Variant, &Literal)>)> {
+    let variants = enm
+        .variants
+        .iter()
+        .filter(|(_, variant)| !variant.skip)
+        .map(|(name, variant)| {
+            let discriminant = variant.attributes.discriminant()?;
+            Some((name.as_ref(), variant, discriminant))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let primitive = variants.first()?.2.primitive();
+    // `*big.Int` can't be a constant.
+    (!matches!(primitive, Primitive::i128 | Primitive::u128)).then_some((primitive, variants))
+}
+
+fn discriminant_value(discriminant: &Literal) -> String {
+    match discriminant.value() {
+        LiteralValue::Int(value) => value.to_string(),
+        LiteralValue::UInt(value) => value.to_string(),
+        _ => unreachable!("numeric enums only have integer discriminants"),
+    }
+}
+
 fn can_be_nil(ty: &str) -> bool {
     ty == "any"
         || ty.starts_with('*')
@@ -1158,7 +1211,8 @@ pub(crate) fn is_go_decimal_digit(ch: char) -> bool {
     get_general_category(ch) == GeneralCategory::DecimalNumber
 }
 
-fn field_name(name: &str, index: usize, path: &str) -> Result<String, Error> {
+fn field_name(name: &str, field: &Field, index: usize, path: &str) -> Result<String, Error> {
+    let name = field.attributes.rename().unwrap_or(name);
     if name.is_empty() {
         return Err(Error::InvalidName {
             path: path.into(),
@@ -1168,8 +1222,9 @@ fn field_name(name: &str, index: usize, path: &str) -> Result<String, Error> {
     Ok(exported_name(name, path).unwrap_or_else(|_| format!("Field{}", index + 1)))
 }
 
-fn enum_constant_suffix(value: &str, index: usize, path: &str) -> String {
-    exported_name(value, path).unwrap_or_else(|_| format!("Value{}", index + 1))
+fn enum_constant_suffix(value: &str, variant: &Variant, index: usize, path: &str) -> String {
+    exported_name(variant.attributes.rename().unwrap_or(value), path)
+        .unwrap_or_else(|_| format!("Value{}", index + 1))
 }
 
 fn to_snake_case(name: &str) -> String {
@@ -7,8 +7,8 @@ use std::{
 use specta::{
     Types,
     datatype::{
-        DataType, Deprecated, Enum, Field, Fields, Generic, NamedDataType, NamedReferenceType,
-        Primitive, Reference, Struct,
+        DataType, Deprecated, Enum, Field, Fields, Generic, LiteralValue, NamedDataType,
+        NamedReferenceType, Primitive, Reference, Struct,
     },
 };
 
@@ -243,7 +243,9 @@ fn declaration(
         }
         DataType::Enum(value)
             if ndt.generics.is_empty()
-                && (is_unit_enum(value) || resolved_string_enum(value).is_some()) =>
+                && (is_unit_enum(value)
+                    || resolved_string_enum(value).is_some()
+                    || resolved_numeric_enum(value).is_some()) =>
         {
             render_unit_enum(&mut out, visibility, &name, value, &name)?
         }
@@ -303,7 +305,17 @@ fn render_unit_enum(
     path: &str,
 ) -> Result<(), Error> {
     writeln!(out, "{visibility}enum {name} {{").expect("writing to String cannot fail");
-    let raw_values = resolved_string_enum(value);
+    let raw_values = resolved_string_enum(value)
+        .map(|raw_values| {
+            (
+                "java.lang.String",
+                raw_values
+                    .into_iter()
+                    .map(|(_, raw)| format!("\"{}\"", escape_java_string(raw)))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .or_else(|| resolved_numeric_enum(value));
     let variants = value
         .variants
         .iter()
@@ -330,10 +342,8 @@ fn render_unit_enum(
         }
         let variant_name = value_identifier(variant_name, &format!("{path}.{variant_name}"))?;
         write!(out, "    {variant_name}").expect("writing to String cannot fail");
-        if let Some(raw_values) = &raw_values {
-            let raw_value = raw_values[index].1;
-            write!(out, "(\"{}\")", escape_java_string(raw_value))
-                .expect("writing to String cannot fail");
+        if let Some((_, raw_values)) = &raw_values {
+            write!(out, "({})", raw_values[index]).expect("writing to String cannot fail");
         }
         if index + 1 != variants.len() {
             out.push(',');
@@ -342,19 +352,16 @@ fn render_unit_enum(
         }
         out.push('\n');
     }
-    if raw_values.is_some() {
-        writeln!(
-            out,
-            "\n    private final java.lang.String {backing_name};\n"
-        )
-        .expect("writing to String cannot fail");
-        writeln!(out, "    {name}(java.lang.String value) {{")
+    if let Some((backing_type, _)) = raw_values {
+        writeln!(out, "\n    private final {backing_type} {backing_name};\n")
+            .expect("writing to String cannot fail");
+        writeln!(out, "    {name}({backing_type} value) {{")
             .expect("writing to String cannot fail");
         writeln!(out, "        this.{backing_name} = value;\n    }}\n")
             .expect("writing to String cannot fail");
         writeln!(
             out,
-            "    public java.lang.String value() {{\n        return {backing_name};\n    }}"
+            "    public {backing_type} value() {{\n        return {backing_name};\n    }}"
         )
         .expect("writing to String cannot fail");
     }
@@ -655,7 +662,11 @@ fn field_datatype(
                 path,
             )?;
         }
-        DataType::Enum(value) if is_unit_enum(value) || resolved_string_enum(value).is_some() => {
+        DataType::Enum(value)
+            if is_unit_enum(value)
+                || resolved_string_enum(value).is_some()
+                || resolved_numeric_enum(value).is_some() =>
+        {
             render_unit_enum(&mut nested, "public ", &type_name, value, path)?;
             return Ok((type_name, Some(nested)));
         }
@@ -985,6 +996,41 @@ fn resolved_string_enum(value: &Enum) -> Option<Vec<(&str, &str)>> {
     (!values.is_empty()).then_some(values)
 }
 
+/// The backing type and values of an enum whose variants all carry a discriminant, like
+/// `serde_repr` enums.
+fn resolved_numeric_enum(value: &Enum) -> Option<(&'static str, Vec<String>)> {
+    let discriminants = value
+        .variants
+        .iter()
+        .filter(|(_, variant)| !variant.skip)
+        .map(|(_, variant)| variant.attributes.discriminant())
+        .collect::<Option<Vec<_>>>()?;
+    let backing_type = match discriminants.first()?.primitive() {
+        Primitive::i8 => "byte",
+        Primitive::u8 | Primitive::i16 => "short",
+        Primitive::u16 | Primitive::i32 => "int",
+        Primitive::u32 | Primitive::i64 => "long",
+        _ => "java.math.BigInteger",
+    };
+    let values = discriminants
+        .into_iter()
+        .map(|discriminant| {
+            let value = match discriminant.value() {
+                LiteralValue::Int(value) => value.to_string(),
+                LiteralValue::UInt(value) => value.to_string(),
+                _ => unreachable!("numeric enums only have integer discriminants"),
+            };
+            match backing_type {
+                "byte" | "short" => format!("({backing_type}) {value}"),
+                "long" => format!("{value}L"),
+                "java.math.BigInteger" => format!("new java.math.BigInteger(\"{value}\")"),
+                _ => value,
+            }
+        })
+        .collect();
+    Some((backing_type, values))
+}
+
 fn escape_java_string(value: &str) -> String {
     value
         .chars()
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, io, path::PathBuf};
 
-use specta::datatype::{OpaqueReference, RecursiveInlineType};
+use specta::datatype::{Literal, OpaqueReference, RecursiveInlineType};
 
 /// Error returned by the JSON Schema exporter.
 #[derive(Debug, thiserror::Error)]
@@ -64,6 +64,17 @@ pub enum Error {
         reference: OpaqueReference,
     },
 
+    /// A literal value cannot be represented in JSON.
+    #[error("unsupported literal at {path}: {literal:?} {reason}")]
+    UnsupportedLiteral {
+        /// Schema path being rendered.
+        path: String,
+        /// Literal value.
+        literal: Literal,
+        /// Why the literal is unsupported.
+        reason: &'static str,
+    },
+
     /// A recursive inline type cannot be represented anonymously.
     #[error("recursive inline type at {path}: {cycle:?}")]
     InfiniteRecursiveInlineType {
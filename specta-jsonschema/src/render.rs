@@ -7,8 +7,9 @@ use serde_json::{Map, Number, Value};
 use specta::{
     Types,
     datatype::{
-        DataType, Deprecated, Enum, Field, Fields, Generic, List, Map as SpectaMap, NamedDataType,
-        NamedReference, NamedReferenceType, Primitive, Reference, Struct, Tuple, Variant,
+        Attributes, DataType, Deprecated, Enum, Field, Fields, Generic, List, Literal,
+        LiteralValue, Map as SpectaMap, NamedDataType, NamedReference, NamedReferenceType,
+        Primitive, Reference, Struct, Tuple, Variant,
     },
 };
 
@@ -320,10 +321,13 @@ impl<'a> Renderer<'a> {
             Reference::Named(reference) => {
                 self.render_named_reference(reference, generics, path, depth)
             }
-            Reference::Opaque(reference) => Err(Error::UnsupportedOpaqueReference {
-                path: path.into(),
-                reference: reference.clone(),
-            }),
+            Reference::Opaque(reference) => match reference.downcast_ref::<Literal>() {
+                Some(literal) => Ok(object([("const", literal_value(literal, path)?)])),
+                None => Err(Error::UnsupportedOpaqueReference {
+                    path: path.into(),
+                    reference: reference.clone(),
+                }),
+            },
         }
     }
 
@@ -640,6 +644,7 @@ impl<'a> Renderer<'a> {
                                 &field.docs,
                                 field.deprecated.as_ref(),
                             );
+                            self.apply_values(&mut schema, &field.attributes);
                             schema
                         }
                         _ => continue,
@@ -650,6 +655,7 @@ impl<'a> Renderer<'a> {
                         &variant.docs,
                         variant.deprecated.as_ref(),
                     );
+                    self.apply_values(&mut schema, &variant.attributes);
                     schemas.push(schema);
                 }
                 match schemas.as_slice() {
@@ -726,6 +732,7 @@ impl<'a> Renderer<'a> {
                     let field_path = format!("{path}.{name}");
                     let mut schema = self.render_datatype(ty, generics, &field_path, depth + 1)?;
                     self.apply_metadata(&mut schema, None, &field.docs, field.deprecated.as_ref());
+                    self.apply_values(&mut schema, &field.attributes);
                     // A repeated field name overwrites its property, so its
                     // `required` entry must not repeat either: `required`
                     // demands unique items in every JSON Schema dialect.
@@ -774,6 +781,7 @@ impl<'a> Renderer<'a> {
                 let mut schema =
                     self.render_datatype(ty, generics, &format!("{path}.{idx}"), depth + 1)?;
                 self.apply_metadata(&mut schema, None, &field.docs, field.deprecated.as_ref());
+                self.apply_values(&mut schema, &field.attributes);
                 Ok(schema)
             })
             .collect::<Result<Vec<_>, _>>()?;
@@ -961,6 +969,7 @@ impl<'a> Renderer<'a> {
             &variant.docs,
             variant.deprecated.as_ref(),
         );
+        self.apply_values(&mut schema, &variant.attributes);
         Ok(schema)
     }
 
@@ -1011,27 +1020,10 @@ impl<'a> Renderer<'a> {
         docs: &str,
         deprecated: Option<&Deprecated>,
     ) {
-        if !schema.is_object() {
-            if title.is_none() && docs.is_empty() && deprecated.is_none() {
-                return;
-            }
-            let inner = std::mem::replace(schema, Value::Object(Map::new()));
-            *schema = object([("allOf", Value::Array(vec![inner]))]);
-        }
-        let Value::Object(schema) = schema else {
-            unreachable!("boolean schemas with metadata are wrapped above")
-        };
-
-        if matches!(self.schema_version, SchemaVersion::Draft7)
-            && schema.contains_key("$ref")
-            && (title.is_some() || !docs.is_empty() || deprecated.is_some())
-        {
-            let reference = std::mem::take(schema);
-            schema.insert(
-                "allOf".to_string(),
-                Value::Array(vec![Value::Object(reference)]),
-            );
+        if title.is_none() && docs.is_empty() && deprecated.is_none() {
+            return;
         }
+        let schema = self.annotatable(schema);
 
         if let Some(title) = title {
             schema.insert("title".to_string(), string(title));
@@ -1052,6 +1044,50 @@ impl<'a> Renderer<'a> {
         }
     }
 
+    /// Adds the `examples` and `default` of a field or variant.
+    fn apply_values(&self, schema: &mut Value, attributes: &Attributes) {
+        let examples = attributes
+            .examples()
+            .iter()
+            .filter_map(|example| serde_json::from_str::<Value>(example).ok())
+            .collect::<Vec<_>>();
+        let default = attributes
+            .default_value()
+            .and_then(|default| serde_json::from_str::<Value>(default).ok());
+        if examples.is_empty() && default.is_none() {
+            return;
+        }
+        let schema = self.annotatable(schema);
+
+        if !examples.is_empty() {
+            schema.insert("examples".to_string(), Value::Array(examples));
+        }
+        if let Some(default) = default {
+            schema.insert("default".to_string(), default);
+        }
+    }
+
+    /// Returns the object annotations can be added to. Boolean schemas, and references in
+    /// Draft 7 which ignores the siblings of `$ref`, are wrapped in an `allOf`.
+    fn annotatable<'s>(&self, schema: &'s mut Value) -> &'s mut Map<String, Value> {
+        if !schema.is_object() {
+            let inner = std::mem::replace(schema, Value::Object(Map::new()));
+            *schema = object([("allOf", Value::Array(vec![inner]))]);
+        }
+        let Value::Object(schema) = schema else {
+            unreachable!("boolean schemas are wrapped above")
+        };
+
+        if matches!(self.schema_version, SchemaVersion::Draft7) && schema.contains_key("$ref") {
+            let reference = std::mem::take(schema);
+            schema.insert(
+                "allOf".to_string(),
+                Value::Array(vec![Value::Object(reference)]),
+            );
+        }
+        schema
+    }
+
     fn ref_path(&self, key: &str) -> String {
         format!(
             "#/{}/{}",
@@ -1416,6 +1452,30 @@ fn object<const N: usize>(entries: [(&str, Value); N]) -> Value {
     )
 }
 
+fn literal_value(literal: &Literal, path: &str) -> Result<Value, Error> {
+    let unsupported = |reason| Error::UnsupportedLiteral {
+        path: path.into(),
+        literal: literal.clone(),
+        reason,
+    };
+
+    Ok(match literal.value() {
+        LiteralValue::Bool(v) => Value::Bool(v),
+        LiteralValue::Int(v) => i64::try_from(v)
+            .map(|v| Value::Number(Number::from(v)))
+            .map_err(|_| unsupported("is too large for a JSON number"))?,
+        LiteralValue::UInt(v) => u64::try_from(v)
+            .map(|v| Value::Number(Number::from(v)))
+            .map_err(|_| unsupported("is too large for a JSON number"))?,
+        LiteralValue::Float(v) => Number::from_f64(v)
+            .map(Value::Number)
+            .ok_or_else(|| unsupported("is `NaN` or infinite, which JSON can't represent"))?,
+        LiteralValue::Char(v) => Value::String(v.to_string()),
+        LiteralValue::Str(v) => string(v),
+        _ => return Err(unsupported("is not a supported kind of literal")),
+    })
+}
+
 fn string(value: impl AsRef<str>) -> Value {
     Value::String(value.as_ref().to_string())
 }
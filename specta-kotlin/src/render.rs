@@ -6,8 +6,8 @@ use std::{
 use specta::{
     Format, Types,
     datatype::{
-        DataType, Enum, Field, Fields, Generic, NamedDataType, NamedReferenceType, Primitive,
-        Reference, Struct, Variant,
+        DataType, Enum, Field, Fields, Generic, Literal, LiteralValue, NamedDataType,
+        NamedReferenceType, Primitive, Reference, Struct, Variant,
     },
 };
 
@@ -384,12 +384,7 @@ fn render_struct(
                     .fields
                     .iter()
                     .filter(|(_, field)| field.ty.is_some())
-                    .map(|(name, _)| {
-                        safe_member_name(
-                            &convert_name(kotlin.naming, name, NameKind::Property),
-                            "field",
-                        )
-                    }),
+                    .map(|(name, field)| property_name(kotlin, name, field)),
             )?;
             out.push_str("public data class ");
             out.push_str(name);
@@ -509,8 +504,14 @@ fn render_enum(
     let mut base_names = BTreeSet::new();
     let variant_names = variants
         .iter()
-        .map(|(original, _)| {
-            let base = safe_member_name(&convert_variant_name(kotlin.naming, original), "Variant");
+        .map(|(original, variant)| {
+            let base = safe_member_name(
+                &variant.attributes.rename().map_or_else(
+                    || convert_variant_name(kotlin.naming, original),
+                    str::to_string,
+                ),
+                "Variant",
+            );
             let base_identifier = identifier(&base, &format!("{path}.{original}"))?;
             let base_identifier = semantic_identifier(&base_identifier).to_owned();
             if !base_names.insert(base_identifier) {
@@ -551,6 +552,42 @@ fn render_enum(
         });
     }
 
+    // Variants which carry a discriminant, like `serde_repr` enums, become an enum class holding it.
+    let discriminants = variants
+        .iter()
+        .map(|(_, variant)| variant.attributes.discriminant())
+        .collect::<Option<Vec<_>>>()
+        .filter(|discriminants| !discriminants.is_empty());
+    if let Some(discriminants) = discriminants {
+        out.push_str("public enum class ");
+        out.push_str(name);
+        out.push_str("(public val value: ");
+        out.push_str(primitive_name(&discriminants[0].primitive()));
+        out.push_str(") {\n");
+        for (index, (((variant_name, variant), converted), discriminant)) in variants
+            .iter()
+            .zip(&variant_names)
+            .zip(&discriminants)
+            .enumerate()
+        {
+            let indent = kotlin.indentation(1);
+            render_kdoc(out, &indent, &variant.docs);
+            render_deprecated(out, &indent, variant.deprecated.as_ref());
+            let variant_path = format!("{path}.{variant_name}");
+            out.push_str(&indent);
+            out.push_str(&identifier(converted, &variant_path)?);
+            out.push('(');
+            out.push_str(&literal_value(discriminant, &variant_path)?);
+            out.push(')');
+            if index + 1 != variants.len() {
+                out.push(',');
+            }
+            out.push('\n');
+        }
+        out.push('}');
+        return Ok(());
+    }
+
     annotation(out, "", kotlin, "Serializable", None);
     if unit_only {
         out.push_str("public enum class ");
@@ -707,12 +744,7 @@ fn render_variant(
                     .fields
                     .iter()
                     .filter(|(_, field)| field.ty.is_some())
-                    .map(|(name, _)| {
-                        safe_member_name(
-                            &convert_name(kotlin.naming, name, NameKind::Property),
-                            "field",
-                        )
-                    }),
+                    .map(|(name, field)| property_name(kotlin, name, field)),
             )?;
             let fields = fields
                 .fields
@@ -782,14 +814,24 @@ fn render_property(
         .is_some_and(|nullability| nullability == Nullability::Nullable);
     render_kdoc(out, &indent, &field.docs);
     render_deprecated(out, &indent, field.deprecated.as_ref());
-    let converted = safe_member_name(
-        &convert_name(kotlin.naming, original_name, NameKind::Property),
-        "field",
-    );
+    let converted = property_name(kotlin, original_name, field);
     if converted != original_name {
         annotation(out, &indent, kotlin, "SerialName", Some(original_name));
     }
-    if kotlin.serialization == Serialization::Kotlinx && nullable && !field.optional {
+    let literal = field
+        .ty
+        .as_ref()
+        .and_then(|ty| match ty {
+            DataType::Reference(Reference::Opaque(reference)) => {
+                reference.downcast_ref::<Literal>()
+            }
+            _ => None,
+        })
+        .map(|literal| literal_value(literal, path))
+        .transpose()?;
+    if kotlin.serialization == Serialization::Kotlinx
+        && ((nullable && !field.optional) || literal.is_some())
+    {
         annotation(out, &indent, kotlin, "EncodeDefault", None);
     }
     out.push_str(&indent);
@@ -799,7 +841,10 @@ fn render_property(
     out.push_str(&identifier(&converted, path)?);
     out.push_str(": ");
     out.push_str(&rendered);
-    if field.optional || (kotlin.serialization == Serialization::Kotlinx && nullable) {
+    if let Some(literal) = literal {
+        out.push_str(" = ");
+        out.push_str(&literal);
+    } else if field.optional || (kotlin.serialization == Serialization::Kotlinx && nullable) {
         out.push_str(" = null");
     }
     Ok(())
@@ -1008,6 +1053,11 @@ fn datatype(
                 return Err(Error::RecursiveInlineType { path: path.into() });
             }
         },
+        DataType::Reference(Reference::Opaque(reference))
+            if let Some(literal) = reference.downcast_ref::<Literal>() =>
+        {
+            primitive_name(&literal.primitive()).to_owned()
+        }
         DataType::Reference(Reference::Opaque(reference)) => match kotlin.unknown_types {
             UnknownType::Any if kotlin.serialization == Serialization::None => {
                 "kotlin.Any?".to_owned()
@@ -1221,6 +1271,47 @@ fn substitute_field_generics(fields: &mut Fields, generics: &[(Generic, DataType
     }
 }
 
+/// Render a literal as a Kotlin expression of the type returned by [`primitive_name`].
+fn literal_value(literal: &Literal, path: &str) -> Result<String, Error> {
+    let primitive = literal.primitive();
+    Ok(match literal.value() {
+        LiteralValue::Bool(v) => v.to_string(),
+        LiteralValue::Int(v) => match primitive {
+            Primitive::i64 | Primitive::isize => format!("{v}L"),
+            Primitive::i128 => format!("java.math.BigInteger(\"{v}\")"),
+            _ => v.to_string(),
+        },
+        LiteralValue::UInt(v) => match primitive {
+            Primitive::u64 | Primitive::usize => format!("{v}uL"),
+            Primitive::u128 => format!("java.math.BigInteger(\"{v}\")"),
+            _ => format!("{v}u"),
+        },
+        LiteralValue::Float(v) if !v.is_finite() => {
+            return Err(Error::UnsupportedType {
+                path: path.into(),
+                reason: "`NaN` and infinite float literals have no Kotlin constant",
+            });
+        }
+        LiteralValue::Float(v) => match primitive {
+            Primitive::f16 | Primitive::f32 => format!("{v:?}f"),
+            Primitive::f128 => format!("java.math.BigDecimal(\"{v:?}\")"),
+            _ => format!("{v:?}"),
+        },
+        LiteralValue::Char(v) => match v {
+            '\'' => "'\\''".to_owned(),
+            '"' => "'\"'".to_owned(),
+            v => format!("'{}'", escape_string(&v.to_string())),
+        },
+        LiteralValue::Str(v) => format!("\"{}\"", escape_string(v)),
+        _ => {
+            return Err(Error::UnsupportedType {
+                path: path.into(),
+                reason: "literal kind is not supported",
+            });
+        }
+    })
+}
+
 fn primitive_name(primitive: &Primitive) -> &'static str {
     match primitive {
         Primitive::i8 => "kotlin.Byte",
@@ -1496,6 +1587,17 @@ fn validate_identifier(name: &str, path: &str) -> Result<(), Error> {
     Ok(())
 }
 
+/// The Kotlin property name of a field, preferring `#[specta(rename = "...")]` over the naming convention.
+fn property_name(kotlin: &Kotlin, name: &str, field: &Field) -> String {
+    safe_member_name(
+        &field.attributes.rename().map_or_else(
+            || convert_name(kotlin.naming, name, NameKind::Property),
+            str::to_string,
+        ),
+        "field",
+    )
+}
+
 fn safe_member_name(name: &str, prefix: &str) -> String {
     if validate_identifier(name, "generated member").is_ok() {
         return name.to_owned();
@@ -36,12 +36,28 @@ use syn::{Error, LitStr, Type, parse_macro_input};
 ///   local derive input name.
 /// - `#[specta(collect)]` or `#[specta(collect = true)]` enables collection for this type when
 ///   the `collect` feature is used. Use `#[specta(collect = false)]` to prevent collection.
+///   Use `#[specta(collect = "billing")]` to collect the type into the named group returned by
+///   `specta::collect_group("billing")`. A quoted `"true"` or `"false"` is rejected rather than
+///   naming a group, as it's almost certainly meant as the boolean.
 /// - `#[specta(skip_attr = "attr_name")]` ignores attributes named `attr_name` while parsing and
 ///   while collecting runtime attributes. This may be repeated.
+/// - `#[specta(readonly)]` or `#[specta(readonly = false)]` overrides the TypeScript exporter's
+///   `readonly` option for the type and everything rendered within it.
+/// - `#[specta(selection_tag = "public")]` attaches a tag to the type's `NamedDataType` which can
+///   be used to select a subset of `Types` with `specta::Selection`. This may be repeated. This is
+///   not related to `#[serde(tag = "...")]` and does not affect the exported type.
+/// - `#[specta(rename = "...")]` exports the type under a different name. Unlike
+///   `#[serde(rename = "...")]` this doesn't change the serialized format, including the tag of
+///   internally tagged structs.
+/// - `#[specta(rename_all = "camelCase")]` sets the exported identifier of every field of a
+///   struct, or every variant of an enum, using the given case. Any case supported by
+///   `#[serde(rename_all = "...")]` can be used.
 /// - `#[specta(transparent)]` or `#[specta(transparent = true)]` treats a struct as its single
 ///   non-skipped field. Use `#[specta(transparent = false)]` to disable it.
 /// - `#[specta(bound = "T: Type")]` replaces the automatically inferred `Type` bounds.
 ///   Use `#[specta(bound = "")]` to emit no inferred bounds.
+/// - `#[specta(serde_repr)]` marks a fieldless enum with an integer `#[repr(..)]` as deriving
+///   `Serialize_repr` and `Deserialize_repr`, so `specta_serde` exports it as its discriminants.
 ///
 /// `#[specta(type = ...)]` cannot be combined with `#[specta(transparent)]`.
 /// `#[specta(transparent)]` is only valid on structs with exactly one non-skipped field.
@@ -56,12 +72,21 @@ use syn::{Error, LitStr, Type, parse_macro_input};
 ///   Use `#[specta(skip = false)]` to disable it.
 /// - `#[specta(inline)]` or `#[specta(inline = true)]` inlines the first unnamed field of the
 ///   variant. Use `#[specta(inline = false)]` to disable it.
+/// - `#[specta(rename = "...")]` sets the exported identifier of the variant.
+/// - `#[specta(rename_all = "camelCase")]` sets the exported identifier of every field of the
+///   variant using the given case.
+/// - `#[specta(example = ...)]`, `#[specta(examples = [...])]` and `#[specta(default = ...)]`
+///   record example and default values, like on fields.
 ///
 /// ## `#[specta(...)]` field attributes
 ///
 /// These can be used on struct fields and enum variant fields.
 ///
 /// - `#[specta(type = T)]` overrides the generated field type with `T`.
+/// - `#[specta(literal = "v1")]` overrides the generated field type with a literal type which
+///   only accepts the given value. Strings, numbers, booleans, chars and paths to constants are
+///   supported, like `#[specta(literal = 404u16)]` or `#[specta(literal = API_VERSION)]`. It
+///   cannot be combined with `#[specta(type = ...)]`.
 /// - `#[specta(inline)]` or `#[specta(inline = true)]` inlines the field type.
 ///   Use `#[specta(inline = false)]` to disable it.
 /// - `#[specta(skip)]` or `#[specta(skip = true)]` skips generating the field type.
@@ -70,6 +95,25 @@ use syn::{Error, LitStr, Type, parse_macro_input};
 ///   This is commonly used with `Option<T>` to export `{ a?: T | null }` instead of
 ///   `{ a: T | null }`.
 /// - `#[specta(default)]` or `#[specta(default = true)]` is an alias for `optional`.
+/// - `#[specta(rename = "...")]` sets the exported identifier of a named field.
+/// - `#[specta(readonly)]` or `#[specta(readonly = false)]` overrides whether TypeScript exports the
+///   field `readonly`, like on containers.
+/// - `#[specta(example = ...)]` records an example value. It can be repeated, or
+///   `#[specta(examples = [...])]` can be used to record several at once.
+/// - `#[specta(default = ...)]` records a default value and marks the field as optional.
+///
+/// Example and default values are any expression implementing `serde::Serialize`. They are
+/// serialized to JSON when the type is registered and stored in the item's `Attributes`, which
+/// JSON Schema, OpenAPI and TypeScript export as `examples`/`default` or `@example`/`@default`.
+/// They require specta's `serde_json` feature. With it enabled, the `Default::default()` value of a
+/// field with `#[specta(default)]` or `#[serde(default)]` is also recorded when the field type
+/// implements `Serialize` and the container has no type or const generics. The function of
+/// `#[serde(default = "...")]` is never called; state its value with `#[specta(default = ...)]`.
+///
+/// Field and variant renames only change identifiers and are stored in the item's `Attributes`,
+/// so they are used by exporters which declare identifiers separately from the serialized name,
+/// like Swift, Kotlin, C# and Go, and ignored where the identifier is the serialized name, like
+/// the keys of a TypeScript object.
 ///
 /// ## Rust attributes
 ///
@@ -85,6 +129,9 @@ use syn::{Error, LitStr, Type, parse_macro_input};
 /// `#[repr(transparent)]` is also accepted on containers as an alias for
 /// `#[specta(transparent)]`.
 ///
+/// On a fieldless enum with an integer `#[repr(u8)]` (or any other integer type), the
+/// discriminant of every variant is recorded in its `Attributes`.
+///
 /// ## Serde attributes
 ///
 /// Specta can read selected `#[serde(...)]` attributes. Prefer Serde attributes when the same
@@ -125,6 +172,11 @@ use syn::{Error, LitStr, Type, parse_macro_input};
 /// - `#[serde(into = "T")]`
 /// - `#[serde(variant_identifier)]`
 /// - `#[serde(field_identifier)]`
+/// - `#[derive(Serialize_repr)]` and `#[derive(Deserialize_repr)]` from `serde_repr`, which
+///   export the enum as its integer discriminants. A derive macro only sees the `#[derive(...)]`
+///   attributes after its own, so these are usually invisible to `Type`. Add
+///   `#[specta(serde_repr)]` to an enum deriving both to export it as its discriminants regardless
+///   of the derive order.
 ///
 /// Variant attributes:
 ///
@@ -2,13 +2,12 @@
 
 use std::str::FromStr;
 
-use inflector::Inflector;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{FnArg, ItemFn, Pat, Visibility, parse};
 
 use crate::{
-    r#type::attr::deprecated_as_tokens,
+    r#type::attr::{RenameAllRule, deprecated_as_tokens},
     utils::{AttrExtract, format_fn_wrapper, parse_attrs},
 };
 
@@ -20,50 +19,6 @@ fn unraw(s: &str) -> &str {
     }
 }
 
-#[derive(Clone, Copy)]
-enum RenameAllRule {
-    Lowercase,
-    Uppercase,
-    PascalCase,
-    CamelCase,
-    SnakeCase,
-    ScreamingSnakeCase,
-    KebabCase,
-    ScreamingKebabCase,
-}
-
-impl RenameAllRule {
-    fn parse(value: &str, span: proc_macro2::Span) -> syn::Result<Self> {
-        match value {
-            "lowercase" => Ok(Self::Lowercase),
-            "UPPERCASE" => Ok(Self::Uppercase),
-            "PascalCase" => Ok(Self::PascalCase),
-            "camelCase" => Ok(Self::CamelCase),
-            "snake_case" => Ok(Self::SnakeCase),
-            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnakeCase),
-            "kebab-case" => Ok(Self::KebabCase),
-            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebabCase),
-            _ => Err(syn::Error::new(
-                span,
-                "specta: unsupported rename rule. Expected one of lowercase, UPPERCASE, PascalCase, camelCase, snake_case, SCREAMING_SNAKE_CASE, kebab-case, SCREAMING-KEBAB-CASE",
-            )),
-        }
-    }
-
-    fn apply(self, input: &str) -> String {
-        match self {
-            Self::Lowercase => input.to_lowercase(),
-            Self::Uppercase => input.to_uppercase(),
-            Self::PascalCase => input.to_pascal_case(),
-            Self::CamelCase => input.to_camel_case(),
-            Self::SnakeCase => input.to_snake_case(),
-            Self::ScreamingSnakeCase => input.to_screaming_snake_case(),
-            Self::KebabCase => input.to_kebab_case(),
-            Self::ScreamingKebabCase => input.to_kebab_case().to_uppercase(),
-        }
-    }
-}
-
 struct FunctionNameAttrs {
     rename: Option<String>,
     rename_all: Option<RenameAllRule>,
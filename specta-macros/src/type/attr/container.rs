@@ -1,11 +1,16 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
+use syn::Lit;
 use syn::Result;
 use syn::Type;
 
-use crate::utils::{AttrExtract, Attribute};
+use crate::utils::{AttrExtract, Attribute, AttributeValue};
 
-use super::RustCAttr;
+use super::{RenameAllRule, RustCAttr};
+
+const INTEGER_REPRS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
 
 #[derive(Default)]
 pub struct ContainerAttr {
@@ -14,12 +19,25 @@ pub struct ContainerAttr {
     pub inline: bool,
     pub remote: Option<TokenStream>,
     pub collect: Option<bool>,
+    pub collect_group: Option<String>,
     pub skip_attrs: Vec<String>,
+    pub tags: Vec<String>,
+    pub rename: Option<String>,
+    pub rename_all: Option<RenameAllRule>,
+    pub readonly: Option<bool>,
     pub common: RustCAttr,
 
     // Struct only (we pass it anyway so enums get nice errors)
     pub transparent: bool,
 
+    // Enum only. The integer type from `#[repr(u8)]` and friends.
+    pub repr: Option<syn::Ident>,
+    // Enum only. `#[specta(serde_repr)]`, for enums deriving `serde_repr` in a way we can't see.
+    pub serde_repr: bool,
+
+    // Whether the container has type or const generics. Set from the item, not an attribute.
+    pub generic: bool,
+
     // Custom where clause bounds (None = automatic, Some(vec) = custom)
     pub bound: Option<Vec<syn::WherePredicate>>,
 }
@@ -53,14 +71,52 @@ impl ContainerAttr {
                 .or(Some(attr.parse_path()?.to_token_stream()));
         }
 
-        if let Some(attr) = attrs.extract("specta", "collect") {
-            result.collect = result.collect.take().or(Some(attr.parse_bool_or_true()?));
+        let mut collect = attrs.extract_all("specta", "collect").into_iter();
+        if let Some(attr) = collect.next() {
+            if let Some(duplicate) = collect.next() {
+                return Err(syn::Error::new(
+                    duplicate.key.span(),
+                    "specta: `collect` may only be specified once",
+                ));
+            }
+
+            if let Some(AttributeValue::Lit(Lit::Str(group))) = &attr.value {
+                let group = group.value();
+                if group.is_empty() || group == "true" || group == "false" {
+                    return Err(syn::Error::new(
+                        attr.value_span(),
+                        format!(
+                            "specta: invalid collect group {group:?}. Use `collect = true` or `collect = false` without quotes to enable or disable collection"
+                        ),
+                    ));
+                }
+                result.collect = result.collect.take().or(Some(true));
+                result.collect_group = Some(group);
+            } else {
+                result.collect = result.collect.take().or(Some(attr.parse_bool_or_true()?));
+            }
         }
 
         for attr in attrs.extract_all("specta", "skip_attr") {
             result.skip_attrs.push(attr.parse_string()?);
         }
 
+        if let Some(attr) = attrs.extract("specta", "readonly") {
+            result.readonly = Some(attr.parse_bool_or_true()?);
+        }
+
+        for attr in attrs.extract_all("specta", "selection_tag") {
+            result.tags.push(attr.parse_string()?);
+        }
+
+        if let Some(attr) = attrs.extract("specta", "rename") {
+            result.rename = Some(attr.parse_string()?);
+        }
+
+        if let Some(attr) = attrs.extract("specta", "rename_all") {
+            result.rename_all = Some(RenameAllRule::from_attr(&attr)?);
+        }
+
         if let Some(attr) = attrs.extract("specta", "transparent") {
             result.transparent = attr.parse_bool_or_true()?;
         } else if is_struct && let Some(attr) = attrs.extract("repr", "transparent") {
@@ -71,6 +127,24 @@ impl ContainerAttr {
             result.transparent = attr.parse_bool_or_true()?;
         }
 
+        if let Some(attr) = attrs.extract("specta", "serde_repr") {
+            if is_struct {
+                return Err(syn::Error::new(
+                    attr.key.span(),
+                    "specta: `#[specta(serde_repr)]` is only allowed on an enum",
+                ));
+            }
+            result.serde_repr = attr.parse_bool_or_true()?;
+        }
+
+        if !is_struct {
+            for int in INTEGER_REPRS {
+                if let Some(attr) = attrs.extract("repr", int) {
+                    result.repr = Some(attr.key);
+                }
+            }
+        }
+
         if let Some(attr) = attrs.extract("specta", "bound") {
             let bound_str = attr.parse_string()?;
             if bound_str.is_empty() {
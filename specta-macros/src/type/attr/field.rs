@@ -1,17 +1,21 @@
-use syn::{Result, Type};
+use syn::{Expr, ExprLit, ExprPath, ExprUnary, Lit, Result, Type, UnOp};
 
-use crate::utils::{AttrExtract, Attribute};
+use crate::utils::{AttrExtract, Attribute, AttributeValue};
 
-use super::RustCAttr;
+use super::{DefaultValue, RustCAttr, ValueAttr};
 
 #[derive(Default)]
 pub struct FieldAttr {
     pub r#type: Option<Type>,
+    pub literal: Option<Expr>,
     pub inline: bool,
     pub skip: bool,
     pub serde_skip: bool,
     pub serde_newtype_skip_ignored: bool,
     pub optional: bool,
+    pub rename: Option<String>,
+    pub readonly: Option<bool>,
+    pub values: ValueAttr,
     pub common: RustCAttr,
 }
 
@@ -26,6 +30,16 @@ impl FieldAttr {
             result.r#type = result.r#type.take().or(Some(attr.parse_type()?));
         }
 
+        if let Some(attr) = attrs.extract("specta", "literal") {
+            if result.r#type.is_some() {
+                return Err(syn::Error::new(
+                    attr.key.span(),
+                    "specta: `#[specta(literal = ...)]` cannot be combined with `#[specta(type = ...)]`",
+                ));
+            }
+            result.literal = Some(parse_literal(&attr)?);
+        }
+
         if let Some(attr) = attrs.extract("specta", "inline") {
             result.inline = attr.parse_bool_or_true()?;
         }
@@ -46,14 +60,79 @@ impl FieldAttr {
             }
         }
 
+        if let Some(attr) = attrs.extract("specta", "rename") {
+            result.rename = Some(attr.parse_string()?);
+        }
+
         if let Some(attr) = attrs.extract("specta", "optional") {
             result.optional = attr.parse_bool_or_true()?;
         }
 
+        if let Some(attr) = attrs.extract("specta", "readonly") {
+            result.readonly = Some(attr.parse_bool_or_true()?);
+        }
+
+        result.values = ValueAttr::from_attrs(attrs)?;
+
         if let Some(attr) = attrs.extract("specta", "default") {
-            result.optional = attr.parse_bool_or_true()?;
+            match result.values.parse_default(&attr)? {
+                Some(optional) => {
+                    result.optional = optional;
+                    if optional {
+                        result.values.default = Some(DefaultValue::Default);
+                    }
+                }
+                None => result.optional = true,
+            }
+        }
+
+        // `#[serde(default)]` is still handled at runtime, we only capture the value here. The
+        // function of `#[serde(default = "path")]` isn't called, as it may do anything.
+        if result.values.default.is_none()
+            && let Some(attr) = attrs.extract("serde", "default")
+            && attr.value.is_none()
+        {
+            result.values.default = Some(DefaultValue::Default);
         }
 
         Ok(result)
     }
 }
+
+/// Parses the value of `#[specta(literal = ...)]`, which must be a string, number, boolean or
+/// char literal or a path to a constant. Numbers may be negated.
+fn parse_literal(attr: &Attribute) -> Result<Expr> {
+    match &attr.value {
+        Some(AttributeValue::Lit(
+            lit @ (Lit::Str(_) | Lit::Int(_) | Lit::Float(_) | Lit::Bool(_) | Lit::Char(_)),
+        )) => Ok(Expr::Lit(ExprLit {
+            attrs: Vec::new(),
+            lit: lit.clone(),
+        })),
+        Some(AttributeValue::Expr(
+            expr @ Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr: operand,
+                ..
+            }),
+        )) if matches!(
+            &**operand,
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(_) | Lit::Float(_),
+                ..
+            })
+        ) =>
+        {
+            Ok(expr.clone())
+        }
+        Some(AttributeValue::Path(path)) => Ok(Expr::Path(ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: path.clone(),
+        })),
+        _ => Err(syn::Error::new(
+            attr.value_span(),
+            "specta: expected a string, number, boolean or char literal or a constant. Eg. `\"v1\"` or `404`",
+        )),
+    }
+}
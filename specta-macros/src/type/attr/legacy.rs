@@ -17,12 +17,6 @@ impl Scope {
 
 pub fn migration_hint(scope: Scope, key: &str) -> Option<String> {
     let replacement = match (scope, key) {
-        (Scope::Container, "rename") | (Scope::Field, "rename") | (Scope::Variant, "rename") => {
-            Some("#[serde(rename = \"...\")]")
-        }
-        (Scope::Container, "rename_all") | (Scope::Variant, "rename_all") => {
-            Some("#[serde(rename_all = \"...\")]")
-        }
         (Scope::Container, "tag") => Some("#[serde(tag = \"...\")]"),
         (Scope::Container, "content") => Some("#[serde(content = \"...\")]"),
         (Scope::Container, "untagged") => Some("#[serde(untagged)]"),
@@ -1,7 +1,9 @@
 pub use container::*;
 pub use field::*;
 pub use legacy::*;
+pub use rename::*;
 pub use rustc::*;
+pub use value::*;
 pub use variant::*;
 
 use crate::utils::{Attribute, AttributeValue};
@@ -9,7 +11,9 @@ use crate::utils::{Attribute, AttributeValue};
 mod container;
 mod field;
 mod legacy;
+mod rename;
 mod rustc;
+mod value;
 mod variant;
 
 pub fn reject_unknown_specta_attrs(attrs: &[Attribute], scope: Scope) -> syn::Result<()> {
@@ -0,0 +1,52 @@
+use inflector::Inflector;
+
+use crate::utils::Attribute;
+
+/// A casing rule from `#[specta(rename_all = "...")]`.
+#[derive(Clone, Copy)]
+pub enum RenameAllRule {
+    Lowercase,
+    Uppercase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+impl RenameAllRule {
+    pub fn parse(value: &str, span: proc_macro2::Span) -> syn::Result<Self> {
+        match value {
+            "lowercase" => Ok(Self::Lowercase),
+            "UPPERCASE" => Ok(Self::Uppercase),
+            "PascalCase" => Ok(Self::PascalCase),
+            "camelCase" => Ok(Self::CamelCase),
+            "snake_case" => Ok(Self::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnakeCase),
+            "kebab-case" => Ok(Self::KebabCase),
+            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebabCase),
+            _ => Err(syn::Error::new(
+                span,
+                "specta: unsupported rename rule. Expected one of lowercase, UPPERCASE, PascalCase, camelCase, snake_case, SCREAMING_SNAKE_CASE, kebab-case, SCREAMING-KEBAB-CASE",
+            )),
+        }
+    }
+
+    pub fn from_attr(attr: &Attribute) -> syn::Result<Self> {
+        Self::parse(&attr.parse_string()?, attr.value_span())
+    }
+
+    pub fn apply(self, input: &str) -> String {
+        match self {
+            Self::Lowercase => input.to_lowercase(),
+            Self::Uppercase => input.to_uppercase(),
+            Self::PascalCase => input.to_pascal_case(),
+            Self::CamelCase => input.to_camel_case(),
+            Self::SnakeCase => input.to_snake_case(),
+            Self::ScreamingSnakeCase => input.to_screaming_snake_case(),
+            Self::KebabCase => input.to_kebab_case(),
+            Self::ScreamingKebabCase => input.to_kebab_case().to_uppercase(),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Expr, Lit, Result, Token, Type,
+    parse::{ParseStream, Parser},
+    punctuated::Punctuated,
+};
+
+use crate::utils::{AttrExtract, Attribute, AttributeValue};
+
+/// Where the default value of a field or variant comes from.
+pub enum DefaultValue {
+    /// `#[specta(default = ...)]`
+    Expr(Expr),
+    /// `#[serde(default)]` or `#[specta(default)]`, which use `Default::default()`.
+    Default,
+}
+
+/// Example and default values, which are serialized to JSON when the type is registered.
+#[derive(Default)]
+pub struct ValueAttr {
+    pub examples: Vec<Expr>,
+    pub default: Option<DefaultValue>,
+    span: Option<Span>,
+}
+
+impl ValueAttr {
+    pub fn from_attrs(attrs: &mut Vec<Attribute>) -> Result<Self> {
+        let mut result = Self::default();
+
+        for attr in attrs.extract_all("specta", "example") {
+            result.span.get_or_insert(attr.key.span());
+            result.examples.push(attr.parse_expr()?);
+        }
+
+        if let Some(attr) = attrs.extract("specta", "examples") {
+            result.span.get_or_insert(attr.key.span());
+            let examples = (|input: ParseStream| {
+                let content;
+                syn::bracketed!(content in input);
+                Punctuated::<Expr, Token![,]>::parse_terminated(&content)
+            })
+            .parse2(attr.parse_expr()?.to_token_stream())
+            .map_err(|_| {
+                syn::Error::new(
+                    attr.value_span(),
+                    "specta: expected an array of examples. Eg. `[\"a\", \"b\"]`",
+                )
+            })?;
+            result.examples.extend(examples);
+        }
+
+        Ok(result)
+    }
+
+    /// Parses `#[specta(default ...)]`, returning the boolean form or recording the given value.
+    pub fn parse_default(&mut self, attr: &Attribute) -> Result<Option<bool>> {
+        match &attr.value {
+            None | Some(AttributeValue::Lit(Lit::Bool(_))) => attr.parse_bool_or_true().map(Some),
+            Some(_) => {
+                self.span.get_or_insert(attr.key.span());
+                self.default = Some(DefaultValue::Expr(attr.parse_expr()?));
+                Ok(None)
+            }
+        }
+    }
+
+    /// Generates the code which records the values on `target`.
+    ///
+    /// `ty` is the type used to evaluate `Default::default()` and inferred defaults are only
+    /// recorded when it's known, as generic types may not implement `Serialize`.
+    pub fn to_tokens(
+        &self,
+        crate_ref: &TokenStream,
+        target: TokenStream,
+        ty: Option<&Type>,
+    ) -> Result<TokenStream> {
+        if !cfg!(feature = "DO_NOT_USE_serde_json") {
+            return match self.span {
+                Some(span) => Err(syn::Error::new(
+                    span,
+                    "specta: example and default values require the `serde_json` feature of `specta`",
+                )),
+                None => Ok(quote!()),
+            };
+        }
+
+        let examples = &self.examples;
+        let examples = (!examples.is_empty()).then(|| {
+            quote!(#target.set_examples([#(#crate_ref::internal::to_json(&(#examples))),*].into_iter().flatten());)
+        });
+
+        let default = match (&self.default, ty) {
+            (Some(DefaultValue::Expr(expr)), _) => {
+                Some(quote!(#crate_ref::internal::to_json(&(#expr))))
+            }
+            (Some(DefaultValue::Default), Some(ty)) => Some(quote!({
+                use #crate_ref::internal::{NoSerializeDefault as _, SerializeDefault as _};
+                (&#crate_ref::internal::DefaultProbe::<#ty>(::core::marker::PhantomData)).json_value()
+            })),
+            _ => None,
+        }
+        .map(|value| {
+            quote! {
+                if let Some(value) = #value {
+                    #target.set_default_value(value);
+                }
+            }
+        });
+
+        Ok(quote!(#examples #default))
+    }
+}
@@ -2,13 +2,16 @@ use syn::{Result, Type};
 
 use crate::utils::{AttrExtract, Attribute};
 
-use super::RustCAttr;
+use super::{RenameAllRule, RustCAttr, ValueAttr};
 
 #[derive(Default)]
 pub struct VariantAttr {
     pub r#type: Option<Type>,
     pub skip: bool,
     pub inline: bool,
+    pub rename: Option<String>,
+    pub rename_all: Option<RenameAllRule>,
+    pub values: ValueAttr,
     pub common: RustCAttr,
 }
 
@@ -39,6 +42,25 @@ impl VariantAttr {
             result.r#type = result.r#type.take().or(Some(attr.parse_type()?));
         }
 
+        if let Some(attr) = attrs.extract("specta", "rename") {
+            result.rename = Some(attr.parse_string()?);
+        }
+
+        if let Some(attr) = attrs.extract("specta", "rename_all") {
+            result.rename_all = Some(RenameAllRule::from_attr(&attr)?);
+        }
+
+        result.values = ValueAttr::from_attrs(attrs)?;
+
+        if let Some(attr) = attrs.extract("specta", "default")
+            && result.values.parse_default(&attr)?.is_some()
+        {
+            return Err(syn::Error::new(
+                attr.key.span(),
+                "specta: `#[specta(default)]` on a variant must be given a value. Eg. `#[specta(default = \"hello\")]`",
+            ));
+        }
+
         Ok(result)
     }
 }
@@ -1,6 +1,9 @@
 use super::{
-    AttributeScope, attr::*, build_runtime_attributes, generics::type_with_inferred_lifetimes,
-    r#struct::decode_field_attrs,
+    AttributeScope,
+    attr::*,
+    build_runtime_attributes,
+    generics::type_with_inferred_lifetimes,
+    r#struct::{decode_field_attrs, reject_unnamed_rename},
 };
 use crate::{r#type::field::construct_field_with_variant_skip, utils::*};
 use proc_macro2::TokenStream;
@@ -11,6 +14,7 @@ pub fn parse_enum(
     crate_ref: &TokenStream,
     container_attrs: &ContainerAttr,
     data: &DataEnum,
+    ident: &TokenStream,
 ) -> syn::Result<TokenStream> {
     if container_attrs.transparent {
         return Err(syn::Error::new(
@@ -19,6 +23,22 @@ pub fn parse_enum(
         ));
     }
 
+    // Fieldless enums with an integer `#[repr(..)]` record each variant's discriminant. We let
+    // rustc evaluate it with a cast so implicit and `const` expression discriminants just work.
+    let repr = container_attrs.repr.as_ref().filter(|_| {
+        !container_attrs.generic
+            && data
+                .variants
+                .iter()
+                .all(|variant| matches!(variant.fields, Fields::Unit))
+    });
+    if container_attrs.serde_repr && repr.is_none() {
+        return Err(syn::Error::new(
+            data.enum_token.span(),
+            "specta: `#[specta(serde_repr)]` requires a fieldless, non-generic enum with an integer `#[repr(..)]`",
+        ));
+    }
+
     let variant_types = data
         .variants
         .iter()
@@ -36,6 +56,7 @@ pub fn parse_enum(
                 quote!(v.attributes),
                 &v.attrs,
                 &container_attrs.skip_attrs,
+                None,
             )?;
 
             Ok((v, variant_attrs, runtime_attrs))
@@ -62,6 +83,7 @@ pub fn parse_enum(
                             .map(|(idx, field)| {
                                 let (mut field_attrs, raw_attrs) =
                                     decode_field_attrs(field, &container_attrs.skip_attrs)?;
+                                reject_unnamed_rename(field, &field_attrs)?;
 
                                 if attrs.inline && idx == 0 {
                                     field_attrs.inline = true;
@@ -85,7 +107,7 @@ pub fn parse_enum(
                             .named
                             .iter()
                             .map(|field| {
-                                let (field_attrs, raw_attrs) =
+                                let (mut field_attrs, raw_attrs) =
                                     decode_field_attrs(field, &container_attrs.skip_attrs)?;
 
                                 let field_ident_str =
@@ -96,6 +118,10 @@ pub fn parse_enum(
                                         )
                                     })?);
 
+                                if field_attrs.rename.is_none() {
+                                    field_attrs.rename =
+                                        attrs.rename_all.map(|rule| rule.apply(&field_ident_str));
+                                }
                                 let field_name = field_ident_str;
 
                                 let inner = construct_field_with_variant_skip(
@@ -128,6 +154,25 @@ pub fn parse_enum(
                 quote!(v.deprecated = #tokens;)
             });
 
+            let variant_rename = attrs
+                .rename
+                .clone()
+                .or_else(|| {
+                    container_attrs
+                        .rename_all
+                        .map(|rule| rule.apply(&variant_ident_str))
+                })
+                .map(|rename| quote!(v.attributes.set_rename(#rename);));
+
+            let variant_discriminant = repr.map(|repr| {
+                let variant_ident = &variant.ident;
+                quote!(v.attributes.set_discriminant(#ident::#variant_ident as #repr);)
+            });
+
+            let variant_values = attrs
+                .values
+                .to_tokens(crate_ref, quote!(v.attributes), None)?;
+
             let type_overridden_attribute = attrs
                 .r#type
                 .as_ref()
@@ -139,15 +184,27 @@ pub fn parse_enum(
                 #field_deprecated
                 #variant_docs
                 #runtime_attrs
+                #variant_rename
+                #variant_discriminant
+                #variant_values
                 #type_overridden_attribute
                 v
             })))
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
+    // Recorded like `#[derive(Serialize_repr, Deserialize_repr)]`, which we can't always see.
+    let serde_repr = container_attrs.serde_repr.then(|| {
+        quote! {
+            e.attributes.insert("serde:container:repr_serialize", true);
+            e.attributes.insert("serde:container:repr_deserialize", true);
+        }
+    });
+
     Ok(quote!({
         let mut e = datatype::Enum::default();
         e.variants = vec![#(#variant_types),*];
+        #serde_repr
         e.into()
     }))
 }
@@ -39,6 +39,14 @@ pub fn construct_field_with_variant_skip(
     // override must not influence serde-behavioral markers.
     let declared_ty_is_option = is_option_type(field_ty);
 
+    // `Default::default()` is evaluated on the declared type, like serde does.
+    let declared_ty = (!container_attrs.generic && !attrs.skip && !variant_skip)
+        .then(|| type_with_inferred_lifetimes(field_ty));
+    let field_values =
+        attrs
+            .values
+            .to_tokens(crate_ref, quote!(field.attributes), declared_ty.as_ref())?;
+
     let field_ty = type_with_inferred_lifetimes(attrs.r#type.as_ref().unwrap_or(field_ty));
 
     let runtime_attrs = build_runtime_attributes(
@@ -47,10 +55,20 @@ pub fn construct_field_with_variant_skip(
         quote!(field.attributes),
         raw_attrs,
         &container_attrs.skip_attrs,
+        None,
     )?;
 
     let field_optional = attrs.optional.then(|| quote!(field.optional = true;));
 
+    let field_rename = attrs
+        .rename
+        .as_ref()
+        .map(|rename| quote!(field.attributes.set_rename(#rename);));
+
+    let field_readonly = attrs
+        .readonly
+        .map(|readonly| quote!(field.attributes.set_readonly(#readonly);));
+
     let field_deprecated = attrs.common.deprecated.map(|deprecated| {
         let tokens = deprecated_as_tokens(deprecated);
         quote!(field.deprecated = #tokens;)
@@ -63,10 +81,8 @@ pub fn construct_field_with_variant_skip(
         }
     });
 
-    let type_overridden_attribute = attrs
-        .r#type
-        .as_ref()
-        .map(|_| quote!(field.attributes.insert("specta:type_override", true);));
+    let type_overridden_attribute = (attrs.r#type.is_some() || attrs.literal.is_some())
+        .then(|| quote!(field.attributes.insert("specta:type_override", true);));
 
     let serde_newtype_skip_ignored = attrs
         .serde_newtype_skip_ignored
@@ -85,6 +101,8 @@ pub fn construct_field_with_variant_skip(
 
     let field_ty = if attrs.skip || variant_skip {
         quote!()
+    } else if let Some(literal) = &attrs.literal {
+        quote!(field.ty = Some(datatype::Literal::new(#literal));)
     } else if attrs.inline {
         quote!(field.ty = Some(datatype::inline(types, |types| <#field_ty as #crate_ref::Type>::definition(types)));)
     } else {
@@ -97,9 +115,12 @@ pub fn construct_field_with_variant_skip(
         #field_deprecated
         #field_docs
         #runtime_attrs
+        #field_rename
+        #field_readonly
         #type_overridden_attribute
         #serde_newtype_skip_ignored
         #nullable_attribute
+        #field_values
         #field_ty
         field
     }))
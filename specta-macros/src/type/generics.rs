@@ -188,7 +188,7 @@ fn visit_field_type(
     let mut attrs = parse_attrs_with_filter(&field.attrs, skip_attrs)?;
     let attrs = FieldAttr::from_attrs(&mut attrs)?;
 
-    if !attrs.skip {
+    if !attrs.skip && attrs.literal.is_none() {
         visitor.visit_type(attrs.r#type.as_ref().unwrap_or(&field.ty));
     }
 
@@ -34,11 +34,13 @@ pub(super) fn build_runtime_attributes(
     attrs: TokenStream,
     raw_attrs: &[syn::Attribute],
     skip_attrs: &[String],
+    renamed_from: Option<&str>,
 ) -> syn::Result<Option<TokenStream>> {
     #[cfg(feature = "serde")]
     let serde_insert = serde::lower_runtime_attributes(
         crate_ref,
         scope,
+        renamed_from,
         &raw_attrs
             .iter()
             .filter(|attr| {
@@ -57,6 +59,7 @@ pub(super) fn build_runtime_attributes(
         let _ = scope;
         let _ = raw_attrs;
         let _ = skip_attrs;
+        let _ = renamed_from;
         None
     };
 
@@ -103,7 +106,12 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
     let raw_attrs = attrs; // Preserve raw attrs before parse_attrs shadows the variable
     let mut attrs = parse_attrs(attrs)?;
 
-    let container_attrs = ContainerAttr::from_attrs(&mut attrs, matches!(data, Data::Struct(_)))?;
+    let mut container_attrs =
+        ContainerAttr::from_attrs(&mut attrs, matches!(data, Data::Struct(_)))?;
+    container_attrs.generic = generics
+        .params
+        .iter()
+        .any(|param| !matches!(param, GenericParam::Lifetime(_)));
     let crate_ref = container_attrs.crate_name.clone().unwrap_or(quote!(specta));
 
     if container_attrs.r#type.is_some() && container_attrs.transparent {
@@ -118,16 +126,22 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
         .clone()
         .unwrap_or_else(|| raw_ident.to_token_stream());
 
-    let name = unraw_raw_ident(&format_ident!("{}", raw_ident.to_string())).to_token_stream();
+    let raw_name = unraw_raw_ident(raw_ident);
+    let name = container_attrs.rename.as_ref().unwrap_or(&raw_name);
 
     reject_unknown_specta_attrs(&attrs, Scope::Container)?;
 
+    // `#[specta(rename = "...")]` only changes the exported name, so serde must still see the
+    // type under its Rust name.
+    let renamed_from = container_attrs.rename.as_ref().map(|_| raw_name.as_str());
+
     let container_runtime_attrs = build_runtime_attributes(
         &crate_ref,
         AttributeScope::Container,
         quote!(s.attributes),
         raw_attrs,
         &container_attrs.skip_attrs,
+        renamed_from,
     )?;
     let enum_runtime_attrs = build_runtime_attributes(
         &crate_ref,
@@ -135,19 +149,37 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
         quote!(en.attributes),
         raw_attrs,
         &container_attrs.skip_attrs,
+        renamed_from,
     )?;
-    let container_runtime_attrs =
-        if container_runtime_attrs.is_some() || enum_runtime_attrs.is_some() {
-            quote! {
-                match &mut e {
-                    datatype::DataType::Struct(s) => { #container_runtime_attrs }
-                    datatype::DataType::Enum(en) => { #enum_runtime_attrs }
-                    _ => unreachable!("specta derive generated non-container datatype"),
-                }
+    let struct_rename = container_attrs
+        .rename
+        .as_ref()
+        .map(|rename| quote!(s.attributes.set_rename(#rename);));
+    let enum_rename = container_attrs
+        .rename
+        .as_ref()
+        .map(|rename| quote!(en.attributes.set_rename(#rename);));
+    let struct_readonly = container_attrs
+        .readonly
+        .map(|readonly| quote!(s.attributes.set_readonly(#readonly);));
+    let enum_readonly = container_attrs
+        .readonly
+        .map(|readonly| quote!(en.attributes.set_readonly(#readonly);));
+    let container_runtime_attrs = if container_runtime_attrs.is_some()
+        || enum_runtime_attrs.is_some()
+        || container_attrs.rename.is_some()
+        || container_attrs.readonly.is_some()
+    {
+        quote! {
+            match &mut e {
+                datatype::DataType::Struct(s) => { #container_runtime_attrs #struct_rename #struct_readonly }
+                datatype::DataType::Enum(en) => { #enum_runtime_attrs #enum_rename #enum_readonly }
+                _ => unreachable!("specta derive generated non-container datatype"),
             }
-        } else {
-            quote!()
-        };
+        }
+    } else {
+        quote!()
+    };
 
     let dt_expr = if let Some(container_ty) = &container_attrs.r#type {
         let container_ty = type_with_inferred_lifetimes(container_ty);
@@ -155,7 +187,7 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
     } else {
         let dt_expr = match data {
             Data::Struct(data) => parse_struct(&crate_ref, &container_attrs, data),
-            Data::Enum(data) => parse_enum(&crate_ref, &container_attrs, data),
+            Data::Enum(data) => parse_enum(&crate_ref, &container_attrs, data, &ident),
             Data::Union(data) => Err(syn::Error::new_spanned(
                 data.union_token,
                 "specta: Union types are not supported by Specta yet!",
@@ -289,12 +321,17 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
             .filter(|param| matches!(param, syn::GenericParam::Type(_)))
             .map(|_| quote! { () });
 
+        let group = match &container_attrs.collect_group {
+            Some(group) => quote!(Some(#group)),
+            None => quote!(None),
+        };
+
         quote! {
             #[doc(hidden)]
             #[allow(unsafe_code, non_snake_case)]
             #[#crate_ref::collect::internal::small_ctor::ctor]
             unsafe fn #export_fn_name() {
-                #crate_ref::collect::internal::register::<#ident<#(#generic_params),*>>();
+                #crate_ref::collect::internal::register::<#ident<#(#generic_params),*>>(#group);
             }
         }
     });
@@ -321,6 +358,13 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
             ndt.docs = Cow::Borrowed(#docs);
         }
     });
+    let tags = (!container_attrs.tags.is_empty()).then(|| {
+        let tags = &container_attrs.tags;
+        quote! {
+            static TAGS: &[Cow<'static, str>] = &[#(Cow::Borrowed(#tags)),*];
+            ndt.tags = Cow::Borrowed(TAGS);
+        }
+    });
     let instantiation_generic_identities = used_generic_types
         .iter()
         .map(|generic| quote!(std::any::type_name::<#generic>()));
@@ -351,6 +395,7 @@ pub fn derive(input: proc_macro::TokenStream) -> syn::Result<proc_macro::TokenSt
                     ndt.module_path = Cow::Borrowed(module_path!());
                     #generics
                     #docs
+                    #tags
                     #deprecated;
 
                     #ndt_ty
@@ -1,6 +1,9 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Attribute, LitStr, Meta, Result, Type, meta::ParseNestedMeta};
+use syn::{
+    Attribute, LitStr, Meta, Path, Result, Token, Type, meta::ParseNestedMeta,
+    punctuated::Punctuated,
+};
 
 use super::AttributeScope;
 
@@ -74,6 +77,8 @@ struct ContainerAttrs {
     into: Option<ConversionType>,
     variant_identifier: bool,
     field_identifier: bool,
+    repr_serialize: bool,
+    repr_deserialize: bool,
 }
 
 #[derive(Default)]
@@ -110,11 +115,24 @@ struct FieldAttrs {
 pub(super) fn lower_runtime_attributes(
     crate_ref: &TokenStream,
     scope: AttributeScope,
+    renamed_from: Option<&str>,
     raw_attrs: &[Attribute],
 ) -> Result<Option<TokenStream>> {
     match scope {
-        AttributeScope::Container => parse_container_attrs(raw_attrs)
-            .map(|attrs| attrs.map(|attrs| lower_container_attrs(crate_ref, attrs))),
+        AttributeScope::Container => parse_container_attrs(raw_attrs).map(|attrs| {
+            attrs.map(|mut attrs| {
+                // The exported name no longer matches serde's, so record serde's explicitly.
+                if let Some(name) = renamed_from {
+                    attrs
+                        .rename_serialize
+                        .get_or_insert_with(|| name.to_string());
+                    attrs
+                        .rename_deserialize
+                        .get_or_insert_with(|| name.to_string());
+                }
+                lower_container_attrs(crate_ref, attrs)
+            })
+        }),
         AttributeScope::Variant => parse_variant_attrs(raw_attrs)
             .map(|attrs| attrs.map(|attrs| lower_variant_attrs(crate_ref, attrs))),
         AttributeScope::Field => parse_field_attrs(raw_attrs)
@@ -135,6 +153,28 @@ fn parse_container_attrs(attrs: &[Attribute]) -> Result<Option<ContainerAttrs>>
         list.parse_nested_meta(|meta| parse_container_meta(&mut parsed, meta))?;
     }
 
+    // `serde_repr` (de)serializes a fieldless enum as its discriminant. A derive macro can only see
+    // the `#[derive(...)]` attributes which come after its own, so this only catches some orders.
+    // `#[specta(serde_repr)]` covers the rest.
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("derive")) {
+        let Meta::List(list) = &attr.meta else {
+            continue;
+        };
+
+        for path in list.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)? {
+            match path
+                .segments
+                .last()
+                .map(|segment| segment.ident.to_string())
+            {
+                Some(ident) if ident == "Serialize_repr" => parsed.repr_serialize = true,
+                Some(ident) if ident == "Deserialize_repr" => parsed.repr_deserialize = true,
+                _ => continue,
+            }
+            found = true;
+        }
+    }
+
     Ok(found.then_some(parsed))
 }
 
@@ -441,6 +481,16 @@ fn lower_container_attrs(crate_ref: &TokenStream, attrs: ContainerAttrs) -> Toke
         "serde:container:field_identifier",
         attrs.field_identifier,
     );
+    push_bool(
+        &mut inserts,
+        "serde:container:repr_serialize",
+        attrs.repr_serialize,
+    );
+    push_bool(
+        &mut inserts,
+        "serde:container:repr_deserialize",
+        attrs.repr_deserialize,
+    );
 
     quote!(#(#inserts)*)
 }
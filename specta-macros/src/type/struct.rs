@@ -22,6 +22,17 @@ pub fn decode_field_attrs<'a>(
     Ok((field_attrs, &field.attrs))
 }
 
+/// `#[specta(rename = "...")]` names an identifier, which tuple fields don't have.
+pub fn reject_unnamed_rename(field: &Field, attrs: &FieldAttr) -> syn::Result<()> {
+    if attrs.rename.is_some() {
+        return Err(syn::Error::new(
+            field.span(),
+            "specta: `#[specta(rename = ...)]` is only supported on named fields",
+        ));
+    }
+    Ok(())
+}
+
 pub fn parse_struct(
     crate_ref: &TokenStream,
     container_attrs: &ContainerAttr,
@@ -74,7 +85,7 @@ pub fn parse_struct(
                 .fields
                 .iter()
                 .map(|field| {
-                    let (field_attrs, raw_attrs) =
+                    let (mut field_attrs, raw_attrs) =
                         decode_field_attrs(field, &container_attrs.skip_attrs)?;
 
                     let field_ident_str =
@@ -84,6 +95,11 @@ pub fn parse_struct(
                                 "specta: named field must have an identifier",
                             )
                         })?);
+                    if field_attrs.rename.is_none() {
+                        field_attrs.rename = container_attrs
+                            .rename_all
+                            .map(|rule| rule.apply(&field_ident_str));
+                    }
                     let field_name = field_ident_str.to_token_stream();
 
                     let inner = construct_field(
@@ -111,6 +127,7 @@ pub fn parse_struct(
                 .map(|field| {
                     let (mut field_attrs, raw_attrs) =
                         decode_field_attrs(field, &container_attrs.skip_attrs)?;
+                    reject_unnamed_rename(field, &field_attrs)?;
                     if is_newtype && field_attrs.serde_skip {
                         // Serde ignores `#[serde(skip)]` on the sole field of a
                         // newtype struct and serializes the bare inner value.
@@ -38,7 +38,9 @@ impl Parse for AttributeValue {
         Ok(match input.peek(Lit) {
             true => Self::Lit(input.parse()?),
             false => {
-                if input.fork().parse::<Path>().is_ok() {
+                // A path followed by more tokens is the start of a larger expression. Eg. `Some(1)`.
+                let fork = input.fork();
+                if fork.parse::<Path>().is_ok() && (fork.is_empty() || fork.peek(Token![,])) {
                     Self::Path(input.parse()?)
                 } else {
                     Self::Expr(input.parse()?)
@@ -108,6 +110,25 @@ impl Attribute {
         }
     }
 
+    pub fn parse_expr(&self) -> Result<Expr> {
+        match &self.value {
+            Some(AttributeValue::Lit(lit)) => Ok(Expr::Lit(syn::ExprLit {
+                attrs: Vec::new(),
+                lit: lit.clone(),
+            })),
+            Some(AttributeValue::Path(path)) => Ok(Expr::Path(syn::ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: path.clone(),
+            })),
+            Some(AttributeValue::Expr(expr)) => Ok(expr.clone()),
+            _ => Err(syn::Error::new(
+                self.value_span(),
+                "specta: expected expression. Eg. `\"hello\"`, `42` or `Some(1)`",
+            )),
+        }
+    }
+
     pub fn parse_type(&self) -> Result<Type> {
         match &self.value {
             Some(AttributeValue::Path(path)) => Ok(Type::Path(TypePath {
@@ -0,0 +1,577 @@
+//! Describing [axum](https://docs.rs/axum) routers as [`Operation`]s.
+//!
+//! axum erases a handler's signature the moment it is routed, so a plain [`axum::Router`] cannot
+//! be asked what it serves. [`ApiRouter`] wraps one and records, beside every route, the operation
+//! its handler's signature describes: extractors become parameters and bodies, the return type
+//! becomes responses. What the router serves and what the document states come from the same
+//! function, so they cannot drift.
+//!
+//! ```rust
+//! use axum::{Json, extract::Path};
+//! use serde::Serialize;
+//! use specta::{Type, Types};
+//! use specta_openapi::{OpenApi, axum::{ApiRouter, get}};
+//!
+//! #[derive(Type, Serialize)]
+//! struct Recipe { name: String }
+//!
+//! async fn recipe(Path(slug): Path<String>) -> Json<Recipe> {
+//!     Json(Recipe { name: slug })
+//! }
+//!
+//! let router = ApiRouter::new().route("/recipes/{slug}", get(recipe));
+//! let document = OpenApi::default()
+//!     .operations(router.operations().iter().cloned())
+//!     .export_document(&Types::default().register::<Recipe>(), specta_serde::Format)
+//!     .unwrap();
+//! assert_eq!(document["paths"]["/recipes/{slug}"]["get"]["operationId"], "recipe");
+//!
+//! let router: axum::Router = router.into_router();
+//! ```
+//!
+//! Handlers are described through [`OperationInput`] and [`OperationOutput`], which say where in
+//! the request or response a type goes. The types themselves are read through
+//! [`FunctionArg`] and [`FunctionResult`], as for `#[specta]` functions. Both traits are implemented
+//! for the common axum extractors and responses; implement them for your own extractors and error
+//! types to describe those too.
+
+use std::{borrow::Cow, future::Future};
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    handler::Handler,
+    http::{HeaderMap, StatusCode, Uri},
+    routing::MethodRouter,
+};
+use axum_extra::{
+    TypedHeader,
+    headers::{self, Header, authorization::Credentials},
+};
+use specta::{
+    Format, Type, Types,
+    datatype::{DataType, Fields, Reference},
+    function::{FunctionArg, FunctionResult, FunctionValueMarker},
+};
+
+use crate::{
+    Method, Operation,
+    operation::{Body, Param, ParameterLocation, Response, capture_arg, capture_result},
+};
+
+/// What an extractor contributes to an operation: parameters, a request body, or nothing.
+///
+/// Implement this for your own extractors. One that reads nothing the caller states - a database
+/// pool, an authenticated session resolved by middleware - returns the operation unchanged.
+pub trait OperationInput {
+    /// Adds this extractor's parameters or body to `operation`.
+    fn describe(operation: Operation) -> Operation;
+}
+
+/// The type a [`TypedHeader`] is documented as.
+///
+/// Implemented for the request headers of [`axum_extra::headers`]. Implement it for your own
+/// [`Header`]s to extract them with [`TypedHeader`].
+pub trait HeaderType: Header {
+    /// The type of the header's value.
+    type Value: Type;
+}
+
+/// What a handler's return type contributes to an operation: its responses.
+///
+/// Implement this for your error types to state the statuses they render as:
+///
+/// ```rust
+/// use specta::Type;
+/// use specta_openapi::{Operation, axum::OperationOutput};
+///
+/// #[derive(Type)]
+/// struct ApiError { message: String }
+///
+/// impl OperationOutput for ApiError {
+///     fn describe(operation: Operation) -> Operation {
+///         operation.response::<ApiError>(404, "No such resource")
+///     }
+/// }
+/// ```
+pub trait OperationOutput {
+    /// Adds this type's responses to `operation`.
+    fn describe(operation: Operation) -> Operation;
+}
+
+/// A handler whose signature can be described as an operation.
+///
+/// Implemented for every function whose arguments implement [`OperationInput`] and whose output
+/// implements [`OperationOutput`]; `T` is the tuple of argument types.
+pub trait OperationHandler<T> {
+    /// Describes the handler's parameters, body and responses on `operation`.
+    fn describe(operation: Operation) -> Operation;
+}
+
+macro_rules! impl_operation_handler {
+    ($($ty:ident),*) => {
+        impl<F, Fut, Res, $($ty,)*> OperationHandler<($($ty,)*)> for F
+        where
+            F: FnOnce($($ty,)*) -> Fut,
+            Fut: Future<Output = Res>,
+            Res: OperationOutput,
+            $($ty: OperationInput,)*
+        {
+            fn describe(operation: Operation) -> Operation {
+                $(let operation = $ty::describe(operation);)*
+                Res::describe(operation)
+            }
+        }
+    };
+}
+
+impl_operation_handler!();
+impl_operation_handler!(T1);
+impl_operation_handler!(T1, T2);
+impl_operation_handler!(T1, T2, T3);
+impl_operation_handler!(T1, T2, T3, T4);
+impl_operation_handler!(T1, T2, T3, T4, T5);
+impl_operation_handler!(T1, T2, T3, T4, T5, T6);
+impl_operation_handler!(T1, T2, T3, T4, T5, T6, T7);
+impl_operation_handler!(T1, T2, T3, T4, T5, T6, T7, T8);
+
+/// Each template segment of `path` is bound, in order, to an element of `T`: a tuple's elements, a
+/// struct's fields by name, or `T` itself when the path has a single parameter.
+impl<T: FunctionArg> OperationInput for Path<T> {
+    fn describe(mut operation: Operation) -> Operation {
+        let names = template_names(&operation.path);
+        let Some(body) = capture_arg::<T>() else {
+            return operation;
+        };
+        let bound: Vec<(Cow<'static, str>, Body)> = match &body.dt {
+            DataType::Tuple(tuple) => names
+                .into_iter()
+                .zip(&tuple.elements)
+                .map(|(name, dt)| (name.into(), with_type(&body, dt.clone())))
+                .collect(),
+            _ => match struct_fields::<T>() {
+                Some(fields) => fields
+                    .into_iter()
+                    .map(|(name, dt, _)| (name, with_type(&body, dt)))
+                    .collect(),
+                None => names
+                    .into_iter()
+                    .take(1)
+                    .map(|name| (name.into(), body.clone()))
+                    .collect(),
+            },
+        };
+        for (name, ty) in bound {
+            operation =
+                operation.parameter(Param::captured(name, ParameterLocation::Path, true, ty));
+        }
+        operation
+    }
+}
+
+/// A struct's fields become one query parameter each, required unless optional or nullable.
+impl<T: FunctionArg> OperationInput for Query<T> {
+    fn describe(operation: Operation) -> Operation {
+        query_parameters::<T>(operation, true)
+    }
+}
+
+/// As [`Query`], with every parameter optional.
+impl<T: FunctionArg> OperationInput for Option<Query<T>> {
+    fn describe(operation: Operation) -> Operation {
+        query_parameters::<T>(operation, false)
+    }
+}
+
+impl<T: FunctionArg> OperationInput for Json<T> {
+    fn describe(mut operation: Operation) -> Operation {
+        if let Some(body) = capture_arg::<T>() {
+            operation.request_body = Some(body);
+        }
+        operation
+    }
+}
+
+/// A required header, named by the typed header's [`Header::name`] and typed by its
+/// [`HeaderType`].
+impl<H: HeaderType> OperationInput for TypedHeader<H> {
+    fn describe(operation: Operation) -> Operation {
+        operation.parameter(Param::header::<H::Value>(H::name().as_str()).required())
+    }
+}
+
+impl<H: HeaderType> OperationInput for Option<TypedHeader<H>> {
+    fn describe(operation: Operation) -> Operation {
+        operation.parameter(Param::header::<H::Value>(H::name().as_str()))
+    }
+}
+
+impl<C: Credentials> HeaderType for headers::Authorization<C> {
+    type Value = String;
+}
+
+impl<C: Credentials> HeaderType for headers::ProxyAuthorization<C> {
+    type Value = String;
+}
+
+macro_rules! impl_header_type {
+    ($($header:ident => $value:ty),* $(,)?) => {
+        $(
+            impl HeaderType for headers::$header {
+                type Value = $value;
+            }
+        )*
+    };
+}
+
+impl_header_type!(
+    AccessControlRequestHeaders => String,
+    AccessControlRequestMethod => String,
+    CacheControl => String,
+    Connection => String,
+    ContentLength => u64,
+    ContentType => String,
+    Cookie => String,
+    Expect => String,
+    Host => String,
+    IfMatch => String,
+    IfModifiedSince => String,
+    IfNoneMatch => String,
+    IfRange => String,
+    IfUnmodifiedSince => String,
+    Origin => String,
+    Pragma => String,
+    Range => String,
+    Referer => String,
+    SecWebsocketKey => String,
+    SecWebsocketVersion => u8,
+    Te => String,
+    Upgrade => String,
+    UserAgent => String,
+);
+
+macro_rules! impl_unstated_input {
+    ($($ty:ty $(where $generic:ident)?),* $(,)?) => {
+        $(
+            impl$(<$generic>)? OperationInput for $ty {
+                fn describe(operation: Operation) -> Operation {
+                    operation
+                }
+            }
+        )*
+    };
+}
+
+impl_unstated_input!(
+    State<S> where S,
+    Extension<T> where T,
+    HeaderMap,
+    axum::http::Method,
+    Uri,
+);
+
+impl<T: FunctionResult<FunctionValueMarker>> OperationOutput for Json<T> {
+    fn describe(mut operation: Operation) -> Operation {
+        operation.responses.push(Response {
+            status: Some(200),
+            description: reason(200).into(),
+            content_type: None,
+            body: Some(capture_result::<T, _>()),
+        });
+        operation
+    }
+}
+
+impl OperationOutput for () {
+    fn describe(operation: Operation) -> Operation {
+        operation.empty_response(200, reason(200))
+    }
+}
+
+impl OperationOutput for String {
+    fn describe(operation: Operation) -> Operation {
+        operation.response_as::<String>(200, reason(200), "text/plain; charset=utf-8")
+    }
+}
+
+impl OperationOutput for &'static str {
+    fn describe(operation: Operation) -> Operation {
+        String::describe(operation)
+    }
+}
+
+impl<T: OperationOutput, E: OperationOutput> OperationOutput for Result<T, E> {
+    fn describe(operation: Operation) -> Operation {
+        E::describe(T::describe(operation))
+    }
+}
+
+/// A bodiless `default` response, as the status is only known at runtime.
+impl OperationOutput for StatusCode {
+    fn describe(mut operation: Operation) -> Operation {
+        operation.responses.push(Response {
+            status: None,
+            description: "Response".into(),
+            content_type: None,
+            body: None,
+        });
+        operation
+    }
+}
+
+/// `T`'s responses, as `default` responses since the status is only known at runtime.
+impl<T: OperationOutput> OperationOutput for (StatusCode, T) {
+    fn describe(operation: Operation) -> Operation {
+        let declared = operation.responses.len();
+        let mut operation = T::describe(operation);
+        for response in &mut operation.responses[declared..] {
+            response.status = None;
+        }
+        operation
+    }
+}
+
+/// A method router that remembers the operation each of its handlers describes.
+///
+/// Built with [`get`], [`post`] and friends, and chained like axum's own.
+pub struct ApiMethodRouter<S = ()> {
+    router: MethodRouter<S>,
+    operations: Vec<PendingOperation>,
+}
+
+struct PendingOperation {
+    method: Method,
+    operation_id: Option<&'static str>,
+    describe: Box<dyn Fn(Operation) -> Operation + Send + Sync>,
+}
+
+macro_rules! method_routers {
+    ($($name:ident => $method:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Routes `", stringify!($method), "` requests to `handler` and records the operation it describes.")]
+            pub fn $name<H, T, D, S>(handler: H) -> ApiMethodRouter<S>
+            where
+                H: Handler<T, S> + OperationHandler<D>,
+                T: 'static,
+                S: Clone + Send + Sync + 'static,
+            {
+                ApiMethodRouter {
+                    router: MethodRouter::new(),
+                    operations: Vec::new(),
+                }
+                .$name(handler)
+            }
+        )*
+
+        impl<S: Clone + Send + Sync + 'static> ApiMethodRouter<S> {
+            $(
+                #[doc = concat!("Chains a handler for `", stringify!($method), "` requests.")]
+                pub fn $name<H, T, D>(mut self, handler: H) -> Self
+                where
+                    H: Handler<T, S> + OperationHandler<D>,
+                    T: 'static,
+                {
+                    self.router = self.router.$name(handler);
+                    self.operations.push(PendingOperation {
+                        method: Method::$method,
+                        operation_id: handler_name::<H>(),
+                        describe: Box::new(<H as OperationHandler<D>>::describe as fn(Operation) -> Operation),
+                    });
+                    self
+                }
+            )*
+        }
+    };
+}
+
+method_routers!(
+    get => Get,
+    post => Post,
+    put => Put,
+    patch => Patch,
+    delete => Delete,
+    head => Head,
+    options => Options,
+    trace => Trace,
+);
+
+impl<S: Clone + Send + Sync + 'static> ApiMethodRouter<S> {
+    /// Amends the operation of the most recently chained handler, to say what its signature cannot:
+    /// a summary, tags, additional responses.
+    pub fn describe(
+        mut self,
+        describe: impl Fn(Operation) -> Operation + Send + Sync + 'static,
+    ) -> Self {
+        if let Some(pending) = self.operations.pop() {
+            let inner = pending.describe;
+            self.operations.push(PendingOperation {
+                describe: Box::new(move |operation| describe(inner(operation))),
+                ..pending
+            });
+        }
+        self
+    }
+}
+
+/// An [`axum::Router`] that records the [`Operation`] behind every route.
+///
+/// Routes are added with [`ApiMethodRouter`]s, and the finished router is taken apart with
+/// [`into_router`](Self::into_router) or [`into_parts`](Self::into_parts).
+pub struct ApiRouter<S = ()> {
+    router: Router<S>,
+    operations: Vec<Operation>,
+}
+
+impl<S: Clone + Send + Sync + 'static> Default for ApiRouter<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Clone + Send + Sync + 'static> ApiRouter<S> {
+    /// Constructs an empty router.
+    pub fn new() -> Self {
+        Self {
+            router: Router::new(),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Adds a route on `path`, recording one operation per method it serves.
+    ///
+    /// Each operation's `operationId` is its handler's function name; closures have none.
+    pub fn route(mut self, path: &str, method_router: ApiMethodRouter<S>) -> Self {
+        self.router = self.router.route(path, method_router.router);
+        for pending in method_router.operations {
+            let mut operation = Operation::new(pending.method, path.to_string());
+            if let Some(operation_id) = pending.operation_id {
+                operation = operation.operation_id(operation_id);
+            }
+            self.operations.push((pending.describe)(operation));
+        }
+        self
+    }
+
+    /// Nests `router` under `prefix`, as [`axum::Router::nest`] does.
+    pub fn nest(mut self, prefix: &str, router: ApiRouter<S>) -> Self {
+        self.router = self.router.nest(prefix, router.router);
+        self.operations
+            .extend(router.operations.into_iter().map(|mut operation| {
+                operation.path = match &*operation.path {
+                    "/" => prefix.to_string().into(),
+                    path => format!("{prefix}{path}").into(),
+                };
+                operation
+            }));
+        self
+    }
+
+    /// Merges the routes of `router`, as [`axum::Router::merge`] does.
+    pub fn merge(mut self, router: ApiRouter<S>) -> Self {
+        self.router = self.router.merge(router.router);
+        self.operations.extend(router.operations);
+        self
+    }
+
+    /// The operations recorded so far, in the order their routes were added.
+    pub fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+
+    /// The underlying router, ready to serve.
+    pub fn into_router(self) -> Router<S> {
+        self.router
+    }
+
+    /// The underlying router and the operations it serves.
+    pub fn into_parts(self) -> (Router<S>, Vec<Operation>) {
+        (self.router, self.operations)
+    }
+}
+
+/// Names of the template segments of `path`, in order. `{*rest}` wildcards are named `rest`.
+fn template_names(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .map(|name| name.trim_start_matches('*').to_string())
+        .collect()
+}
+
+fn query_parameters<T: FunctionArg>(mut operation: Operation, required: bool) -> Operation {
+    let Some(body) = capture_arg::<T>() else {
+        return operation;
+    };
+    match struct_fields::<T>() {
+        Some(fields) => {
+            for (name, dt, optional) in fields {
+                operation = operation.parameter(Param::captured(
+                    name,
+                    ParameterLocation::Query,
+                    required && !optional,
+                    with_type(&body, dt),
+                ));
+            }
+            operation
+        }
+        None => operation.parameter(Param::captured(
+            "query",
+            ParameterLocation::Query,
+            required,
+            body,
+        )),
+    }
+}
+
+/// The named fields of `T` when it is a struct, as serde names them, with whether each may be
+/// omitted. Skipped fields are not part of the request and are left out.
+fn struct_fields<T: FunctionArg>() -> Option<Vec<(Cow<'static, str>, DataType, bool)>> {
+    let mut types = Types::default();
+    let DataType::Reference(Reference::Named(reference)) = T::to_datatype(&mut types)? else {
+        return None;
+    };
+    // An invalid serde type fails the export regardless, so fall back to the unformatted fields.
+    let types = match specta_serde::Format.map_types(&types) {
+        Ok(formatted) => formatted.into_owned(),
+        Err(_) => types,
+    };
+    let Some(DataType::Struct(strct)) = types.get(&reference).and_then(|ndt| ndt.ty.clone()) else {
+        return None;
+    };
+    let Fields::Named(fields) = strct.fields else {
+        return None;
+    };
+    Some(
+        fields
+            .fields
+            .into_iter()
+            .filter_map(|(name, field)| {
+                let dt = field.ty?;
+                let optional = field.optional || matches!(dt, DataType::Nullable(_));
+                Some((name, dt, optional))
+            })
+            .collect(),
+    )
+}
+
+fn with_type(body: &Body, dt: DataType) -> Body {
+    Body {
+        dt,
+        type_name: body.type_name,
+    }
+}
+
+/// The function name of a handler, for its `operationId`. Closures and other unnameable handlers
+/// have none.
+fn handler_name<H>() -> Option<&'static str> {
+    let name = std::any::type_name::<H>().rsplit("::").next()?;
+    name.chars()
+        .all(|character| character.is_ascii_alphanumeric() || character == '_')
+        .then_some(name)
+}
+
+fn reason(status: u16) -> &'static str {
+    StatusCode::from_u16(status)
+        .ok()
+        .and_then(|status| status.canonical_reason())
+        .unwrap_or("Response")
+}
@@ -26,7 +26,8 @@ pub enum Error {
         feature: &'static str,
     },
 
-    /// Two JSON Schema definition names map to the same OpenAPI component name.
+    /// Two JSON Schema definition names map to the same OpenAPI component name,
+    /// or two components or paths map to the same file of a split document.
     #[error("OpenAPI definition name collision for {name:?}: {first:?} and {second:?}")]
     DefinitionNameCollision {
         /// Colliding OpenAPI component name.
@@ -86,8 +87,8 @@ pub enum Error {
     #[error("cannot add components to a non-object OpenAPI document")]
     InvalidTargetDocument,
 
-    /// A request-body example could not be serialized to JSON.
-    #[error("failed to serialize the request-body example for {path:?}: {message}")]
+    /// An example could not be serialized to JSON.
+    #[error("failed to serialize an example for {path:?}: {message}")]
     ExampleSerialization {
         /// Templated path of the operation carrying the example.
         path: String,
@@ -95,6 +96,47 @@ pub enum Error {
         message: String,
     },
 
+    /// An example does not match the schema of what it exemplifies.
+    #[error("example {example:?} of operation {operation} does not match its schema: {reason}")]
+    InvalidExample {
+        /// Method and templated path of the operation carrying the example.
+        operation: String,
+        /// Name of the example; an unnamed one is reported as `example`.
+        example: String,
+        /// The first mismatch found.
+        reason: String,
+    },
+    /// An operation has both an unnamed example and a named one called `example`, which the
+    /// unnamed one is exported as alongside named examples.
+    #[error(
+        "operation {operation} has both an unnamed example and one named \"example\"; rename the named example"
+    )]
+    DuplicateExample {
+        /// Method and templated path of the operation carrying the examples.
+        operation: String,
+    },
+
+    /// Two operations, or an operation and the client's own declarations, render to the same
+    /// TypeScript name.
+    #[error("TypeScript client name collision for {name:?}: {first} and {second}")]
+    ClientNameCollision {
+        /// Colliding TypeScript name.
+        name: String,
+        /// What claimed the name first.
+        first: String,
+        /// What claimed it second.
+        second: String,
+    },
+
+    /// A path template names a segment no path parameter of the operation declares.
+    #[error("operation {operation} has no path parameter for template segment {name:?}")]
+    UndeclaredPathParameter {
+        /// Method and templated path of the operation.
+        operation: String,
+        /// Name of the template segment.
+        name: String,
+    },
+
     /// A directory could not be created.
     #[error("failed to create output directory {path:?}: {source}")]
     CreateDir {
@@ -104,6 +146,35 @@ pub enum Error {
         source: io::Error,
     },
 
+    /// A file of a split document could not be read.
+    #[error("failed to read OpenAPI document {path:?}: {source}")]
+    ReadFile {
+        /// File path.
+        path: PathBuf,
+        /// Underlying filesystem error.
+        source: io::Error,
+    },
+
+    /// A file of a split document is not valid JSON or YAML.
+    #[error("failed to parse OpenAPI document {path:?}: {message}")]
+    ParseFile {
+        /// File path.
+        path: PathBuf,
+        /// Parser error message.
+        message: String,
+    },
+
+    /// A split document references something other than a whole local file.
+    #[error(
+        "cannot bundle reference {reference:?} in {path:?}: only references to whole local files are supported"
+    )]
+    UnsupportedReference {
+        /// The reference as written.
+        reference: String,
+        /// File containing the reference.
+        path: PathBuf,
+    },
+
     /// The exported document could not be written.
     #[error("failed to write OpenAPI document {path:?}: {source}")]
     WriteFile {
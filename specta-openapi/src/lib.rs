@@ -67,6 +67,16 @@
 //! OpenAPI 3.0 cannot mark `nullable` beside a `$ref` — along with null-only
 //! types, heterogeneous tuples, constrained map keys, and closed flattened
 //! intersections.
+//!
+//! # Framework integration
+//!
+//! With the `axum` feature, [`axum::ApiRouter`] records the operation each
+//! route's handler describes while building the router, so endpoints need not
+//! be restated by hand.
+//!
+//! With the `typescript` feature, [`typescript::TypescriptClient`] renders a
+//! `fetch` client for the described operations that names the same type
+//! declarations as `specta-typescript`'s output.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc(
@@ -74,13 +84,22 @@
     html_favicon_url = "https://github.com/specta-rs/specta/raw/main/.github/logo-128.png"
 )]
 
+#[cfg(feature = "axum")]
+#[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
+pub mod axum;
 mod error;
 mod openapi;
 mod operation;
 mod paths;
 mod resolve;
+mod split;
 mod transform;
+#[cfg(feature = "typescript")]
+#[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
+pub mod typescript;
+mod validate;
 
 pub use error::Error;
 pub use openapi::{OasVersion, OpenApi, OutputFormat, SchemaMode};
-pub use operation::{Method, Operation, Param};
+pub use operation::{Example, Method, Operation, Param};
+pub use split::bundle;
@@ -3,7 +3,7 @@ use std::{borrow::Cow, collections::BTreeMap, path::Path};
 use serde_json::{Map, Value, json};
 use specta::{Format, Types};
 
-use crate::{Error, operation::Operation, resolve::resolve};
+use crate::{Error, operation::Operation, resolve::resolve, validate::Validator};
 
 /// OpenAPI Specification version of the emitted document.
 ///
@@ -47,7 +47,8 @@ pub enum SchemaMode {
     Compatible,
 }
 
-/// Serialization format used by [`OpenApi::export`] and [`OpenApi::export_to`].
+/// Serialization format used by [`OpenApi::export`], [`OpenApi::export_to`]
+/// and [`OpenApi::export_split`].
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum OutputFormat {
     /// Pretty-printed JSON.
@@ -91,7 +92,7 @@ pub struct OpenApi {
     output_format: OutputFormat,
     oas_version: OasVersion,
     schema_mode: SchemaMode,
-    operations: Vec<Operation>,
+    pub(crate) operations: Vec<Operation>,
     servers: Vec<Server>,
     tags: Vec<Tag>,
     contact: Option<Contact>,
@@ -297,7 +298,7 @@ impl OpenApi {
         let paths = if self.operations.is_empty() {
             Value::Object(Map::new())
         } else {
-            crate::paths::paths(&self.operations, &resolved)?
+            crate::paths::paths(&self.operations, &resolved, &Validator::new(&schemas))?
         };
 
         let mut components = Map::new();
@@ -427,6 +428,35 @@ impl OpenApi {
         }
     }
 
+    /// Export the supplied types as a directory of JSON or YAML files: one per
+    /// schema component under `components/schemas/`, one per path under
+    /// `paths/`, and the root `openapi.json` or `openapi.yaml` linking them by
+    /// relative `$ref`s. [`bundle`](crate::bundle) reassembles the directory.
+    ///
+    /// Components whose names differ only in case are rejected with
+    /// [`Error::DefinitionNameCollision`] rather than overwriting one another.
+    /// Paths that map to the same file name, such as `/a/b` and `/a_b`, are
+    /// told apart by a numeric suffix: `a_b` and `a_b_2`. Files for components or paths that
+    /// are no longer exported are left in place.
+    pub fn export_split(
+        &self,
+        dir: impl AsRef<Path>,
+        types: &Types,
+        format: impl Format,
+    ) -> Result<(), Error> {
+        let dir = dir.as_ref();
+        let document = self.export_document(types, format)?;
+        for (path, value) in crate::split::split(document, self.output_format)? {
+            let path = dir.join(path);
+            let output = match self.output_format {
+                OutputFormat::Json => serde_json::to_string_pretty(&value)?,
+                OutputFormat::Yaml => serde_yaml::to_string(&value)?,
+            };
+            write(&path, output)?;
+        }
+        Ok(())
+    }
+
     /// Export the supplied types to a JSON or YAML file.
     pub fn export_to(
         &self,
@@ -434,17 +464,20 @@ impl OpenApi {
         types: &Types,
         format: impl Format,
     ) -> Result<(), Error> {
-        let path = path.as_ref();
         let output = self.export(types, format)?;
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent).map_err(|source| Error::CreateDir {
-                path: parent.to_path_buf(),
-                source,
-            })?;
-        }
-        std::fs::write(path, output).map_err(|source| Error::WriteFile {
-            path: path.to_path_buf(),
+        write(path.as_ref(), output)
+    }
+}
+
+fn write(path: &Path, output: String) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|source| Error::CreateDir {
+            path: parent.to_path_buf(),
             source,
-        })
+        })?;
     }
+    std::fs::write(path, output).map_err(|source| Error::WriteFile {
+        path: path.to_path_buf(),
+        source,
+    })
 }
@@ -2,6 +2,8 @@
 
 use std::borrow::Cow;
 
+#[cfg(feature = "axum")]
+use specta::function::{FunctionArg, FunctionResult};
 use specta::{Type, Types, datatype::DataType};
 
 /// HTTP method an [`Operation`] is served on.
@@ -40,9 +42,57 @@ pub(crate) struct Parameter {
     pub(crate) required: bool,
     pub(crate) description: Option<Cow<'static, str>>,
     pub(crate) example: Option<serde_json::Value>,
+    pub(crate) examples: Vec<(Cow<'static, str>, Example)>,
     pub(crate) ty: Body,
 }
 
+/// A named example of a request body, response, or parameter: a real value,
+/// serialized through the same serde path as production traffic.
+///
+/// Every example is validated against the schema of what it exemplifies when
+/// the document is exported, so one that no longer matches its type fails the
+/// export rather than the reader.
+///
+/// ```rust
+/// # use specta_openapi::{Example, Operation};
+/// # use serde_json::json;
+/// let operation = Operation::post("/recipes").request_body_example(
+///     "minimal",
+///     Example::new(json!({ "slug": "pancakes" })).summary("Only the required fields"),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub(crate) summary: Option<Cow<'static, str>>,
+    pub(crate) description: Option<Cow<'static, str>>,
+    /// Serialized eagerly; a failure is carried as the error message and
+    /// raised loudly at export rather than dropped.
+    pub(crate) value: Result<serde_json::Value, String>,
+}
+
+impl Example {
+    /// An example of `value`.
+    pub fn new<T: serde::Serialize>(value: T) -> Self {
+        Self {
+            summary: None,
+            description: None,
+            value: serde_json::to_value(&value).map_err(|error| error.to_string()),
+        }
+    }
+
+    /// Sets the example's short summary.
+    pub fn summary(mut self, summary: impl Into<Cow<'static, str>>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// Sets the example's long description.
+    pub fn description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
 /// A parameter under construction: [`Param::path`], [`Param::query`], or
 /// [`Param::header`] pick the location and type, and the builder methods add
 /// what the bare [`Operation`] conveniences cannot say.
@@ -71,6 +121,7 @@ impl Param {
             required: true,
             description: None,
             example: None,
+            examples: Vec::new(),
             ty: capture::<T>(),
         })
     }
@@ -83,6 +134,7 @@ impl Param {
             required: false,
             description: None,
             example: None,
+            examples: Vec::new(),
             ty: capture::<T>(),
         })
     }
@@ -95,6 +147,7 @@ impl Param {
             required: false,
             description: None,
             example: None,
+            examples: Vec::new(),
             ty: capture::<T>(),
         })
     }
@@ -116,6 +169,32 @@ impl Param {
         self.0.example = Some(example);
         self
     }
+
+    /// Adds a named example to the parameter's `examples`.
+    pub fn named_example(mut self, name: impl Into<Cow<'static, str>>, example: Example) -> Self {
+        self.0.examples.push((name.into(), example));
+        self
+    }
+
+    /// A parameter whose type is already captured, such as one field of an
+    /// extractor's struct.
+    #[cfg(feature = "axum")]
+    pub(crate) fn captured(
+        name: impl Into<Cow<'static, str>>,
+        location: ParameterLocation,
+        required: bool,
+        ty: Body,
+    ) -> Self {
+        Self(Parameter {
+            name: name.into(),
+            location,
+            required,
+            description: None,
+            example: None,
+            examples: Vec::new(),
+            ty,
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -128,7 +207,8 @@ pub(crate) struct Body {
 
 #[derive(Debug, Clone)]
 pub(crate) struct Response {
-    pub(crate) status: u16,
+    /// `None` for the `default` response, which covers every status not declared otherwise.
+    pub(crate) status: Option<u16>,
     pub(crate) description: Cow<'static, str>,
     pub(crate) content_type: Option<Cow<'static, str>>,
     pub(crate) body: Option<Body>,
@@ -169,7 +249,9 @@ pub struct Operation {
     /// Serialized eagerly; a failure is carried as the error message and
     /// raised loudly at export rather than dropped.
     pub(crate) request_body_example: Option<Result<serde_json::Value, String>>,
+    pub(crate) request_body_examples: Vec<(Cow<'static, str>, Example)>,
     pub(crate) responses: Vec<Response>,
+    pub(crate) response_examples: Vec<(u16, Cow<'static, str>, Example)>,
     pub(crate) security: Vec<SecurityRequirement>,
 }
 
@@ -189,7 +271,9 @@ impl Operation {
             parameters: Vec::new(),
             request_body: None,
             request_body_example: None,
+            request_body_examples: Vec::new(),
             responses: Vec::new(),
+            response_examples: Vec::new(),
             security: Vec::new(),
         }
     }
@@ -287,6 +371,28 @@ impl Operation {
         self
     }
 
+    /// Adds a named example to the request body's `examples`.
+    pub fn request_body_example(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        example: Example,
+    ) -> Self {
+        self.request_body_examples.push((name.into(), example));
+        self
+    }
+
+    /// Adds a named example to the `examples` of the response declared for
+    /// `status`, which must have a body.
+    pub fn response_example(
+        mut self,
+        status: u16,
+        name: impl Into<Cow<'static, str>>,
+        example: Example,
+    ) -> Self {
+        self.response_examples.push((status, name.into(), example));
+        self
+    }
+
     /// Declares a JSON response body of `T` for `status`.
     ///
     /// Call once per status an endpoint can return; the multi-status case has no single Rust return
@@ -297,7 +403,21 @@ impl Operation {
         description: impl Into<Cow<'static, str>>,
     ) -> Self {
         self.responses.push(Response {
-            status,
+            status: Some(status),
+            description: description.into(),
+            content_type: None,
+            body: Some(capture::<T>()),
+        });
+        self
+    }
+
+    /// Declares a JSON response body of `T` for every status not declared otherwise, OpenAPI's
+    /// `default` response.
+    ///
+    /// For endpoints whose status is only decided at runtime, such as errors carrying their own.
+    pub fn default_response<T: Type>(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.responses.push(Response {
+            status: None,
             description: description.into(),
             content_type: None,
             body: Some(capture::<T>()),
@@ -315,7 +435,7 @@ impl Operation {
         content_type: impl Into<Cow<'static, str>>,
     ) -> Self {
         self.responses.push(Response {
-            status,
+            status: Some(status),
             description: description.into(),
             content_type: Some(content_type.into()),
             body: Some(capture::<T>()),
@@ -372,7 +492,7 @@ impl Operation {
         description: impl Into<Cow<'static, str>>,
     ) -> Self {
         self.responses.push(Response {
-            status,
+            status: Some(status),
             description: description.into(),
             content_type: None,
             body: None,
@@ -381,11 +501,32 @@ impl Operation {
     }
 }
 
+/// Captures the type of a handler argument through [`FunctionArg`], which is `None` for an argument
+/// that isn't part of the request.
+#[cfg(feature = "axum")]
+pub(crate) fn capture_arg<T: FunctionArg>() -> Option<Body> {
+    let mut scratch = Types::default();
+    Some(Body {
+        dt: T::to_datatype(&mut scratch)?,
+        type_name: std::any::type_name::<T>(),
+    })
+}
+
+/// Captures the type of a handler's response body through [`FunctionResult`].
+#[cfg(feature = "axum")]
+pub(crate) fn capture_result<T: FunctionResult<M>, M>() -> Body {
+    let mut scratch = Types::default();
+    Body {
+        dt: T::to_datatype(&mut scratch),
+        type_name: std::any::type_name::<T>(),
+    }
+}
+
 /// Captures `T`'s datatype so it can be resolved against the exporter's collection later.
 ///
 /// Taken from a scratch collection: a derived type's identity is its module path and name, so the
 /// result stays resolvable against whichever collection is exported.
-fn capture<T: Type>() -> Body {
+pub(crate) fn capture<T: Type>() -> Body {
     let mut scratch = Types::default();
     Body {
         dt: T::definition(&mut scratch),
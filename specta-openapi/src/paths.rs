@@ -1,20 +1,27 @@
 //! Lowering of [`Operation`]s into the document's `paths` object.
 
+use std::borrow::Cow;
+
 use serde_json::{Map, Value, json};
 
 use crate::{
     Error,
-    operation::{Body, Method, Operation, ParameterLocation},
+    operation::{Body, Example, Method, Operation, ParameterLocation},
     resolve::Resolved,
+    validate::Validator,
 };
 
 const JSON: &str = "application/json";
 
-pub(crate) fn paths(operations: &[Operation], resolved: &Resolved) -> Result<Value, Error> {
+pub(crate) fn paths(
+    operations: &[Operation],
+    resolved: &Resolved,
+    validator: &Validator<'_>,
+) -> Result<Value, Error> {
     let mut paths = Map::new();
 
     for operation in operations {
-        let lowered = lower(operation, resolved)?;
+        let lowered = lower(operation, resolved, validator)?;
         let item = paths
             .entry(operation.path.to_string())
             .or_insert_with(|| Value::Object(Map::new()));
@@ -35,7 +42,7 @@ pub(crate) fn paths(operations: &[Operation], resolved: &Resolved) -> Result<Val
     Ok(Value::Object(paths))
 }
 
-fn method_key(method: Method) -> &'static str {
+pub(crate) fn method_key(method: Method) -> &'static str {
     match method {
         Method::Get => "get",
         Method::Post => "post",
@@ -48,25 +55,52 @@ fn method_key(method: Method) -> &'static str {
     }
 }
 
-fn lower(operation: &Operation, resolved: &Resolved) -> Result<Value, Error> {
+fn lower(
+    operation: &Operation,
+    resolved: &Resolved,
+    validator: &Validator<'_>,
+) -> Result<Value, Error> {
     if operation.responses.is_empty() {
         return Err(Error::OperationWithoutResponses {
             path: operation.path.to_string(),
         });
     }
 
+    if let Some((status, name, _)) = operation.response_examples.iter().find(|(status, ..)| {
+        !operation
+            .responses
+            .iter()
+            .any(|response| response.status == Some(*status) && response.body.is_some())
+    }) {
+        return Err(Error::InvalidExample {
+            operation: label(operation),
+            example: name.to_string(),
+            reason: format!("no response with a body is declared for status {status}"),
+        });
+    }
+
     let mut responses = Map::new();
     for response in &operation.responses {
         let mut object = Map::new();
         object.insert("description".to_string(), json!(response.description));
         if let Some(body) = &response.body {
             let content_type = response.content_type.as_deref().unwrap_or(JSON);
-            object.insert(
-                "content".to_string(),
-                json!({ content_type: { "schema": response_schema_of(body, resolved)? } }),
-            );
+            let schema = response_schema_of(body, resolved)?;
+            let mut media = Map::new();
+            let examples = operation
+                .response_examples
+                .iter()
+                .filter(|(status, ..)| Some(*status) == response.status)
+                .map(|(_, name, example)| (name, example));
+            insert_examples(&mut media, None, examples, &schema, operation, validator)?;
+            media.insert("schema".to_string(), schema);
+            object.insert("content".to_string(), json!({ content_type: media }));
         }
-        responses.insert(response.status.to_string(), Value::Object(object));
+        let status = match response.status {
+            Some(status) => status.to_string(),
+            None => "default".to_string(),
+        };
+        responses.insert(status, Value::Object(object));
     }
 
     let mut object = Map::new();
@@ -89,23 +123,34 @@ fn lower(operation: &Operation, resolved: &Resolved) -> Result<Value, Error> {
                 operation
                     .parameters
                     .iter()
-                    .map(|parameter| self::parameter(parameter, resolved))
+                    .map(|parameter| self::parameter(parameter, operation, resolved, validator))
                     .collect::<Result<_, _>>()?,
             ),
         );
     }
     if let Some(body) = &operation.request_body {
+        let schema = request_schema_of(body, resolved)?;
         let mut media = Map::new();
-        media.insert("schema".to_string(), request_schema_of(body, resolved)?);
-        if let Some(example) = &operation.request_body_example {
-            let example = example
-                .clone()
-                .map_err(|message| Error::ExampleSerialization {
-                    path: operation.path.to_string(),
-                    message,
-                })?;
-            media.insert("example".to_string(), example);
-        }
+        let example = operation
+            .request_body_example
+            .clone()
+            .transpose()
+            .map_err(|message| Error::ExampleSerialization {
+                path: operation.path.to_string(),
+                message,
+            })?;
+        insert_examples(
+            &mut media,
+            example,
+            operation
+                .request_body_examples
+                .iter()
+                .map(|(name, example)| (name, example)),
+            &schema,
+            operation,
+            validator,
+        )?;
+        media.insert("schema".to_string(), schema);
         object.insert(
             "requestBody".to_string(),
             json!({
@@ -139,7 +184,12 @@ fn lower(operation: &Operation, resolved: &Resolved) -> Result<Value, Error> {
 }
 
 /// Lowers a parameter, carrying the schema of whatever the extractor parses it into.
-fn parameter(parameter: &crate::operation::Parameter, resolved: &Resolved) -> Result<Value, Error> {
+fn parameter(
+    parameter: &crate::operation::Parameter,
+    operation: &Operation,
+    resolved: &Resolved,
+    validator: &Validator<'_>,
+) -> Result<Value, Error> {
     let mut object = Map::new();
     object.insert("name".to_string(), json!(parameter.name));
     object.insert(
@@ -156,16 +206,95 @@ fn parameter(parameter: &crate::operation::Parameter, resolved: &Resolved) -> Re
     if parameter.required {
         object.insert("required".to_string(), json!(true));
     }
-    if let Some(example) = &parameter.example {
-        object.insert("example".to_string(), example.clone());
-    }
-    object.insert(
-        "schema".to_string(),
-        request_schema_of(&parameter.ty, resolved)?,
-    );
+    let schema = request_schema_of(&parameter.ty, resolved)?;
+    insert_examples(
+        &mut object,
+        parameter.example.clone(),
+        parameter
+            .examples
+            .iter()
+            .map(|(name, example)| (name, example)),
+        &schema,
+        operation,
+        validator,
+    )?;
+    object.insert("schema".to_string(), schema);
     Ok(Value::Object(object))
 }
 
+/// Name an unnamed `example` is reported under, and kept under when it has to
+/// join named `examples` - OpenAPI forbids carrying both.
+const UNNAMED_EXAMPLE: &str = "example";
+
+/// Validates an object's examples against `schema` and inserts them: a lone
+/// unnamed example as `example`, anything else as the `examples` map.
+fn insert_examples<'a>(
+    object: &mut Map<String, Value>,
+    example: Option<Value>,
+    examples: impl Iterator<Item = (&'a Cow<'static, str>, &'a Example)>,
+    schema: &Value,
+    operation: &Operation,
+    validator: &Validator<'_>,
+) -> Result<(), Error> {
+    let validate = |name: &str, value: &Value| {
+        validator
+            .validate(schema, value)
+            .map_err(|reason| Error::InvalidExample {
+                operation: label(operation),
+                example: name.to_string(),
+                reason,
+            })
+    };
+
+    let mut named = Map::new();
+    for (name, example) in examples {
+        let value = example
+            .value
+            .clone()
+            .map_err(|message| Error::ExampleSerialization {
+                path: operation.path.to_string(),
+                message,
+            })?;
+        validate(name, &value)?;
+        let mut object = Map::new();
+        if let Some(summary) = &example.summary {
+            object.insert("summary".to_string(), json!(summary));
+        }
+        if let Some(description) = &example.description {
+            object.insert("description".to_string(), json!(description));
+        }
+        object.insert("value".to_string(), value);
+        named.insert(name.to_string(), Value::Object(object));
+    }
+
+    if let Some(example) = example {
+        validate(UNNAMED_EXAMPLE, &example)?;
+        if named.is_empty() {
+            object.insert("example".to_string(), example);
+            return Ok(());
+        }
+        if named.contains_key(UNNAMED_EXAMPLE) {
+            return Err(Error::DuplicateExample {
+                operation: label(operation),
+            });
+        }
+        named.insert(UNNAMED_EXAMPLE.to_string(), json!({ "value": example }));
+    }
+    if !named.is_empty() {
+        object.insert("examples".to_string(), Value::Object(named));
+    }
+    Ok(())
+}
+
+/// How errors name an operation: its method and path.
+pub(crate) fn label(operation: &Operation) -> String {
+    format!(
+        "{} {}",
+        method_key(operation.method).to_uppercase(),
+        operation.path
+    )
+}
+
 /// What the exporter emitted for a request-side type - a body or parameter,
 /// resolved through the deserialize phase: a `$ref` when it has a component,
 /// its schema in place when it does not.
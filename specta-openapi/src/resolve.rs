@@ -180,7 +180,7 @@ pub(crate) fn resolve(
 ///
 /// `#[specta(inline)]` and recursive-inline references are written out at the use site instead, so
 /// they never become one.
-fn is_component(reference: &NamedReference) -> bool {
+pub(crate) fn is_component(reference: &NamedReference) -> bool {
     matches!(
         reference.inner,
         specta::datatype::NamedReferenceType::Reference { .. }
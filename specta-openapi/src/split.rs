@@ -0,0 +1,332 @@
+//! Splitting a document into one file per schema component and per path, and bundling such a
+//! directory back into a single document.
+//!
+//! A split document keeps its root file, whose `paths` entries and `components.schemas` entries
+//! are replaced by relative `$ref`s to `paths/*` and `components/schemas/*` files. References
+//! between schemas become relative file references too, so every file is readable on its own and
+//! any OpenAPI tool that follows external references can load the root directly.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Component, Path, PathBuf},
+};
+
+use serde_json::{Map, Value, json};
+
+use crate::{
+    Error, OutputFormat,
+    transform::{component_name, unique_names},
+};
+
+const SCHEMAS_DIR: &str = "components/schemas";
+const PATHS_DIR: &str = "paths";
+const COMPONENT_REF: &str = "#/components/schemas/";
+
+/// Splits `document` into files, keyed by their path relative to the output directory. The root
+/// document is `openapi.json` or `openapi.yaml`.
+pub(crate) fn split(
+    mut document: Value,
+    format: OutputFormat,
+) -> Result<Vec<(PathBuf, Value)>, Error> {
+    let extension = extension(format);
+    let mut files = Vec::new();
+
+    let schemas = document
+        .pointer_mut("/components/schemas")
+        .and_then(Value::as_object_mut)
+        .map(std::mem::take)
+        .unwrap_or_default();
+    // Component names are already unique, but two that differ only in case would share a file
+    // on case-insensitive filesystems.
+    unique_names(schemas.keys().map(String::as_str), str::to_ascii_lowercase)?;
+    let mut references = Map::new();
+    for (name, mut schema) in schemas {
+        rewrite_refs(&mut schema, "", extension);
+        let file = format!("{SCHEMAS_DIR}/{name}.{extension}");
+        references.insert(name, json!({ "$ref": file }));
+        files.push((PathBuf::from(file), schema));
+    }
+    if let Some(schemas) = document
+        .pointer_mut("/components")
+        .and_then(Value::as_object_mut)
+        .filter(|_| !references.is_empty())
+    {
+        schemas.insert("schemas".to_string(), Value::Object(references));
+    }
+
+    if let Some(paths) = document.get_mut("paths").and_then(Value::as_object_mut) {
+        let stems = path_file_stems(paths.keys().map(String::as_str));
+        for (path, item) in paths.iter_mut() {
+            let Some(stem) = stems.get(path) else {
+                continue;
+            };
+            let file = format!("{PATHS_DIR}/{stem}.{extension}");
+            let mut item = std::mem::replace(item, json!({ "$ref": file }));
+            rewrite_refs(&mut item, "../components/schemas/", extension);
+            files.push((PathBuf::from(file), item));
+        }
+    }
+
+    files.push((PathBuf::from(format!("openapi.{extension}")), document));
+    Ok(files)
+}
+
+/// Bundles a split document back into a single one, the inverse of
+/// [`OpenApi::export_split`](crate::OpenApi::export_split).
+///
+/// `root` is the document's root file. Every external `$ref` it reaches, directly or through the
+/// files it references, must name a whole local JSON or YAML file: path items are inlined into
+/// `paths`, and schemas become `components.schemas` entries - under the name the root gives them,
+/// or their file stem when it gives none - referenced by `#/components/schemas/...`.
+///
+/// ```rust,no_run
+/// let document = specta_openapi::bundle("openapi/openapi.yaml").unwrap();
+/// ```
+pub fn bundle(root: impl AsRef<Path>) -> Result<Value, Error> {
+    let root = root.as_ref();
+    let base = root.parent().unwrap_or(Path::new(""));
+    let mut bundler = Bundler::default();
+    let mut document = read(root)?;
+
+    // Register the root's component files first, so references to them resolve to the names the
+    // root gives them rather than to their file stems.
+    let mut components = Vec::new();
+    if let Some(schemas) = document
+        .pointer_mut("/components/schemas")
+        .and_then(Value::as_object_mut)
+    {
+        for (name, schema) in schemas.iter_mut() {
+            if let Some(reference) = external_ref(schema) {
+                let file = bundler.locate(reference, root, base)?;
+                bundler.register(name.clone(), file.clone())?;
+                components.push(file);
+            }
+        }
+        for schema in schemas.values_mut() {
+            if external_ref(schema).is_none() {
+                bundler.rewrite(schema, root, base)?;
+            }
+        }
+    }
+    bundler.pending.extend(components);
+
+    if let Some(paths) = document.get_mut("paths").and_then(Value::as_object_mut) {
+        for item in paths.values_mut() {
+            if let Some(reference) = external_ref(item) {
+                let file = bundler.locate(reference, root, base)?;
+                *item = bundler.load(&file)?;
+            } else {
+                bundler.rewrite(item, root, base)?;
+            }
+        }
+    }
+
+    while let Some(file) = bundler.pending.pop() {
+        let schema = bundler.load(&file)?;
+        if let Some(name) = bundler.files.get(&file) {
+            bundler.schemas.insert(name.clone(), schema);
+        }
+    }
+
+    let schemas = document
+        .as_object_mut()
+        .ok_or(Error::InvalidTargetDocument)?
+        .entry("components")
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .ok_or(Error::InvalidTargetDocument)?
+        .entry("schemas")
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .ok_or(Error::InvalidTargetDocument)?;
+    schemas.extend(bundler.schemas);
+    Ok(document)
+}
+
+#[derive(Default)]
+struct Bundler {
+    /// Component name of every schema file referenced so far.
+    files: BTreeMap<PathBuf, String>,
+    /// The file behind every component name, to detect two files claiming one name.
+    names: BTreeMap<String, PathBuf>,
+    /// Schema files referenced but not yet loaded.
+    pending: Vec<PathBuf>,
+    /// Loaded schemas, by component name.
+    schemas: BTreeMap<String, Value>,
+}
+
+impl Bundler {
+    fn register(&mut self, name: String, file: PathBuf) -> Result<(), Error> {
+        if let Some(first) = self.names.get(&name)
+            && *first != file
+        {
+            return Err(Error::DefinitionNameCollision {
+                name,
+                first: first.display().to_string(),
+                second: file.display().to_string(),
+            });
+        }
+        self.names.insert(name.clone(), file.clone());
+        self.files.insert(file, name);
+        Ok(())
+    }
+
+    /// Reads `file`, rewriting its external references to components.
+    fn load(&mut self, file: &Path) -> Result<Value, Error> {
+        let mut value = read(file)?;
+        self.rewrite(&mut value, file, file.parent().unwrap_or(Path::new("")))?;
+        Ok(value)
+    }
+
+    /// Rewrites every external `$ref` in `value`, which was read from `file` in `base`, to a
+    /// component reference, queueing files seen for the first time.
+    fn rewrite(&mut self, value: &mut Value, file: &Path, base: &Path) -> Result<(), Error> {
+        match value {
+            Value::Object(object) => {
+                if let Some(Value::String(reference)) = object.get_mut("$ref")
+                    && !reference.starts_with('#')
+                {
+                    let target = self.locate(reference, file, base)?;
+                    let name = match self.files.get(&target) {
+                        Some(name) => name.clone(),
+                        None => {
+                            let name = component_name(
+                                &target
+                                    .file_stem()
+                                    .map(|stem| stem.to_string_lossy())
+                                    .unwrap_or_default(),
+                            );
+                            self.register(name.clone(), target.clone())?;
+                            self.pending.push(target);
+                            name
+                        }
+                    };
+                    *reference = format!("{COMPONENT_REF}{name}");
+                }
+                for value in object.values_mut() {
+                    self.rewrite(value, file, base)?;
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.rewrite(value, file, base)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// The file an external reference in `file` names. Only whole-file references are supported:
+    /// a fragment or a URL would need a name the referenced file cannot give.
+    fn locate(&self, reference: &str, file: &Path, base: &Path) -> Result<PathBuf, Error> {
+        if reference.contains('#') || reference.contains("://") {
+            return Err(Error::UnsupportedReference {
+                reference: reference.to_string(),
+                path: file.to_path_buf(),
+            });
+        }
+        Ok(normalize(&base.join(reference)))
+    }
+}
+
+/// The external reference an object consists of, if it is one.
+fn external_ref(value: &Value) -> Option<&str> {
+    value
+        .get("$ref")
+        .and_then(Value::as_str)
+        .filter(|reference| !reference.starts_with('#'))
+}
+
+fn read(path: &Path) -> Result<Value, Error> {
+    let source = std::fs::read_to_string(path).map_err(|source| Error::ReadFile {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let parsed = if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        serde_json::from_str(&source).map_err(|error| error.to_string())
+    } else {
+        serde_yaml::from_str(&source).map_err(|error| error.to_string())
+    };
+    parsed.map_err(|message| Error::ParseFile {
+        path: path.to_path_buf(),
+        message,
+    })
+}
+
+/// Rewrites component references to the sibling file `{prefix}{name}.{extension}`.
+fn rewrite_refs(value: &mut Value, prefix: &str, extension: &str) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get_mut("$ref")
+                && let Some(name) = reference.strip_prefix(COMPONENT_REF)
+            {
+                *reference = format!("{prefix}{name}.{extension}");
+            }
+            object
+                .values_mut()
+                .for_each(|value| rewrite_refs(value, prefix, extension));
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| rewrite_refs(value, prefix, extension)),
+        _ => {}
+    }
+}
+
+/// File stem of every path. Paths which map to the same stem, ignoring case, such as `/a/b` and
+/// `/a_b`, are told apart by a numeric suffix in the order they are given: `a_b` and `a_b_2`.
+fn path_file_stems<'a>(paths: impl IntoIterator<Item = &'a str>) -> BTreeMap<String, String> {
+    let mut taken = BTreeSet::new();
+    let mut stems = BTreeMap::new();
+    for path in paths {
+        let base = path_file_stem(path);
+        let mut stem = base.clone();
+        let mut suffix = 1;
+        while !taken.insert(stem.to_ascii_lowercase()) {
+            suffix += 1;
+            stem = format!("{base}_{suffix}");
+        }
+        stems.insert(path.to_string(), stem);
+    }
+    stems
+}
+
+/// File stem for a templated path: `/recipes/{slug}` is stored as `recipes_slug`.
+fn path_file_stem(path: &str) -> String {
+    if path.trim_matches('/').is_empty() {
+        "root".to_string()
+    } else {
+        component_name(path)
+    }
+}
+
+fn extension(format: OutputFormat) -> &'static str {
+    match format {
+        OutputFormat::Json => "json",
+        OutputFormat::Yaml => "yaml",
+    }
+}
+
+/// Resolves `.` and `..` lexically, so one file reached by two relative routes is one component.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
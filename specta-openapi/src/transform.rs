@@ -23,19 +23,10 @@ pub(crate) fn components(
         .and_then(|definitions| definitions.as_object().cloned())
         .unwrap_or_default();
 
-    let mut names = BTreeMap::new();
-    let mut references = BTreeMap::new();
-    for name in definitions.keys() {
-        let component_name = component_name(name);
-        if let Some(first) = names.insert(component_name.clone(), name.clone()) {
-            return Err(Error::DefinitionNameCollision {
-                name: component_name,
-                first,
-                second: name.clone(),
-            });
-        }
-        references.insert(definition_ref(name), component_name);
-    }
+    let references = unique_names(definitions.keys().map(String::as_str), component_name)?
+        .into_iter()
+        .map(|(name, component_name)| (definition_ref(&name), component_name))
+        .collect::<BTreeMap<_, _>>();
 
     let mut components = BTreeMap::new();
     for (name, mut schema) in definitions {
@@ -53,6 +44,28 @@ pub(crate) fn components(
     Ok(components)
 }
 
+/// Maps each original name to `rename(original)`, rejecting two originals that
+/// are given the same name.
+pub(crate) fn unique_names<'a>(
+    originals: impl IntoIterator<Item = &'a str>,
+    rename: impl Fn(&str) -> String,
+) -> Result<BTreeMap<String, String>, Error> {
+    let mut seen = BTreeMap::new();
+    let mut names = BTreeMap::new();
+    for original in originals {
+        let name = rename(original);
+        if let Some(first) = seen.insert(name.clone(), original.to_string()) {
+            return Err(Error::DefinitionNameCollision {
+                name,
+                first,
+                second: original.to_string(),
+            });
+        }
+        names.insert(original.to_string(), name);
+    }
+    Ok(names)
+}
+
 pub(crate) fn component_name(name: &str) -> String {
     let mut output = String::with_capacity(name.len());
     let mut separator = false;
@@ -182,6 +195,14 @@ fn transform_object(
         schema.insert("enum".to_string(), Value::Array(vec![constant]));
     }
 
+    // OpenAPI 3.0 schemas only have a single `example`.
+    if version == OasVersion::V3_0
+        && let Some(Value::Array(examples)) = schema.remove("examples")
+        && let Some(example) = examples.into_iter().next()
+    {
+        schema.insert("example".to_string(), example);
+    }
+
     compact_string_enum(&mut schema);
 
     preserve_wide_integer_bound(&mut schema, "minimum");
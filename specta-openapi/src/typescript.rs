@@ -0,0 +1,492 @@
+//! A TypeScript `fetch` client for the described operations.
+//!
+//! [`TypescriptClient`] renders one `async` function per [`Operation`] as a
+//! [framework runtime](specta_typescript::Exporter::framework_runtime) of the TypeScript exporter,
+//! so its signatures name the very declarations [`Typescript::export`](specta_typescript::Typescript::export)
+//! writes beside them - a body type used by ten operations is declared once, under whatever
+//! [`Layout`] the exporter is configured with.
+//!
+//! ```rust
+//! use specta::{Type, Types};
+//! use specta_openapi::{OpenApi, Operation, typescript::TypescriptClient};
+//! use specta_typescript::{Exporter, Typescript};
+//!
+//! #[derive(Type)]
+//! struct Recipe { name: String }
+//!
+//! let api = OpenApi::default().operation(
+//!     Operation::get("/recipes/{slug}")
+//!         .operation_id("get_recipe")
+//!         .path_param::<String>("slug")
+//!         .response::<Recipe>(200, "The recipe"),
+//! );
+//! let client = TypescriptClient::new(&api);
+//! let output = Exporter::from(Typescript::default())
+//!     .framework_runtime(move |exporter| client.render(&exporter))
+//!     .export(&Types::default().register::<Recipe>(), specta_serde::Format)
+//!     .unwrap();
+//! assert!(output.contains("export async function getRecipe("));
+//! assert!(output.contains("{ status: 200; data: Recipe }"));
+//! ```
+//!
+//! Each function takes its parameters grouped by location - `path`, `query`, and `headers` - beside
+//! the request `body`, and resolves to a union discriminated by `status` over the declared
+//! responses. A status the operation does not declare rejects the promise, since there is no type
+//! to give its body.
+
+use std::{borrow::Cow, collections::BTreeMap};
+
+use specta::datatype::{DataType, Reference};
+use specta_serde::{Phase, select_phase_datatype};
+use specta_typescript::{FrameworkExporter, Layout};
+
+use crate::{
+    Error, OpenApi,
+    operation::{Body, Operation, ParameterLocation, Response},
+    paths::{label, method_key},
+    resolve::is_component,
+};
+
+/// Name of the options type every function accepts.
+const OPTIONS: &str = "ClientOptions";
+
+/// Name of the function every generated function sends its request through.
+const REQUEST: &str = "request";
+
+const RUNTIME: &str = r#"/** Options accepted by every request function. */
+export type ClientOptions = {
+	/** Prepended to every request path, such as `https://api.example.com`. */
+	baseUrl?: string;
+	/** The `fetch` implementation to send requests with; the global one by default. */
+	fetch?: typeof fetch;
+	/** Headers sent with every request. */
+	headers?: HeadersInit;
+};
+
+async function request(
+	options: ClientOptions,
+	method: string,
+	path: string,
+	init: {
+		query?: Record<string, unknown> | undefined;
+		headers?: Record<string, unknown> | undefined;
+		body?: unknown;
+	},
+): Promise<Response> {
+	const search = new URLSearchParams();
+	for (const [name, value] of Object.entries(init.query ?? {})) {
+		for (const item of Array.isArray(value) ? value : [value]) {
+			if (item !== undefined && item !== null) search.append(name, String(item));
+		}
+	}
+	const headers = new Headers(options.headers);
+	for (const [name, value] of Object.entries(init.headers ?? {})) {
+		if (value !== undefined && value !== null) headers.set(name, String(value));
+	}
+	let body: string | undefined;
+	if ("body" in init) {
+		headers.set("Content-Type", "application/json");
+		body = JSON.stringify(init.body);
+	}
+	const query = search.toString();
+	return (options.fetch ?? fetch)(`${options.baseUrl ?? ""}${path}${query ? `?${query}` : ""}`, {
+		method,
+		headers,
+		body,
+	});
+}"#;
+
+/// Words JavaScript does not allow as a function name.
+const RESERVED_WORDS: &[&str] = &[
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Renders a TypeScript `fetch` client for the operations of an [`OpenApi`] document.
+///
+/// Functions are named after each operation's `operationId` in camel case, or after its method and
+/// path when it has none. Request-side types are rendered through the deserialize phase and
+/// responses through the serialize phase, as in the document, so under
+/// [`specta_serde::PhasesFormat`] each side names its own projection.
+///
+/// The client is TypeScript, so render it through [`Typescript`](specta_typescript::Typescript)
+/// rather than [`JSDoc`](specta_typescript::JSDoc).
+#[derive(Debug, Clone)]
+pub struct TypescriptClient {
+    operations: Vec<Operation>,
+}
+
+impl TypescriptClient {
+    /// A client for the operations described on `openapi`.
+    pub fn new(openapi: &OpenApi) -> Self {
+        Self {
+            operations: openapi.operations.clone(),
+        }
+    }
+
+    /// Renders the client, for use as the exporter's
+    /// [framework runtime](specta_typescript::Exporter::framework_runtime).
+    pub fn render(
+        &self,
+        exporter: &FrameworkExporter,
+    ) -> Result<Cow<'static, str>, specta_typescript::Error> {
+        if exporter.layout != Layout::Files
+            && let Some(ndt) = exporter
+                .types
+                .into_unsorted_iter()
+                .find(|ndt| ndt.name == OPTIONS)
+        {
+            return Err(client_error(Error::ClientNameCollision {
+                name: OPTIONS.to_string(),
+                first: "the client's options type".to_string(),
+                second: format!("the type {}::{}", ndt.module_path, ndt.name),
+            }));
+        }
+
+        let mut names = BTreeMap::from([(
+            REQUEST.to_string(),
+            "the client's request function".to_string(),
+        )]);
+        let mut out = RUNTIME.to_string();
+        for operation in &self.operations {
+            let name = function_name(operation);
+            if let Some(first) = names.insert(name.clone(), label(operation)) {
+                return Err(client_error(Error::ClientNameCollision {
+                    name,
+                    first,
+                    second: label(operation),
+                }));
+            }
+            out.push_str("\n\n");
+            out.push_str(&function(exporter, operation, &name)?);
+        }
+        out.push('\n');
+        Ok(Cow::Owned(out))
+    }
+}
+
+fn function(
+    exporter: &FrameworkExporter,
+    operation: &Operation,
+    name: &str,
+) -> Result<String, specta_typescript::Error> {
+    let mut out = String::new();
+    let docs = [&operation.summary, &operation.description]
+        .into_iter()
+        .flatten()
+        .map(|docs| docs.replace("*/", "*\\/"))
+        .collect::<Vec<_>>();
+    if !docs.is_empty() {
+        out.push_str("/**\n");
+        for line in docs.join("\n\n").lines() {
+            out.push_str(format!(" * {line}").trim_end());
+            out.push('\n');
+        }
+        out.push_str(" */\n");
+    }
+
+    // Input members, with whether each may be omitted.
+    let mut members = Vec::new();
+    let mut arguments = Vec::new();
+    for (group, location) in [
+        ("path", ParameterLocation::Path),
+        ("query", ParameterLocation::Query),
+        ("headers", ParameterLocation::Header),
+    ] {
+        let mut properties = Vec::new();
+        let mut optional = true;
+        for parameter in operation
+            .parameters
+            .iter()
+            .filter(|parameter| parameter.location == location)
+        {
+            optional &= !parameter.required;
+            properties.push(format!(
+                "{}{}: {}",
+                property(&parameter.name),
+                if parameter.required { "" } else { "?" },
+                reference(exporter, &parameter.ty, Phase::Deserialize)?
+            ));
+        }
+        if !properties.is_empty() {
+            members.push((
+                format!(
+                    "{group}{}: {{ {} }}",
+                    if optional { "?" } else { "" },
+                    properties.join("; ")
+                ),
+                optional,
+            ));
+            if location != ParameterLocation::Path {
+                arguments.push(format!("{group}: input.{group}"));
+            }
+        }
+    }
+    if let Some(body) = &operation.request_body {
+        members.push((
+            format!("body: {}", reference(exporter, body, Phase::Deserialize)?),
+            false,
+        ));
+        arguments.push("body: input.body".to_string());
+    }
+
+    out.push_str(&format!("export async function {name}(\n"));
+    if !members.is_empty() {
+        out.push_str("\tinput: {\n");
+        for (member, _) in &members {
+            out.push_str(&format!("\t\t{member};\n"));
+        }
+        let omittable = members.iter().all(|(_, optional)| *optional);
+        out.push_str(if omittable { "\t} = {},\n" } else { "\t},\n" });
+    }
+    out.push_str(&format!("\toptions: {OPTIONS} = {{}},\n"));
+
+    let variants = operation
+        .responses
+        .iter()
+        .map(|response| {
+            Ok(format!(
+                "{{ status: {}; data: {} }}",
+                match response.status {
+                    Some(status) => status.to_string(),
+                    None => "number".to_string(),
+                },
+                match &response.body {
+                    Some(body) => reference(exporter, body, Phase::Serialize)?,
+                    None => "undefined".to_string(),
+                }
+            ))
+        })
+        .collect::<Result<Vec<_>, specta_typescript::Error>>()?;
+    if variants.is_empty() {
+        out.push_str("): Promise<never> {\n");
+    } else {
+        out.push_str("): Promise<\n");
+        for variant in &variants {
+            out.push_str(&format!("\t| {variant}\n"));
+        }
+        out.push_str("> {\n");
+    }
+
+    let init = if arguments.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", arguments.join(", "))
+    };
+    out.push_str(&format!(
+        "\tconst response = await {REQUEST}(options, {}, {}, {init});\n",
+        string(&method_key(operation.method).to_uppercase()),
+        path(operation)?,
+    ));
+    out.push_str("\tswitch (response.status) {\n");
+    for response in &operation.responses {
+        let Some(status) = response.status else {
+            continue;
+        };
+        out.push_str(&format!(
+            "\t\tcase {status}:\n\t\t\treturn {{ status: {status}, data: {} }};\n",
+            decode(response),
+        ));
+    }
+    match operation
+        .responses
+        .iter()
+        .find(|response| response.status.is_none())
+    {
+        Some(response) => out.push_str(&format!(
+            "\t\tdefault:\n\t\t\treturn {{ status: response.status, data: {} }};\n",
+            decode(response),
+        )),
+        None => out.push_str(&format!(
+            "\t\tdefault:\n\t\t\tthrow new Error(`{} responded with unexpected status ${{response.status}}`);\n",
+            template(&label(operation))
+        )),
+    }
+    out.push_str("\t}\n}");
+    Ok(out)
+}
+
+/// The request path as a template literal, interpolating the `path` input group.
+fn path(operation: &Operation) -> Result<String, specta_typescript::Error> {
+    let mut out = String::from("`");
+    for (index, segment) in operation.path.split('/').enumerate() {
+        if index > 0 {
+            out.push('/');
+        }
+        let Some(name) = segment
+            .strip_prefix('{')
+            .and_then(|segment| segment.strip_suffix('}'))
+        else {
+            out.push_str(&template(segment));
+            continue;
+        };
+        let (name, wildcard) = match name.strip_prefix('*') {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+        if !operation.parameters.iter().any(|parameter| {
+            parameter.location == ParameterLocation::Path && parameter.name == name
+        }) {
+            return Err(client_error(Error::UndeclaredPathParameter {
+                operation: label(operation),
+                name: name.to_string(),
+            }));
+        }
+        let value = format!("String(input.path{})", access(name));
+        // A wildcard captures the rest of the path, whose separators must survive encoding.
+        if wildcard {
+            out.push_str(&format!(
+                "${{{value}.split(\"/\").map(encodeURIComponent).join(\"/\")}}"
+            ));
+        } else {
+            out.push_str(&format!("${{encodeURIComponent({value})}}"));
+        }
+    }
+    out.push('`');
+    Ok(out)
+}
+
+/// The expression reading a response's body.
+fn decode(response: &Response) -> &'static str {
+    match (&response.body, &response.content_type) {
+        (None, _) => "undefined",
+        (Some(_), Some(content_type)) if !content_type.contains("json") => "await response.text()",
+        (Some(_), _) => "await response.json()",
+    }
+}
+
+/// Renders a parameter or body type, naming the collection's declaration for a named type.
+fn reference(
+    exporter: &FrameworkExporter,
+    body: &Body,
+    phase: Phase,
+) -> Result<String, specta_typescript::Error> {
+    let selected = select_phase_datatype(&body.dt, exporter.types, phase);
+    if let DataType::Reference(Reference::Named(reference)) = &selected
+        && is_component(reference)
+        && exporter.types.get(reference).is_none()
+    {
+        return Err(client_error(Error::UnregisteredOperationType {
+            type_name: body.type_name.to_string(),
+        }));
+    }
+    exporter.reference(&selected)
+}
+
+/// The function's name: its `operationId` or else its method and path, in camel case.
+fn function_name(operation: &Operation) -> String {
+    let source = match &operation.operation_id {
+        Some(operation_id) => operation_id.to_string(),
+        None => format!("{} {}", method_key(operation.method), operation.path),
+    };
+    let mut name = String::with_capacity(source.len());
+    for word in source
+        .split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        let mut characters = word.chars();
+        if let Some(first) = characters.next() {
+            if name.is_empty() {
+                name.push(first.to_ascii_lowercase());
+            } else {
+                name.push(first.to_ascii_uppercase());
+            }
+            name.extend(characters);
+        }
+    }
+    if name.is_empty() || name.starts_with(|character: char| character.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    if RESERVED_WORDS.contains(&name.as_str()) {
+        name.push('_');
+    }
+    name
+}
+
+/// A property name, quoted unless it is an identifier.
+fn property(name: &str) -> String {
+    if is_identifier(name) {
+        name.to_string()
+    } else {
+        string(name)
+    }
+}
+
+/// Member access for a property name.
+fn access(name: &str) -> String {
+    if is_identifier(name) {
+        format!(".{name}")
+    } else {
+        format!("[{}]", string(name))
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut characters = name.chars();
+    characters
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && characters
+            .all(|character| character.is_ascii_alphanumeric() || matches!(character, '_' | '$'))
+}
+
+/// A string literal. JSON's string syntax is a subset of JavaScript's.
+fn string(value: &str) -> String {
+    serde_json::Value::String(value.to_string()).to_string()
+}
+
+/// Escapes literal text for a template literal.
+fn template(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+}
+
+fn client_error(error: Error) -> specta_typescript::Error {
+    specta_typescript::Error::framework("failed to render the TypeScript client", error)
+}
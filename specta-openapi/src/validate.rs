@@ -0,0 +1,410 @@
+//! Validation of example values against the schemas the exporter emitted.
+//!
+//! This covers the dialect the exporter itself produces rather than all of JSON Schema: the
+//! keywords `specta-jsonschema` emits, OpenAPI 3.0's `nullable`, and the components' `$ref`s.
+//! `format` and `pattern` are annotations here, and `x-specta-*` extensions are ignored - they
+//! carry constraints the document could not state, which are vacuous for any value serde produces.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::Value;
+
+/// How deep `$ref`s may chain without consuming any of the value, which only an alias cycle does.
+const MAX_DEPTH: usize = 256;
+
+pub(crate) struct Validator<'a> {
+    components: &'a BTreeMap<String, Value>,
+}
+
+impl<'a> Validator<'a> {
+    pub(crate) fn new(components: &'a BTreeMap<String, Value>) -> Self {
+        Self { components }
+    }
+
+    /// Checks `value` against `schema`, describing the first mismatch found.
+    pub(crate) fn validate(&self, schema: &Value, value: &Value) -> Result<(), String> {
+        self.check(schema, value, "", 0)
+    }
+
+    fn check(&self, schema: &Value, value: &Value, at: &str, depth: usize) -> Result<(), String> {
+        if depth > MAX_DEPTH {
+            return Err(format!(
+                "{}: schema references do not terminate",
+                location(at)
+            ));
+        }
+        let schema = match schema {
+            Value::Bool(true) => return Ok(()),
+            Value::Bool(false) => return Err(format!("{}: no value is allowed", location(at))),
+            Value::Object(schema) => schema,
+            _ => return Ok(()),
+        };
+
+        if value.is_null() && schema.get("nullable") == Some(&Value::Bool(true)) {
+            return Ok(());
+        }
+
+        if let Some(Value::String(reference)) = schema.get("$ref") {
+            let target = self
+                .resolve(reference)
+                .ok_or_else(|| format!("{}: unresolvable reference {reference:?}", location(at)))?;
+            self.check(target, value, at, depth + 1)?;
+        }
+
+        if let Some(expected) = schema.get("type") {
+            let matches = match expected {
+                Value::String(expected) => has_type(value, expected),
+                Value::Array(expected) => expected
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .any(|expected| has_type(value, expected)),
+                _ => true,
+            };
+            if !matches {
+                return Err(format!(
+                    "{}: expected {}, found {}",
+                    location(at),
+                    describe_type(expected),
+                    type_of(value)
+                ));
+            }
+        }
+
+        if let Some(constant) = schema.get("const")
+            && constant != value
+        {
+            return Err(format!(
+                "{}: expected {constant}, found {value}",
+                location(at)
+            ));
+        }
+        if let Some(Value::Array(allowed)) = schema.get("enum")
+            && !allowed.contains(value)
+        {
+            return Err(format!(
+                "{}: {value} is not one of {}",
+                location(at),
+                Value::Array(allowed.clone())
+            ));
+        }
+
+        if let Some(Value::Array(schemas)) = schema.get("allOf") {
+            for schema in schemas {
+                self.check(schema, value, at, depth + 1)?;
+            }
+        }
+        if let Some(Value::Array(schemas)) = schema.get("anyOf")
+            && !schemas
+                .iter()
+                .any(|schema| self.check(schema, value, at, depth + 1).is_ok())
+        {
+            return Err(format!(
+                "{}: matches none of the allowed shapes",
+                location(at)
+            ));
+        }
+        if let Some(Value::Array(schemas)) = schema.get("oneOf") {
+            let matching = schemas
+                .iter()
+                .filter(|schema| self.check(schema, value, at, depth + 1).is_ok())
+                .count();
+            if matching != 1 {
+                return Err(format!(
+                    "{}: matches {matching} of the alternatives, where exactly one is required",
+                    location(at)
+                ));
+            }
+        }
+        if let Some(schema) = schema.get("not")
+            && self.check(schema, value, at, depth + 1).is_ok()
+        {
+            return Err(format!("{}: matches a disallowed shape", location(at)));
+        }
+
+        match value {
+            Value::Object(object) => self.check_object(schema, object, at, depth),
+            Value::Array(items) => self.check_array(schema, items, at, depth),
+            Value::String(string) => check_length(schema, string.chars().count(), at),
+            Value::Number(number) => check_bounds(schema, number, at),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_object(
+        &self,
+        schema: &serde_json::Map<String, Value>,
+        object: &serde_json::Map<String, Value>,
+        at: &str,
+        depth: usize,
+    ) -> Result<(), String> {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(name) {
+                    return Err(format!(
+                        "{}: missing required property {name:?}",
+                        location(at)
+                    ));
+                }
+            }
+        }
+        for (name, value) in object {
+            let at = format!("{at}/{}", escape(name));
+            if let Some(property) = properties.and_then(|properties| properties.get(name)) {
+                self.check(property, value, &at, depth + 1)?;
+            } else if let Some(additional) = schema.get("additionalProperties") {
+                self.check(additional, value, &at, depth + 1)
+                    .map_err(|_| format!("{}: property is not allowed", location(&at)))?;
+            }
+            if let Some(names) = schema.get("propertyNames") {
+                self.check(names, &Value::String(name.clone()), &at, depth + 1)?;
+            }
+        }
+        if let Some(unevaluated) = schema.get("unevaluatedProperties") {
+            let mut evaluated = BTreeSet::new();
+            self.evaluated(
+                schema,
+                &Value::Object(object.clone()),
+                &mut evaluated,
+                depth,
+            );
+            for (name, value) in object {
+                if !evaluated.contains(name) {
+                    let at = format!("{at}/{}", escape(name));
+                    self.check(unevaluated, value, &at, depth + 1)
+                        .map_err(|_| format!("{}: property is not allowed", location(&at)))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_array(
+        &self,
+        schema: &serde_json::Map<String, Value>,
+        items: &[Value],
+        at: &str,
+        depth: usize,
+    ) -> Result<(), String> {
+        check_length(schema, items.len(), at)?;
+        let prefix = match (schema.get("prefixItems"), schema.get("items")) {
+            (Some(Value::Array(prefix)), _) | (None, Some(Value::Array(prefix))) => {
+                prefix.as_slice()
+            }
+            _ => &[],
+        };
+        for (index, item) in items.iter().enumerate() {
+            let at = format!("{at}/{index}");
+            if let Some(schema) = prefix.get(index) {
+                self.check(schema, item, &at, depth + 1)?;
+            } else if let Some(schema @ (Value::Object(_) | Value::Bool(_))) = schema.get("items") {
+                self.check(schema, item, &at, depth + 1)?;
+            }
+        }
+        if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+            for (index, item) in items.iter().enumerate() {
+                if items[..index].contains(item) {
+                    return Err(format!(
+                        "{}: duplicate item",
+                        location(&format!("{at}/{index}"))
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Property names evaluated by `schema` and by the subschemas that apply to `value`, for
+    /// `unevaluatedProperties`.
+    fn evaluated(
+        &self,
+        schema: &serde_json::Map<String, Value>,
+        value: &Value,
+        names: &mut BTreeSet<String>,
+        depth: usize,
+    ) {
+        if depth > MAX_DEPTH {
+            return;
+        }
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            names.extend(properties.keys().cloned());
+        }
+        if schema.contains_key("additionalProperties")
+            && let Some(object) = value.as_object()
+        {
+            names.extend(object.keys().cloned());
+        }
+        if let Some(Value::String(reference)) = schema.get("$ref")
+            && let Some(Value::Object(target)) = self.resolve(reference)
+        {
+            self.evaluated(target, value, names, depth + 1);
+        }
+        for key in ["allOf", "anyOf", "oneOf"] {
+            if let Some(Value::Array(schemas)) = schema.get(key) {
+                for schema in schemas {
+                    if let Value::Object(object) = schema
+                        && self.check(schema, value, "", depth + 1).is_ok()
+                    {
+                        self.evaluated(object, value, names, depth + 1);
+                    }
+                }
+            }
+        }
+    }
+
+    fn resolve(&self, reference: &str) -> Option<&'a Value> {
+        self.components
+            .get(reference.strip_prefix("#/components/schemas/")?)
+    }
+}
+
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "number" => value.is_number(),
+        "integer" => match value {
+            Value::Number(number) => {
+                number.is_i64()
+                    || number.is_u64()
+                    || number.as_f64().is_some_and(|number| number.fract() == 0.0)
+            }
+            _ => false,
+        },
+        _ => true,
+    }
+}
+
+fn type_of(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn describe_type(expected: &Value) -> String {
+    match expected {
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" or "),
+        Value::String(expected) => expected.clone(),
+        expected => expected.to_string(),
+    }
+}
+
+fn check_length(
+    schema: &serde_json::Map<String, Value>,
+    length: usize,
+    at: &str,
+) -> Result<(), String> {
+    let (min, max) = if schema.contains_key("minItems") || schema.contains_key("maxItems") {
+        ("minItems", "maxItems")
+    } else {
+        ("minLength", "maxLength")
+    };
+    if let Some(min) = schema.get(min).and_then(Value::as_u64)
+        && (length as u64) < min
+    {
+        return Err(format!("{}: length {length} is below {min}", location(at)));
+    }
+    if let Some(max) = schema.get(max).and_then(Value::as_u64)
+        && (length as u64) > max
+    {
+        return Err(format!("{}: length {length} exceeds {max}", location(at)));
+    }
+    Ok(())
+}
+
+fn check_bounds(
+    schema: &serde_json::Map<String, Value>,
+    number: &serde_json::Number,
+    at: &str,
+) -> Result<(), String> {
+    let Some(value) = number.as_f64() else {
+        return Ok(());
+    };
+    // OpenAPI 3.0 makes `minimum`/`maximum` exclusive with a boolean, 3.1 gives the bound itself.
+    let exclusive = |key: &str| schema.get(key).and_then(Value::as_bool).unwrap_or(false);
+    if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64) {
+        if exclusive("exclusiveMinimum") && value <= minimum {
+            return Err(format!("{}: {number} is not above {minimum}", location(at)));
+        }
+        if value < minimum {
+            return Err(format!("{}: {number} is below {minimum}", location(at)));
+        }
+    }
+    if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64) {
+        if exclusive("exclusiveMaximum") && value >= maximum {
+            return Err(format!("{}: {number} is not below {maximum}", location(at)));
+        }
+        if value > maximum {
+            return Err(format!("{}: {number} exceeds {maximum}", location(at)));
+        }
+    }
+    if let Some(minimum) = schema.get("exclusiveMinimum").and_then(Value::as_f64)
+        && value <= minimum
+    {
+        return Err(format!("{}: {number} is not above {minimum}", location(at)));
+    }
+    if let Some(maximum) = schema.get("exclusiveMaximum").and_then(Value::as_f64)
+        && value >= maximum
+    {
+        return Err(format!("{}: {number} is not below {maximum}", location(at)));
+    }
+    Ok(())
+}
+
+/// Where in the example a mismatch is, as a JSON Pointer.
+fn location(at: &str) -> String {
+    if at.is_empty() {
+        "at the root".to_string()
+    } else {
+        format!("at {at}")
+    }
+}
+
+fn escape(name: &str) -> String {
+    name.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use super::Validator;
+
+    #[test]
+    fn exclusive_bounds_reject_the_bound_itself() {
+        let components = BTreeMap::new();
+        let validator = Validator::new(&components);
+
+        // OpenAPI 3.1
+        let schema = json!({ "type": "number", "exclusiveMinimum": 0, "exclusiveMaximum": 10 });
+        assert!(validator.validate(&schema, &json!(5)).is_ok());
+        assert!(validator.validate(&schema, &json!(0)).is_err());
+        assert!(validator.validate(&schema, &json!(10)).is_err());
+
+        // OpenAPI 3.0
+        let schema = json!({
+            "type": "number",
+            "minimum": 0,
+            "exclusiveMinimum": true,
+            "maximum": 10,
+            "exclusiveMaximum": false,
+        });
+        assert!(validator.validate(&schema, &json!(10)).is_ok());
+        assert!(validator.validate(&schema, &json!(0)).is_err());
+        assert!(validator.validate(&schema, &json!(11)).is_err());
+    }
+}
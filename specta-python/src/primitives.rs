@@ -9,8 +9,9 @@ use std::{
 use specta::{
     Types,
     datatype::{
-        DataType, Deprecated, Enum, Field, Fields, Generic, GenericDefinition, NamedDataType,
-        NamedReference, NamedReferenceType, OpaqueReference, Primitive, Reference, Struct,
+        DataType, Deprecated, Enum, Field, Fields, Generic, GenericDefinition, Literal,
+        LiteralValue, NamedDataType, NamedReference, NamedReferenceType, OpaqueReference,
+        Primitive, Reference, Struct,
     },
 };
 
@@ -1160,6 +1161,21 @@ fn opaque_to_python(reference: &OpaqueReference, location: &Location) -> Result<
     if reference.downcast_ref::<opaque::Never>().is_some() {
         return Ok("_specta_typing.Never".to_string());
     }
+    if let Some(literal) = reference.downcast_ref::<Literal>() {
+        // `typing.Literal` can't hold floats.
+        let value = match literal.value() {
+            LiteralValue::Bool(true) => Some("True".to_string()),
+            LiteralValue::Bool(false) => Some("False".to_string()),
+            LiteralValue::Int(value) => Some(value.to_string()),
+            LiteralValue::UInt(value) => Some(value.to_string()),
+            LiteralValue::Char(value) => Some(python_string(&value.to_string())),
+            LiteralValue::Str(value) => Some(python_string(value)),
+            _ => None,
+        };
+        if let Some(value) = value {
+            return Ok(format!("_specta_typing.Literal[{value}]"));
+        }
+    }
     Err(Error::unsupported_opaque_reference(
         crate::error::display_path(location),
         reference.clone(),
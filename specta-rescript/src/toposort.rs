@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use std::ptr;
 
 use specta::{
@@ -63,97 +62,9 @@ pub(crate) fn is_self_recursive(dt: &DataType, types: &Types, ty: &NamedDataType
         .any(|dependency| ptr::eq(*dependency, ty))
 }
 
-type TypeId = *const NamedDataType;
-
-fn type_id(ty: &NamedDataType) -> TypeId {
-    ptr::from_ref(ty)
-}
-
-struct Tarjan<'a> {
-    types: &'a Types,
-    next_index: usize,
-    indices: HashMap<TypeId, usize>,
-    lowlinks: HashMap<TypeId, usize>,
-    stack: Vec<&'a NamedDataType>,
-    on_stack: HashMap<TypeId, bool>,
-    groups: Vec<Vec<&'a NamedDataType>>,
-}
-
-impl<'a> Tarjan<'a> {
-    fn visit(&mut self, ty: &'a NamedDataType) {
-        let id = type_id(ty);
-        let index = self.next_index;
-        self.next_index += 1;
-        self.indices.insert(id, index);
-        self.lowlinks.insert(id, index);
-        self.stack.push(ty);
-        self.on_stack.insert(id, true);
-
-        let mut dependencies = Vec::new();
-        if let Some(dt) = &ty.ty {
-            deps(dt, self.types, &mut dependencies);
-        }
-        dependencies.sort_by(|a, b| {
-            a.name
-                .cmp(&b.name)
-                .then(a.module_path.cmp(&b.module_path))
-                .then(a.location.cmp(&b.location))
-        });
-        dependencies.dedup_by(|a, b| ptr::eq(*a, *b));
-
-        for dependency in dependencies {
-            let dependency_id = type_id(dependency);
-            if !self.indices.contains_key(&dependency_id) {
-                self.visit(dependency);
-                let dependency_lowlink = self.lowlinks[&dependency_id];
-                self.lowlinks
-                    .entry(id)
-                    .and_modify(|lowlink| *lowlink = (*lowlink).min(dependency_lowlink));
-            } else if self.on_stack.get(&dependency_id) == Some(&true) {
-                let dependency_index = self.indices[&dependency_id];
-                self.lowlinks
-                    .entry(id)
-                    .and_modify(|lowlink| *lowlink = (*lowlink).min(dependency_index));
-            }
-        }
-
-        if self.lowlinks[&id] == self.indices[&id] {
-            let mut group = Vec::new();
-            loop {
-                let member = self
-                    .stack
-                    .pop()
-                    .expect("current type is on the Tarjan stack");
-                self.on_stack.insert(type_id(member), false);
-                group.push(member);
-                if ptr::eq(member, ty) {
-                    break;
-                }
-            }
-            group.sort_by(|a, b| a.name.cmp(&b.name));
-            self.groups.push(group);
-        }
-    }
-}
-
 /// Sort types into dependency-ordered strongly connected groups.
 pub(crate) fn topological_sort(types: &Types) -> Vec<Vec<&NamedDataType>> {
-    let mut tarjan = Tarjan {
-        types,
-        next_index: 0,
-        indices: HashMap::new(),
-        lowlinks: HashMap::new(),
-        stack: Vec::new(),
-        on_stack: HashMap::new(),
-        groups: Vec::new(),
-    };
-
-    for ty in types.into_sorted_iter() {
-        if !tarjan.indices.contains_key(&type_id(ty)) {
-            tarjan.visit(ty);
-        }
-    }
-    tarjan.groups
+    types.graph().strongly_connected_components()
 }
 
 #[cfg(test)]
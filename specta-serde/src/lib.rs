@@ -2835,6 +2835,21 @@ fn unnamed_live_field_count(unnamed: &UnnamedFields) -> usize {
 /// explicitly makes the check exact.
 const ENUM_REPR_REWRITTEN_MARKER: &str = "specta_serde:enum_repr_rewritten";
 
+/// The representation an enum was rewritten from: `"external"`, `"internal"` or `"adjacent"`.
+/// Stored as a `String` alongside [`ENUM_TAG_ATTRIBUTE`] and [`ENUM_CONTENT_ATTRIBUTE`], so
+/// exporters can tell how a rewritten enum is tagged without inferring it from its shape.
+const ENUM_REPR_ATTRIBUTE: &str = "specta_serde:enum_repr";
+
+/// The tag field name of a rewritten internally or adjacently tagged enum.
+const ENUM_TAG_ATTRIBUTE: &str = "specta_serde:enum_tag";
+
+/// The content field name of a rewritten adjacently tagged enum.
+const ENUM_CONTENT_ATTRIBUTE: &str = "specta_serde:enum_content";
+
+/// The serialized tag of a rewritten variant. Untagged and widened `#[serde(other)]` variants
+/// don't have one.
+const VARIANT_TAG_ATTRIBUTE: &str = "specta_serde:variant_tag";
+
 /// Marker retained when an external variant's serde payload was hidden only
 /// from Specta before the enum was rewritten. The generated string-literal
 /// shape is otherwise indistinguishable from a genuine unit variant.
@@ -2885,6 +2900,15 @@ fn rewrite_enum_repr_for_phase(
         return Ok(());
     }
 
+    if let Some(attrs) = SerdeContainerAttrs::from_attributes(&e.attributes)?
+        && serde_repr_for_phase(&attrs, mode)?
+    {
+        rewrite_serde_repr_enum(e)?;
+        e.attributes = Default::default();
+        e.attributes.insert(ENUM_REPR_REWRITTEN_MARKER, true);
+        return Ok(());
+    }
+
     let repr = EnumRepr::from_attrs(&e.attributes)?;
     if matches!(repr, EnumRepr::Untagged) {
         rewrite_container_untagged_unit_variants(e)?;
@@ -2924,6 +2948,7 @@ fn rewrite_enum_repr_for_phase(
             // later passes (which still filter and walk variants before the
             // marker check) see a plain, already-rewritten variant.
             transformed_variant.attributes = Default::default();
+            keep_variant_metadata(&variant, &mut transformed_variant);
             if hidden_external_payload {
                 transformed_variant
                     .attributes
@@ -2942,7 +2967,7 @@ fn rewrite_enum_repr_for_phase(
             .unwrap_or(&[]);
         let names = std::iter::once(serialized_name).chain(aliases.iter().cloned());
 
-        for serialized_name in names {
+        for (index, serialized_name) in names.enumerate() {
             let widen_tag = matches!(mode, PhaseRewrite::Unified | PhaseRewrite::Deserialize)
                 && variant_attrs.as_ref().is_some_and(|attrs| attrs.other);
             let mut transformed_variant = match &repr {
@@ -2977,6 +3002,10 @@ fn rewrite_enum_repr_for_phase(
             };
 
             transformed_variant.attributes = Default::default();
+            // Aliases are extra variants, which can't share the export name of the variant.
+            if index == 0 {
+                keep_variant_metadata(&variant, &mut transformed_variant);
+            }
             if hidden_external_payload {
                 transformed_variant
                     .attributes
@@ -2987,6 +3016,11 @@ fn rewrite_enum_repr_for_phase(
                     .attributes
                     .insert(ENUM_OTHER_VARIANT_MARKER, true);
             }
+            if !widen_tag {
+                transformed_variant
+                    .attributes
+                    .insert(VARIANT_TAG_ATTRIBUTE, serialized_name.clone());
+            }
             if matches!(repr, EnumRepr::External)
                 && matches!(transformed_variant.fields, Fields::Named(_))
             {
@@ -3001,7 +3035,68 @@ fn rewrite_enum_repr_for_phase(
     e.variants = transformed;
     e.attributes = Default::default();
     e.attributes.insert(ENUM_REPR_REWRITTEN_MARKER, true);
+    let (repr, tag, content) = match repr {
+        EnumRepr::External => ("external", None, None),
+        EnumRepr::Internal { tag } => ("internal", Some(tag), None),
+        EnumRepr::Adjacent { tag, content } => ("adjacent", Some(tag), Some(content)),
+        EnumRepr::Untagged => unreachable!(),
+    };
+    e.attributes.insert(ENUM_REPR_ATTRIBUTE, String::from(repr));
+    if let Some(tag) = tag {
+        e.attributes.insert(ENUM_TAG_ATTRIBUTE, tag.into_owned());
+    }
+    if let Some(content) = content {
+        e.attributes
+            .insert(ENUM_CONTENT_ATTRIBUTE, content.into_owned());
+    }
+
+    Ok(())
+}
+
+/// Whether the enum derives `serde_repr`'s `Serialize_repr`/`Deserialize_repr` for this phase.
+fn serde_repr_for_phase(attrs: &SerdeContainerAttrs, mode: PhaseRewrite) -> Result<bool, Error> {
+    Ok(match mode {
+        PhaseRewrite::Serialize => attrs.repr_serialize,
+        PhaseRewrite::Deserialize => attrs.repr_deserialize,
+        PhaseRewrite::Unified if attrs.repr_serialize != attrs.repr_deserialize => {
+            return Err(Error::invalid_enum_representation(
+                "`Serialize_repr` and `Deserialize_repr` must be derived together to export a unified type",
+            ));
+        }
+        PhaseRewrite::Unified => attrs.repr_serialize,
+    })
+}
+
+/// `serde_repr` (de)serializes each variant as its discriminant, so every variant becomes the
+/// integer literal recorded by `#[derive(Type)]`. The discriminant is kept on the variant so
+/// exporters can render a numeric enum instead of a union.
+fn rewrite_serde_repr_enum(e: &mut Enum) -> Result<(), Error> {
+    let variants = std::mem::take(&mut e.variants);
+    let mut transformed = Vec::with_capacity(variants.len());
+    for (variant_name, variant) in variants {
+        if variant.skip {
+            continue;
+        }
+
+        let Some(discriminant) = variant.attributes.discriminant().cloned() else {
+            return Err(Error::invalid_enum_representation(format!(
+                "`serde_repr` variant `{variant_name}` has no discriminant; the enum must be fieldless with an integer `#[repr(..)]`"
+            )));
+        };
+
+        let mut transformed_variant = clone_variant_with_unnamed_fields(
+            &variant,
+            vec![Field::new(discriminant.clone().into())],
+        );
+        transformed_variant.attributes = Default::default();
+        keep_variant_metadata(&variant, &mut transformed_variant);
+        transformed_variant
+            .attributes
+            .set_discriminant(discriminant);
+        transformed.push((variant_name, transformed_variant));
+    }
 
+    e.variants = transformed;
     Ok(())
 }
 
@@ -5176,6 +5271,7 @@ fn container_has_local_difference(attrs: &specta::datatype::Attributes) -> Resul
         || conversions.rename_serialize != conversions.rename_deserialize
         || conversions.rename_all_serialize != conversions.rename_all_deserialize
         || conversions.rename_all_fields_serialize != conversions.rename_all_fields_deserialize
+        || conversions.repr_serialize != conversions.repr_deserialize
         || conversions.variant_identifier
         || conversions.field_identifier)
 }
@@ -5634,6 +5730,22 @@ fn split_type_name(original: &NamedDataType, mode: PhaseRewrite) -> Result<Strin
     Ok(name)
 }
 
+/// Keeps the export name, examples and default of a variant which was rebuilt for its serde
+/// representation.
+fn keep_variant_metadata(original: &Variant, transformed: &mut Variant) {
+    if let Some(rename) = original.attributes.rename() {
+        transformed.attributes.set_rename(rename.to_string());
+    }
+    transformed
+        .attributes
+        .set_examples(original.attributes.examples().iter().cloned());
+    if let Some(default) = original.attributes.default_value() {
+        transformed
+            .attributes
+            .set_default_value(default.to_string());
+    }
+}
+
 fn renamed_type_name_for_phase(
     ty: &DataType,
     mode: PhaseRewrite,
@@ -5644,6 +5756,10 @@ fn renamed_type_name_for_phase(
         DataType::Enum(e) => &e.attributes,
         _ => return Ok(None),
     };
+    // `#[specta(rename = "...")]` takes precedence over serde's name for the exported type.
+    if attributes.rename().is_some() {
+        return Ok(None);
+    }
     let Some(attrs) = SerdeContainerAttrs::from_attributes(attributes)? else {
         return Ok(None);
     };
@@ -24,6 +24,8 @@ const CONTAINER_INTO_TYPE_SRC: &str = "serde:container:into_type_src";
 pub(crate) const CONTAINER_INTO_RESOLVED: &str = "serde:container:into_resolved";
 const CONTAINER_VARIANT_IDENTIFIER: &str = "serde:container:variant_identifier";
 const CONTAINER_FIELD_IDENTIFIER: &str = "serde:container:field_identifier";
+const CONTAINER_REPR_SERIALIZE: &str = "serde:container:repr_serialize";
+const CONTAINER_REPR_DESERIALIZE: &str = "serde:container:repr_deserialize";
 
 pub(crate) const VARIANT_RENAME_SERIALIZE: &str = "serde:variant:rename_serialize";
 pub(crate) const VARIANT_RENAME_DESERIALIZE: &str = "serde:variant:rename_deserialize";
@@ -70,6 +72,8 @@ const CONTAINER_ATTR_KEYS: &[&str] = &[
     CONTAINER_INTO_RESOLVED,
     CONTAINER_VARIANT_IDENTIFIER,
     CONTAINER_FIELD_IDENTIFIER,
+    CONTAINER_REPR_SERIALIZE,
+    CONTAINER_REPR_DESERIALIZE,
 ];
 
 const VARIANT_ATTR_KEYS: &[&str] = &[
@@ -127,6 +131,8 @@ pub struct SerdeContainerAttrs {
     pub resolved_into: Option<DataType>,
     pub variant_identifier: bool,
     pub field_identifier: bool,
+    pub repr_serialize: bool,
+    pub repr_deserialize: bool,
 }
 
 impl SerdeContainerAttrs {
@@ -168,6 +174,8 @@ impl SerdeContainerAttrs {
                     resolved_into: get_datatype(attributes, CONTAINER_INTO_RESOLVED),
                     variant_identifier: has_attr(attributes, CONTAINER_VARIANT_IDENTIFIER),
                     field_identifier: has_attr(attributes, CONTAINER_FIELD_IDENTIFIER),
+                    repr_serialize: has_attr(attributes, CONTAINER_REPR_SERIALIZE),
+                    repr_deserialize: has_attr(attributes, CONTAINER_REPR_DESERIALIZE),
                 })
             })
             .transpose()
@@ -3,8 +3,8 @@
 use specta::{
     Format, Types,
     datatype::{
-        DataType, Enum, Field, Fields, Generic, NamedReferenceType, Primitive, Reference, Struct,
-        Variant,
+        DataType, Enum, Field, Fields, Generic, Literal, LiteralValue, NamedReferenceType,
+        Primitive, Reference, Struct, Variant,
     },
 };
 
@@ -70,6 +70,16 @@ fn resolved_string_enum(e: &Enum) -> Option<Vec<(&str, &str)>> {
         .collect()
 }
 
+/// The integer raw type of an enum whose variants all carry a discriminant, like `serde_repr`
+/// enums exported through `specta_serde`.
+fn resolved_numeric_enum(e: &Enum) -> Option<Primitive> {
+    let mut variants = e.variants.iter().filter(|(_, variant)| !variant.skip);
+    let first = variants.next()?.1.attributes.discriminant()?.primitive();
+    variants
+        .all(|(_, variant)| variant.attributes.discriminant().is_some())
+        .then_some(first)
+}
+
 fn serde_variant_payload<'a>(variant_name: &str, variant: &'a Variant) -> Option<&'a DataType> {
     let Fields::Named(fields) = &variant.fields else {
         return None;
@@ -304,7 +314,7 @@ pub fn export_type(
                 };
             let e = formatted_enum
                 .as_ref()
-                .filter(|e| resolved_string_enum(e).is_some())
+                .filter(|e| resolved_string_enum(e).is_some() || resolved_numeric_enum(e).is_some())
                 .unwrap_or(e);
 
             let name = swift.naming.convert(&ndt.name);
@@ -338,17 +348,23 @@ pub fn export_type(
 
             let needs_custom_codable = has_struct_variants || has_serde_payload_variants;
 
+            let numeric_raw_type = resolved_numeric_enum(e)
+                .map(|primitive| primitive_to_swift(&primitive))
+                .transpose()?;
+
             // Determine protocols based on whether we'll generate custom Codable
-            let protocols = if is_string_enum_val {
+            let protocols = if let Some(raw_type) = &numeric_raw_type {
+                format!("{raw_type}, Codable")
+            } else if is_string_enum_val {
                 if needs_custom_codable {
-                    "String" // Custom Codable will be generated
+                    "String".to_string() // Custom Codable will be generated
                 } else {
-                    "String, Codable"
+                    "String, Codable".to_string()
                 }
             } else if needs_custom_codable {
-                "" // Custom Codable will be generated
+                String::new() // Custom Codable will be generated
             } else {
-                "Codable"
+                "Codable".to_string()
             };
 
             let protocol_part = if protocols.is_empty() {
@@ -687,28 +703,56 @@ fn primitive_to_swift(primitive: &Primitive) -> Result<String, Error> {
     })
 }
 
-// /// Convert literal types to Swift.
-// fn literal_to_swift(literal: &specta::datatype::Literal) -> Result<String, Error> {
-//     Ok(match literal {
-//         specta::datatype::Literal::i8(v) => v.to_string(),
-//         specta::datatype::Literal::i16(v) => v.to_string(),
-//         specta::datatype::Literal::i32(v) => v.to_string(),
-//         specta::datatype::Literal::u8(v) => v.to_string(),
-//         specta::datatype::Literal::u16(v) => v.to_string(),
-//         specta::datatype::Literal::u32(v) => v.to_string(),
-//         specta::datatype::Literal::f32(v) => v.to_string(),
-//         specta::datatype::Literal::f64(v) => v.to_string(),
-//         specta::datatype::Literal::bool(v) => v.to_string(),
-//         specta::datatype::Literal::String(s) => format!("\"{}\"", s),
-//         specta::datatype::Literal::char(c) => format!("\"{}\"", c),
-//         specta::datatype::Literal::None => "nil".to_string(),
-//         _ => {
-//             return Err(Error::UnsupportedType(
-//                 "Unsupported literal type".to_string(),
-//             ));
-//         }
-//     })
-// }
+/// Convert literal types to Swift.
+fn literal_to_swift(literal: &Literal) -> Result<String, Error> {
+    Ok(match literal.value() {
+        LiteralValue::Bool(v) => v.to_string(),
+        LiteralValue::Int(v) => v.to_string(),
+        LiteralValue::UInt(v) => v.to_string(),
+        LiteralValue::Float(v) if !v.is_finite() => {
+            return Err(Error::UnsupportedType(format!(
+                "Literal {literal:?} can't be exported to Swift as `NaN` and infinite values have no literal"
+            )));
+        }
+        LiteralValue::Float(v) => format!("{v:?}"),
+        LiteralValue::Char(v) => format!("\"{}\"", escape_swift_string(&v.to_string())),
+        LiteralValue::Str(v) => format!("\"{}\"", escape_swift_string(v)),
+        _ => {
+            return Err(Error::UnsupportedType(format!(
+                "Literal {literal:?} is not supported by the Swift exporter"
+            )));
+        }
+    })
+}
+
+/// Escape a string for use inside a Swift string literal.
+fn escape_swift_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\0' => escaped.push_str("\\0"),
+            ch if ch.is_control() => escaped.push_str(&format!("\\u{{{:x}}}", ch as u32)),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// The initial value for a property whose type is a literal, as it can only hold a single value.
+fn literal_initializer(ty: &DataType) -> Result<String, Error> {
+    match ty {
+        DataType::Reference(Reference::Opaque(reference)) => reference
+            .downcast_ref::<Literal>()
+            .map(|literal| Ok(format!(" = {}", literal_to_swift(literal)?)))
+            .unwrap_or_else(|| Ok(String::new())),
+        _ => Ok(String::new()),
+    }
+}
 
 /// Convert list types to Swift arrays.
 fn list_to_swift(
@@ -736,6 +780,22 @@ fn map_to_swift(
 }
 
 /// Convert struct types to Swift.
+/// The Swift property name of a field, preferring `#[specta(rename = "...")]` over the naming convention.
+fn field_name(swift: &Swift, name: &str, field: &Field) -> String {
+    field
+        .attributes
+        .rename()
+        .map_or_else(|| swift.naming.convert_field(name), str::to_string)
+}
+
+/// The Swift case name of a variant, preferring `#[specta(rename = "...")]` over the naming convention.
+fn case_name(swift: &Swift, name: &str, variant: &Variant) -> String {
+    variant
+        .attributes
+        .rename()
+        .map_or_else(|| swift.naming.convert_enum_case(name), str::to_string)
+}
+
 fn struct_to_swift(
     swift: &Swift,
     format: Option<&dyn Format>,
@@ -772,9 +832,10 @@ fn struct_to_swift(
                     // absent on deserialize; Swift's closest representation
                     // is an Optional property.
                     let optional_marker = if field.optional { "?" } else { "" };
+                    let initializer = literal_initializer(ty)?;
                     result.push_str(&format!(
-                        "    public let field{}: {}{}\n",
-                        i, field_type, optional_marker
+                        "    public let field{}: {}{}{}\n",
+                        i, field_type, optional_marker, initializer
                     ));
                 }
                 Ok(result)
@@ -785,18 +846,19 @@ fn struct_to_swift(
             let mut field_mappings = Vec::new();
 
             for (original_field_name, field) in &fields.fields {
-                let field_type = if let Some(ty) = field.ty.as_ref() {
-                    datatype_to_swift(swift, format, types, ty, generic_scope.clone())?
-                } else {
+                let Some(ty) = field.ty.as_ref() else {
                     continue;
                 };
+                let field_type =
+                    datatype_to_swift(swift, format, types, ty, generic_scope.clone())?;
 
                 let optional_marker = if field.optional { "?" } else { "" };
-                let swift_field_name = swift.naming.convert_field(original_field_name.as_ref());
+                let initializer = literal_initializer(ty)?;
+                let swift_field_name = field_name(swift, original_field_name, field);
 
                 result.push_str(&format!(
-                    "    public let {}: {}{}\n",
-                    swift_field_name, field_type, optional_marker
+                    "    public let {}: {}{}{}\n",
+                    swift_field_name, field_type, optional_marker, initializer
                 ));
 
                 field_mappings.push((swift_field_name, original_field_name.to_string()));
@@ -835,15 +897,23 @@ fn enum_to_swift(
 
     // Check if this is a string enum
     let is_string_enum = resolved_string_enum(e).is_some();
+    let is_numeric_enum = resolved_numeric_enum(e).is_some();
 
     for (original_variant_name, variant) in &e.variants {
         if variant.skip {
             continue;
         }
 
-        let variant_name = swift
-            .naming
-            .convert_enum_case(original_variant_name.as_ref());
+        let variant_name = case_name(swift, original_variant_name, variant);
+
+        if is_numeric_enum && let Some(discriminant) = variant.attributes.discriminant() {
+            result.push_str(&format!(
+                "    case {} = {}\n",
+                variant_name,
+                literal_to_swift(discriminant)?
+            ));
+            continue;
+        }
 
         match &variant.fields {
             specta::datatype::Fields::Unit => {
@@ -960,10 +1030,11 @@ fn generate_enum_structs(
                     let field_type =
                         datatype_to_swift(swift, format, types, ty, generic_scope.clone())?;
                     let optional_marker = if field.optional { "?" } else { "" };
-                    let swift_field_name = swift.naming.convert_field(original_field_name.as_ref());
+                    let initializer = literal_initializer(ty)?;
+                    let swift_field_name = field_name(swift, original_field_name, field);
                     result.push_str(&format!(
-                        "    public let {}: {}{}\n",
-                        swift_field_name, field_type, optional_marker
+                        "    public let {}: {}{}{}\n",
+                        swift_field_name, field_type, optional_marker, initializer
                     ));
                     field_mappings.push((swift_field_name, original_field_name.to_string()));
                 }
@@ -1094,9 +1165,12 @@ fn reference_to_swift(
                 Ok(format!("{}<{}>", name, generics))
             }
         }
-        Reference::Opaque(_) => Err(Error::UnsupportedType(
-            "Opaque references are not supported by Swift exporter".to_string(),
-        )),
+        Reference::Opaque(reference) => match reference.downcast_ref::<Literal>() {
+            Some(literal) => primitive_to_swift(&literal.primitive()),
+            None => Err(Error::UnsupportedType(
+                "Opaque references are not supported by Swift exporter".to_string(),
+            )),
+        },
     }
 }
 
@@ -1134,9 +1208,7 @@ fn generate_enum_codable_impl(
         if variant.skip {
             continue;
         }
-        let swift_case_name = swift
-            .naming
-            .convert_enum_case(original_variant_name.as_ref());
+        let swift_case_name = case_name(swift, original_variant_name, variant);
         result.push_str(&format!(
             "        case {} = \"{}\"\n",
             swift_case_name, original_variant_name
@@ -1161,9 +1233,7 @@ fn generate_enum_codable_impl(
             continue;
         }
 
-        let swift_case_name = swift
-            .naming
-            .convert_enum_case(original_variant_name.as_ref());
+        let swift_case_name = case_name(swift, original_variant_name, variant);
 
         match &variant.fields {
             specta::datatype::Fields::Unit => {
@@ -1262,9 +1332,7 @@ fn generate_enum_codable_impl(
             continue;
         }
 
-        let swift_case_name = swift
-            .naming
-            .convert_enum_case(original_variant_name.as_ref());
+        let swift_case_name = case_name(swift, original_variant_name, variant);
 
         match &variant.fields {
             specta::datatype::Fields::Unit => {
@@ -0,0 +1,41 @@
+//! [TypeBox](https://github.com/sinclairzx81/typebox) language exporter for [Specta](specta).
+//!
+//! # Usage
+//!
+//! ```rust,no_run
+//! use specta::{Type, Types};
+//! use specta_typebox::TypeBox;
+//!
+//! #[derive(Type)]
+//! pub struct User {
+//!     pub id: u32,
+//!     pub name: String,
+//! }
+//!
+//! let types = Types::default().register::<User>();
+//! TypeBox::default()
+//!     .export_to("./schemas.ts", &types, specta_serde::Format)
+//!     .unwrap();
+//! ```
+//!
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://github.com/specta-rs/specta/raw/main/.github/logo-128.png",
+    html_favicon_url = "https://github.com/specta-rs/specta/raw/main/.github/logo-128.png"
+)]
+
+mod error;
+mod map_keys;
+mod opaque;
+pub mod primitives;
+mod references;
+mod reserved_names;
+mod typebox;
+mod types;
+
+pub use error::Error;
+pub use opaque::define;
+pub use references::collect_references;
+pub use specta_typescript::OptionalStyle;
+pub use typebox::{FrameworkExporter, Layout, TypeBox};
+pub use types::{Any, Never, Unknown};
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, error, fmt, io, panic::Location, path::PathBuf};
 
-use specta::datatype::{NamedDataType, OpaqueReference, RecursiveInlineType};
+use specta::datatype::{Literal, NamedDataType, OpaqueReference, RecursiveInlineType};
 
 use crate::Layout;
 
@@ -77,6 +77,12 @@ enum ErrorKind {
     BigIntForbidden {
         path: String,
     },
+    /// A literal value has no exact JavaScript representation.
+    UnsupportedLiteral {
+        path: String,
+        literal: Literal,
+        reason: &'static str,
+    },
     /// A type's name conflicts with a reserved keyword in Typescript.
     ForbiddenName {
         path: String,
@@ -138,6 +144,10 @@ enum ErrorKind {
         path: PathBuf,
         source: io::Error,
     },
+    /// A `Layout::Files` barrel would overwrite the file of a module named `index`.
+    BarrelConflict {
+        path: PathBuf,
+    },
     /// Failed to read a generated file while exporting files.
     ReadFile {
         path: PathBuf,
@@ -278,6 +288,18 @@ impl Error {
         Self::new(ErrorKind::BigIntForbidden { path })
     }
 
+    pub(crate) fn unsupported_literal(
+        path: String,
+        literal: Literal,
+        reason: &'static str,
+    ) -> Self {
+        Self::new(ErrorKind::UnsupportedLiteral {
+            path,
+            literal,
+            reason,
+        })
+    }
+
     pub(crate) fn invalid_name(path: String, name: impl Into<Cow<'static, str>>) -> Self {
         Self::new(ErrorKind::InvalidName {
             path,
@@ -364,6 +386,10 @@ impl Error {
         Self::new(ErrorKind::InlineRecursionLimitExceeded { path })
     }
 
+    pub(crate) fn barrel_conflict(path: PathBuf) -> Self {
+        Self::new(ErrorKind::BarrelConflict { path })
+    }
+
     pub(crate) fn export_requires_export_to(layout: Layout) -> Self {
         Self::new(ErrorKind::ExportRequiresExportTo(layout))
     }
@@ -399,6 +425,14 @@ impl fmt::Display for Error {
                 f,
                 "Attempted to export {path:?} but Specta forbids exporting BigInt-style types (usize, isize, i64, u64, i128, u128) to avoid precision loss. See {BIGINT_DOCS_URL} for a full explanation."
             ),
+            ErrorKind::UnsupportedLiteral {
+                path,
+                literal,
+                reason,
+            } => write!(
+                f,
+                "Attempted to export {path:?} but the literal {literal:?} can't be represented in Typescript as {reason}."
+            ),
             ErrorKind::ForbiddenName { path, name } => write!(
                 f,
                 "Attempted to export {} but was unable to due to name {name:?} conflicting with a reserved keyword in Typescript. Try renaming it or using `#[specta(rename = \"new name\")]`",
@@ -527,6 +561,11 @@ impl fmt::Display for Error {
                 f,
                 "Unable to export layout {layout} as a single string. Use `Exporter::export_to` with a directory path for file-based exports."
             ),
+            ErrorKind::BarrelConflict { path } => write!(
+                f,
+                "Unable to write barrel file '{}': a module named `index` is exported to the same path. Rename the module or disable barrels.",
+                path.display()
+            ),
             ErrorKind::JsdocNamespacesUnsupported => write!(
                 f,
                 "Unable to export JSDoc with the Namespaces layout. Disable JSDoc or use FlatFile, ModulePrefixedName, or Files layout."
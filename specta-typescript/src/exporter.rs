@@ -1,6 +1,6 @@
 use std::{
     borrow::Cow,
-    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, hash_map::Entry},
     fmt,
     ops::Deref,
     panic::Location,
@@ -10,10 +10,13 @@ use std::{
 
 use specta::{
     Format, Types,
-    datatype::{DataType, Fields, NamedDataType, NamedReference, NamedReferenceType, Reference},
+    datatype::{
+        DataType, Fields, Function, Literal, NamedDataType, NamedReference, NamedReferenceType,
+        Reference,
+    },
 };
 
-use crate::{Branded, Error, primitives, references};
+use crate::{Branded, Error, FunctionStyle, functions, primitives, references, source_map};
 
 fn rust_type_path(ndt: &NamedDataType) -> Cow<'static, str> {
     if ndt.module_path.is_empty() {
@@ -38,6 +41,60 @@ pub enum Layout {
     FlatFile,
 }
 
+/// How `Layout::Files` writes the module specifiers of its imports and barrel re-exports.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportExtension {
+    /// No extension, as bundlers and `moduleResolution: bundler` expect: `./types`.
+    #[default]
+    None,
+    /// A `.js` extension, as Node ESM and `moduleResolution: nodenext` require: `./types.js`.
+    Js,
+    /// A `.ts` extension, for `allowImportingTsExtensions` and runtimes which execute Typescript
+    /// directly: `./types.ts`.
+    Ts,
+}
+
+/// How enums whose every variant serializes as a string are exported.
+///
+/// The non-union styles declare a runtime value beside the type, so the variants can be listed
+/// and iterated. JavaScript has no `enum` syntax, so the JSDoc exporter renders both
+/// [`UnitEnumStyle::Enum`] and [`UnitEnumStyle::ConstEnum`] as [`UnitEnumStyle::AsConst`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnitEnumStyle {
+    /// A union of string literals: `type Color = "red" | "green"`.
+    #[default]
+    Union,
+    /// A frozen `as const` object of the variants, and a union type of its values:
+    /// `const Color = Object.freeze({ red: "red" } as const)` with
+    /// `type Color = (typeof Color)[keyof typeof Color]`.
+    AsConst,
+    /// A string enum: `enum Color { red = "red" }`.
+    Enum,
+    /// A string `const enum`, which is inlined at every use and requires a compiler that sees the
+    /// whole program, so it does not work with `isolatedModules`.
+    ConstEnum,
+}
+
+/// How optional object properties, like `Option` fields with `#[serde(default)]`, are exported.
+///
+/// This only affects object properties, optional tuple elements are always rendered as `T?`.
+/// `specta-zod` and `specta-valibot` take the same setting, so their schemas infer the exported
+/// types.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OptionalStyle {
+    /// `key?: T`, which also accepts an explicit `undefined` unless the consumer enables
+    /// `exactOptionalPropertyTypes`. Schemas use Zod's `.optional()` and Valibot's `v.optional`.
+    #[default]
+    Optional,
+    /// `key?: T | undefined`, which accepts an explicit `undefined` under any compiler options.
+    /// This is the type Zod's `.optional()` and Valibot's `v.optional` infer.
+    OptionalUndefined,
+    /// `key?: T` for consumers with `exactOptionalPropertyTypes`, where the key may be missing
+    /// but not `undefined`. Schemas use Zod's `.exactOptional()`, which requires Zod 4.1, and
+    /// Valibot's `v.exactOptional`.
+    ExactOptional,
+}
+
 impl fmt::Display for Layout {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{self:?}")
@@ -77,11 +134,21 @@ pub struct Exporter {
     /// Custom header prepended to exported files.
     pub header: Cow<'static, str>,
     raw: Vec<Cow<'static, str>>,
+    constants: Vec<(Cow<'static, str>, Literal)>,
     framework_runtime: Option<RuntimeFn>,
     pub(crate) branded_type_impl: Option<BrandedTypeImpl>,
     framework_prelude: Cow<'static, str>,
     /// Output layout mode for generated TypeScript.
     pub layout: Layout,
+    pub(crate) import_extension: ImportExtension,
+    pub(crate) type_only_imports: bool,
+    pub(crate) barrels: bool,
+    pub(crate) unit_enum_style: UnitEnumStyle,
+    pub(crate) tagged_union_helpers: bool,
+    pub(crate) readonly: bool,
+    pub(crate) optional_style: OptionalStyle,
+    pub(crate) source_locations: bool,
+    pub(crate) declaration_maps: Option<PathBuf>,
     pub(crate) jsdoc: bool,
 }
 
@@ -91,12 +158,22 @@ impl Exporter {
         Exporter {
             header: Cow::Borrowed(""),
             raw: Vec::new(),
+            constants: Vec::new(),
             framework_runtime: None,
             branded_type_impl: None,
             framework_prelude: Cow::Borrowed(
                 "// This file has been generated by Specta. Do not edit this file manually.",
             ),
             layout: Default::default(),
+            import_extension: Default::default(),
+            type_only_imports: true,
+            barrels: false,
+            unit_enum_style: Default::default(),
+            tagged_union_helpers: false,
+            readonly: false,
+            optional_style: Default::default(),
+            source_locations: false,
+            declaration_maps: None,
             jsdoc: false,
         }
     }
@@ -126,6 +203,27 @@ impl Exporter {
         self
     }
 
+    /// Export a constant value as part of the bindings.
+    ///
+    /// This is useful for sharing a Rust `const` with the frontend. It renders as
+    /// `export const NAME = value;` alongside the other runtime code.
+    ///
+    /// ```rust
+    /// # use specta_typescript::Typescript;
+    /// const API_VERSION: &str = "v1";
+    ///
+    /// let exporter = Typescript::default().constant("API_VERSION", API_VERSION);
+    /// # let _ = exporter;
+    /// ```
+    pub fn constant(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<Literal>,
+    ) -> Self {
+        self.constants.push((name.into(), value.into()));
+        self
+    }
+
     /// Configure how `specta_typescript::branded!` types are rendered with exporter context.
     ///
     /// This callback receives both the branded payload and a [`BrandedTypeExporter`], allowing
@@ -190,6 +288,91 @@ impl Exporter {
         self
     }
 
+    /// Configure the extension `Layout::Files` appends to import specifiers.
+    pub fn import_extension(mut self, import_extension: ImportExtension) -> Self {
+        self.import_extension = import_extension;
+        self
+    }
+
+    /// Configure whether `Layout::Files` imports with `import type`, which is the default.
+    ///
+    /// Type-only imports are erased entirely, as `verbatimModuleSyntax` requires for imports of
+    /// declarations which have no runtime value. JSDoc output always imports through `@typedef`.
+    pub fn type_only_imports(mut self, type_only_imports: bool) -> Self {
+        self.type_only_imports = type_only_imports;
+        self
+    }
+
+    /// Configure whether `Layout::Files` writes an `index` barrel into every module directory.
+    ///
+    /// A module's barrel re-exports its own types and each child module as a namespace, so
+    /// `a/index.ts` exposes `a.ts` beside `export * as b` for `a/b.ts`. The root `index` file gains
+    /// the re-exports of the top-level modules.
+    pub fn barrels(mut self, barrels: bool) -> Self {
+        self.barrels = barrels;
+        self
+    }
+
+    /// Configure how enums whose every variant serializes as a string are exported.
+    ///
+    /// Variant docs and deprecations become JSDoc on each member of the non-union styles.
+    pub fn unit_enum_style(mut self, unit_enum_style: UnitEnumStyle) -> Self {
+        self.unit_enum_style = unit_enum_style;
+        self
+    }
+
+    /// Configure whether internally and adjacently tagged enums are exported with helpers.
+    ///
+    /// Each such enum `Foo` gains an `isFoo_<tag>(value)` type guard per variant and a
+    /// `matchFoo(value, cases)` function, which requires a case for every variant. The tag field
+    /// and its values are those `specta_serde` lowered the enum to, so this has no effect on types
+    /// exported without a serde format.
+    pub fn tagged_union_helpers(mut self, tagged_union_helpers: bool) -> Self {
+        self.tagged_union_helpers = tagged_union_helpers;
+        self
+    }
+
+    /// Configure whether exported data is immutable.
+    ///
+    /// Every object property is marked `readonly`, lists render as `readonly T[]`, tuples as
+    /// `readonly [A, B]` and maps as `{ readonly [key in K]: V }`, which is
+    /// `Readonly<Record<K, V>>`. Use `#[specta(readonly = ...)]` or
+    /// [`Attributes::set_readonly`](specta::datatype::Attributes::set_readonly) to override this
+    /// for a single type or field, and everything rendered within it, so a struct marked `false`
+    /// keeps its properties, arrays and maps mutable when everything else is `readonly`.
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    /// Configure how optional object properties are exported.
+    ///
+    /// See [`OptionalStyle`] for the options and the schemas each one corresponds to.
+    pub fn optional_style(mut self, optional_style: OptionalStyle) -> Self {
+        self.optional_style = optional_style;
+        self
+    }
+
+    /// Configure whether each exported type's doc comment links to the Rust source it was
+    /// exported from, with a `@see path/to/file.rs:123` tag.
+    pub fn source_locations(mut self, source_locations: bool) -> Self {
+        self.source_locations = source_locations;
+        self
+    }
+
+    /// Write a source map beside each file [`Exporter::export_to`] writes, which maps the
+    /// declaration of every exported type to the Rust source it was exported from.
+    ///
+    /// Sources are recorded as the compiler reports them, relative to the Cargo workspace root for
+    /// local crates, so `source_root` should be that directory. A relative `source_root` is
+    /// resolved from the directory of each map. TypeScript's editor support follows these maps
+    /// from `.d.ts` files, so export to a `.d.ts` path for "go to definition" to reach the Rust
+    /// type.
+    pub fn declaration_maps(mut self, source_root: impl Into<PathBuf>) -> Self {
+        self.declaration_maps = Some(source_root.into());
+        self
+    }
+
     /// Export the files into a single string.
     ///
     /// Note: This returns an error if the format is `Format::Files`.
@@ -220,7 +403,7 @@ impl Exporter {
                     types,
                 });
             }
-            let runtime = render_runtime(&exporter, runtime?);
+            let runtime = render_runtime(&exporter, runtime?)?;
 
             // Framework runtime
             if !runtime.is_empty() {
@@ -236,12 +419,37 @@ impl Exporter {
                 render_types(&mut out, &exporter, Some(&format), types, "")?;
             }
 
-            Ok(out)
+            Ok(source_map::strip_markers(&exporter, out))
         }
 
         inner(self.clone(), types, &format)
     }
 
+    /// Export the types into a single string, followed by the given functions.
+    ///
+    /// This renders the functions after any [`Exporter::framework_runtime`], as
+    /// [`FrameworkExporter::functions`] does.
+    pub fn export_functions(
+        &self,
+        functions: &[Function],
+        style: FunctionStyle,
+        types: &Types,
+        format: impl Format,
+    ) -> Result<String, Error> {
+        let functions = functions.to_vec();
+        let runtime = self.framework_runtime.clone();
+        self.clone()
+            .framework_runtime(move |mut ctx| {
+                let mut out = match &runtime {
+                    Some(runtime) => (runtime.0)(ctx.reborrow())?.into_owned(),
+                    None => String::new(),
+                };
+                out.push_str(&ctx.functions(&functions, style)?);
+                Ok(Cow::Owned(out))
+            })
+            .export(types, format)
+    }
+
     /// Export the types to a specific file/folder.
     ///
     /// When configured when `format` is `Format::Files`, you must provide a directory path.
@@ -276,7 +484,7 @@ impl Exporter {
                         types,
                     });
                 }
-                let runtime = render_runtime(&exporter, runtime?);
+                let runtime = render_runtime(&exporter, runtime?)?;
 
                 if !runtime.is_empty() {
                     result.push('\n');
@@ -292,8 +500,15 @@ impl Exporter {
                     std::fs::create_dir_all(parent)
                         .map_err(|source| Error::create_dir(parent.to_path_buf(), source))?;
                 };
+                let (result, map) = source_map::finish(&exporter, path, result);
                 std::fs::write(path, result)
                     .map_err(|source| Error::write_file(path.to_path_buf(), source))?;
+                match map {
+                    Some((map_path, map)) => std::fs::write(&map_path, map)
+                        .map_err(|source| Error::write_file(map_path, source))?,
+                    None if exporter.declaration_maps.is_some() => remove_declaration_map(path)?,
+                    None => {}
+                }
                 return Ok(());
             }
 
@@ -409,7 +624,7 @@ impl Exporter {
                     framework_runtime = runtime_result?;
                     runtime_references = referenced_types;
                 }
-                let runtime = render_runtime(&exporter, framework_runtime);
+                let runtime = render_runtime(&exporter, framework_runtime)?;
 
                 let should_export_user_types =
                     !has_manually_exported_user_types && !root_types.is_empty();
@@ -467,6 +682,10 @@ impl Exporter {
                 }
             }
 
+            if exporter.barrels {
+                render_barrels(&exporter, path, &mut files)?;
+            }
+
             match path.metadata() {
                 Ok(meta) if !meta.is_dir() => std::fs::remove_file(path).or_else(|source| {
                     if source.kind() == std::io::ErrorKind::NotFound {
@@ -482,13 +701,21 @@ impl Exporter {
                 }
             }
 
-            for (path, content) in &files {
+            for (path, content) in &mut files {
                 if let Some(parent) = path.parent() {
                     std::fs::create_dir_all(parent)
                         .map_err(|source| Error::create_dir(parent.to_path_buf(), source))?;
                 }
-                std::fs::write(path, content)
+                let (stripped, map) = source_map::finish(&exporter, path, std::mem::take(content));
+                *content = stripped;
+                std::fs::write(path, &*content)
                     .map_err(|source| Error::write_file(path.clone(), source))?;
+                match map {
+                    Some((map_path, map)) => std::fs::write(&map_path, map)
+                        .map_err(|source| Error::write_file(map_path, source))?,
+                    None if exporter.declaration_maps.is_some() => remove_declaration_map(path)?,
+                    None => {}
+                }
             }
 
             cleanup_stale_files(path, &files, &exporter)?;
@@ -530,13 +757,29 @@ fn format_types<'a>(types: &'a Types, format: &dyn Format) -> Result<Cow<'a, Typ
     )
 }
 
-fn render_runtime(exporter: &Exporter, framework_runtime: Cow<'static, str>) -> String {
+fn render_runtime(
+    exporter: &Exporter,
+    framework_runtime: Cow<'static, str>,
+) -> Result<String, Error> {
     let mut runtime = String::new();
 
     if !framework_runtime.is_empty() {
         runtime.push_str(&framework_runtime);
     }
 
+    for (name, value) in &exporter.constants {
+        let location = [name.clone()];
+        let name = primitives::sanitise_type_name(&location, name)?;
+        if !runtime.is_empty() {
+            runtime.push('\n');
+        }
+        runtime.push_str("export const ");
+        runtime.push_str(&name);
+        runtime.push_str(" = ");
+        primitives::push_literal(&mut runtime, value, &location)?;
+        runtime.push(';');
+    }
+
     for raw in exporter.raw.iter().filter(|raw| !raw.is_empty()) {
         if !runtime.is_empty() {
             runtime.push('\n');
@@ -544,7 +787,7 @@ fn render_runtime(exporter: &Exporter, framework_runtime: Cow<'static, str>) ->
         runtime.push_str(raw);
     }
 
-    runtime
+    Ok(runtime)
 }
 
 fn map_datatype_format(
@@ -885,6 +1128,16 @@ impl Deref for FrameworkExporter<'_> {
 }
 
 impl FrameworkExporter<'_> {
+    fn reborrow(&mut self) -> FrameworkExporter<'_> {
+        FrameworkExporter {
+            exporter: self.exporter,
+            format: self.format,
+            has_manually_exported_user_types: self.has_manually_exported_user_types,
+            files_root_types: self.files_root_types,
+            types: self.types,
+        }
+    }
+
     /// Render the types within the [`Types`](specta::Types).
     ///
     /// This will only work if used within [`Exporter::framework_runtime`].
@@ -916,6 +1169,15 @@ impl FrameworkExporter<'_> {
         primitives::reference(self, self.types, &mapped)
     }
 
+    /// Render the signatures of functions collected with `#[specta::specta]`.
+    ///
+    /// Arguments become a parameter list, or an object in a client's `invoke` call. A `Result`
+    /// is split into its success type, which the function returns, and its error type, which is
+    /// documented with `@throws`. See [`FunctionStyle`] for the shapes this renders.
+    pub fn functions(&self, functions: &[Function], style: FunctionStyle) -> Result<String, Error> {
+        functions::render(self, functions, style)
+    }
+
     /// [primitives::export]
     pub fn export<'a>(
         &self,
@@ -1234,6 +1496,7 @@ fn cleanup_stale_files(
                 Err(Error::remove_file(path.clone(), source))
             }
         })?;
+        remove_declaration_map(&path)?;
     }
 
     remove_empty_dirs(root, root)?;
@@ -1241,6 +1504,18 @@ fn cleanup_stale_files(
     Ok(())
 }
 
+/// Delete the source map an earlier export wrote beside `path`, if any.
+fn remove_declaration_map(path: &Path) -> Result<(), Error> {
+    let mut map_path = path.as_os_str().to_owned();
+    map_path.push(".map");
+    let map_path = PathBuf::from(map_path);
+    match std::fs::remove_file(&map_path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(source) => Err(Error::remove_file(map_path, source)),
+    }
+}
+
 fn exported_type_name(exporter: &Exporter, ndt: &NamedDataType) -> Cow<'static, str> {
     match exporter.layout {
         Layout::ModulePrefixedName => {
@@ -1268,17 +1543,17 @@ fn module_import_statement(
     from_module_path: &str,
     to_module_path: &str,
 ) -> String {
-    let import_keyword = if exporter.jsdoc {
-        "import"
-    } else {
+    let import_keyword = if !exporter.jsdoc && exporter.type_only_imports {
         "import type"
+    } else {
+        "import"
     };
 
     format!(
         "{} * as {} from \"{}\";",
         import_keyword,
         module_alias(to_module_path),
-        module_import_path(from_module_path, to_module_path)
+        module_import_path(exporter, from_module_path, to_module_path)
     )
 }
 
@@ -1292,7 +1567,7 @@ fn module_import_block(
 
         for module_path in import_paths {
             out.push_str(" * @typedef {import(\"");
-            out.push_str(&module_import_path(from_module_path, module_path));
+            out.push_str(&module_import_path(exporter, from_module_path, module_path));
             out.push_str("\")} ");
             out.push_str(&module_alias(module_path));
             out.push('\n');
@@ -1309,7 +1584,7 @@ fn module_import_block(
     }
 }
 
-fn module_import_path(from_module_path: &str, to_module_path: &str) -> String {
+fn module_import_path(exporter: &Exporter, from_module_path: &str, to_module_path: &str) -> String {
     fn module_file_segments(module_path: &str) -> Vec<&str> {
         if module_path.is_empty() {
             vec!["index"]
@@ -1342,5 +1617,103 @@ fn module_import_path(from_module_path: &str, to_module_path: &str) -> String {
         relative_parts.insert(0, ".");
     }
 
-    relative_parts.join("/")
+    let mut specifier = relative_parts.join("/");
+    specifier.push_str(import_extension(exporter));
+    specifier
+}
+
+fn import_extension(exporter: &Exporter) -> &'static str {
+    match exporter.import_extension {
+        ImportExtension::None => "",
+        ImportExtension::Js => ".js",
+        ImportExtension::Ts => ".ts",
+    }
+}
+
+/// Write an `index` barrel into every directory holding module files.
+///
+/// A directory's barrel stands for the module of the same path, so it re-exports that module's
+/// own file from beside the directory, then each child module as a namespace: through the child's
+/// barrel when the child has a directory of its own, otherwise straight from its file. The root
+/// directory's barrel is the root `index` file, which the re-exports are appended to.
+fn render_barrels(
+    exporter: &Exporter,
+    root: &Path,
+    files: &mut HashMap<PathBuf, String>,
+) -> Result<(), Error> {
+    let file_extension = if exporter.jsdoc { "js" } else { "ts" };
+    let root_index = root.join("index").with_extension(file_extension);
+
+    let modules = files
+        .keys()
+        .filter(|path| **path != root_index)
+        .filter_map(|path| {
+            let relative = path.strip_prefix(root).ok()?.with_extension("");
+            Some(
+                relative
+                    .iter()
+                    .map(|segment| segment.to_string_lossy().into_owned())
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect::<BTreeSet<_>>();
+
+    let mut directories = BTreeMap::<Vec<String>, BTreeSet<String>>::new();
+    for module in &modules {
+        for depth in 0..module.len() {
+            directories
+                .entry(module[..depth].to_vec())
+                .or_default()
+                .insert(module[depth].clone());
+        }
+    }
+
+    let keyword = if !exporter.jsdoc
+        && exporter.type_only_imports
+        && exporter.unit_enum_style == UnitEnumStyle::Union
+        && !exporter.tagged_union_helpers
+    {
+        "export type"
+    } else {
+        "export"
+    };
+    let extension = import_extension(exporter);
+    for (directory, children) in &directories {
+        let mut statements = Vec::new();
+        if let Some(name) = directory.last()
+            && modules.contains(directory)
+        {
+            statements.push(format!("{keyword} * from \"../{name}{extension}\";"));
+        }
+        for child in children {
+            let mut child_directory = directory.clone();
+            child_directory.push(child.clone());
+            let target = if directories.contains_key(&child_directory) {
+                format!("./{child}/index{extension}")
+            } else {
+                format!("./{child}{extension}")
+            };
+            statements.push(format!("{keyword} * as {child} from \"{target}\";"));
+        }
+        let statements = statements.join("\n");
+
+        let barrel = directory
+            .iter()
+            .fold(root.to_path_buf(), |path, segment| path.join(segment))
+            .join("index")
+            .with_extension(file_extension);
+        match files.entry(barrel) {
+            Entry::Occupied(mut entry) if directory.is_empty() => {
+                let out = entry.get_mut();
+                out.push_str("\n\n");
+                out.push_str(&statements);
+            }
+            Entry::Occupied(entry) => return Err(Error::barrel_conflict(entry.key().clone())),
+            Entry::Vacant(entry) => {
+                entry.insert(format!("{}{statements}\n", render_file_header(exporter)?));
+            }
+        }
+    }
+
+    Ok(())
 }
@@ -0,0 +1,279 @@
+//! Rendering of the function signatures `#[specta::specta]` collects.
+
+use std::borrow::Cow;
+
+use specta::{
+    Types,
+    datatype::{DataType, Function, NamedReferenceType, Reference},
+};
+
+use crate::{
+    Error, FrameworkExporter,
+    primitives::{escape_typescript_string_literal, is_identifier, js_doc, sanitise_key},
+    reserved_names::RESERVED_TYPE_NAMES,
+};
+
+/// How [`FrameworkExporter::functions`] renders functions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FunctionStyle {
+    /// An `export declare function` per function, for implementations provided elsewhere.
+    ///
+    /// JSDoc has no declarations, so it gets a `@typedef` of each function's type instead.
+    #[default]
+    Declarations,
+    /// A `Functions` type mapping each function's name to its `args` object, `result` and
+    /// `error`, and a `createClient(invoke)` factory with a method per function, which forwards
+    /// its arguments to `invoke` under the function's name.
+    Client,
+}
+
+/// A function's signature, rendered with the exporter.
+struct Signature<'a> {
+    function: &'a Function,
+    /// Parameter name, argument name and type of each argument.
+    params: Vec<(String, &'a str, String)>,
+    /// The success type, `None` for functions returning nothing.
+    result: Option<String>,
+    /// The error type of functions returning a `Result`.
+    error: Option<String>,
+}
+
+impl Signature<'_> {
+    /// The docs of the function, with `@throws` for its error type.
+    fn docs(&self) -> String {
+        let mut docs = self.function.docs.to_string();
+        if let Some(error) = &self.error {
+            if !docs.is_empty() {
+                docs.push('\n');
+            }
+            docs.push_str(&format!("@throws {{{error}}}"));
+        }
+        docs
+    }
+
+    fn parameter_list(&self, typed: bool) -> String {
+        self.params
+            .iter()
+            .map(|(param, _, ty)| match typed {
+                true => format!("{param}: {ty}"),
+                false => param.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn args_object(&self) -> String {
+        if self.params.is_empty() {
+            return "Record<string, never>".into();
+        }
+        let fields = self
+            .params
+            .iter()
+            .map(|(_, arg, ty)| format!("{}: {ty}", key(arg)))
+            .collect::<Vec<_>>();
+        format!("{{ {} }}", fields.join("; "))
+    }
+
+    fn args_value(&self) -> String {
+        if self.params.is_empty() {
+            return "{}".into();
+        }
+        let fields = self
+            .params
+            .iter()
+            .map(|(param, arg, _)| match param == arg {
+                true => param.clone(),
+                false => format!("{}: {param}", key(arg)),
+            })
+            .collect::<Vec<_>>();
+        format!("{{ {} }}", fields.join(", "))
+    }
+}
+
+pub(crate) fn render(
+    ctx: &FrameworkExporter,
+    functions: &[Function],
+    style: FunctionStyle,
+) -> Result<String, Error> {
+    let signatures = functions
+        .iter()
+        .map(|function| signature(ctx, function))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut s = String::new();
+    match (style, ctx.jsdoc) {
+        (FunctionStyle::Declarations, false) => {
+            for signature in &signatures {
+                let function = signature.function;
+                let result = signature.result.as_deref().unwrap_or("void");
+                s.push('\n');
+                js_doc(&mut s, &signature.docs(), function.deprecated.as_ref());
+                s.push_str(&format!(
+                    "export declare function {}({}): {};\n",
+                    function.name,
+                    signature.parameter_list(true),
+                    match function.asyncness {
+                        true => format!("Promise<{result}>"),
+                        false => result.to_string(),
+                    }
+                ));
+            }
+        }
+        (FunctionStyle::Declarations, true) => {
+            for signature in &signatures {
+                let function = signature.function;
+                let result = signature.result.as_deref().unwrap_or("void");
+                let mut docs = signature.docs();
+                if !docs.is_empty() {
+                    docs.push('\n');
+                }
+                docs.push_str(&format!(
+                    "@typedef {{({}) => {}}} {}",
+                    signature.parameter_list(true),
+                    match function.asyncness {
+                        true => format!("Promise<{result}>"),
+                        false => result.to_string(),
+                    },
+                    function.name
+                ));
+                s.push('\n');
+                js_doc(&mut s, &docs, function.deprecated.as_ref());
+            }
+        }
+        (FunctionStyle::Client, jsdoc) => {
+            let entries = signatures
+                .iter()
+                .map(|signature| {
+                    format!(
+                        "{}: {{ args: {}; result: {}; error: {} }}",
+                        key(&signature.function.name),
+                        signature.args_object(),
+                        signature.result.as_deref().unwrap_or("void"),
+                        signature.error.as_deref().unwrap_or("never"),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let invoke = "<K extends keyof Functions>(name: K, args: Functions[K][\"args\"]) => Promise<Functions[K][\"result\"]>";
+
+            s.push('\n');
+            if jsdoc {
+                s.push_str("/**\n * @typedef {{\n");
+                for entry in &entries {
+                    s.push_str(&format!(" * \t{entry},\n"));
+                }
+                s.push_str(&format!(
+                    " * }}}} Functions\n */\n\n/**\n * @param {{{invoke}}} invoke\n */\nexport function createClient(invoke) {{\n"
+                ));
+            } else {
+                s.push_str("export type Functions = {\n");
+                for (signature, entry) in signatures.iter().zip(&entries) {
+                    let mut docs = String::new();
+                    js_doc(&mut docs, &signature.function.docs, None);
+                    for line in docs.lines() {
+                        s.push_str(&format!("\t{line}\n"));
+                    }
+                    s.push_str(&format!("\t{entry};\n"));
+                }
+                s.push_str(&format!(
+                    "}};\n\nexport function createClient(invoke: {invoke}) {{\n"
+                ));
+            }
+
+            s.push_str("\treturn {\n");
+            for signature in &signatures {
+                let function = signature.function;
+                let result = format!("Promise<{}>", signature.result.as_deref().unwrap_or("void"));
+                let mut docs = signature.docs();
+                if jsdoc {
+                    for (param, _, ty) in &signature.params {
+                        docs.push_str(&format!("\n@param {{{ty}}} {param}"));
+                    }
+                    docs.push_str(&format!("\n@returns {{{result}}}"));
+                }
+                let mut comments = String::new();
+                js_doc(
+                    &mut comments,
+                    docs.strip_prefix('\n').unwrap_or(&docs),
+                    function.deprecated.as_ref(),
+                );
+                for line in comments.lines() {
+                    s.push_str(&format!("\t\t{line}\n"));
+                }
+                s.push_str(&format!(
+                    "\t\t{}: ({}){} => invoke(\"{}\", {}),\n",
+                    key(&function.name),
+                    signature.parameter_list(!jsdoc),
+                    if jsdoc {
+                        String::new()
+                    } else {
+                        format!(": {result}")
+                    },
+                    escape_typescript_string_literal(&function.name),
+                    signature.args_value()
+                ));
+            }
+            s.push_str("\t};\n}\n");
+        }
+    }
+    Ok(s)
+}
+
+fn signature<'a>(ctx: &FrameworkExporter, function: &'a Function) -> Result<Signature<'a>, Error> {
+    if !is_identifier(&function.name) || RESERVED_TYPE_NAMES.contains(&function.name.as_ref()) {
+        return Err(Error::invalid_name(
+            function.name.to_string(),
+            function.name.clone(),
+        ));
+    }
+
+    let mut params = Vec::<(String, &str, String)>::new();
+    for (index, (name, dt)) in function.args.iter().enumerate() {
+        let mut param = match is_identifier(name) {
+            true => name.to_string(),
+            false => format!("arg{index}"),
+        };
+        while RESERVED_TYPE_NAMES.contains(&param.as_str())
+            || params.iter().any(|(other, _, _)| *other == param)
+        {
+            param.push('_');
+        }
+        params.push((param, name, ctx.reference(dt)?));
+    }
+
+    let (result, error) = match &function.result {
+        None => (None, None),
+        Some(dt) => match result_variants(ctx.types, dt) {
+            Some((ok, err)) => (Some(ctx.reference(ok)?), Some(ctx.reference(err)?)),
+            None => (Some(ctx.reference(dt)?), None),
+        },
+    };
+
+    Ok(Signature {
+        function,
+        params,
+        result,
+        error,
+    })
+}
+
+/// The success and error types of a `Result`.
+fn result_variants<'a>(types: &Types, dt: &'a DataType) -> Option<(&'a DataType, &'a DataType)> {
+    let DataType::Reference(Reference::Named(reference)) = dt else {
+        return None;
+    };
+    let ndt = types.get(reference)?;
+    if ndt.name != "Result" || ndt.module_path != "std::result" {
+        return None;
+    }
+    match &reference.inner {
+        NamedReferenceType::Reference { generics, .. } => match generics.as_slice() {
+            [(_, ok), (_, err)] => Some((ok, err)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn key(name: &str) -> Cow<'static, str> {
+    sanitise_key(Cow::Owned(name.to_string()), false)
+}
@@ -52,6 +52,7 @@
 mod branded;
 mod error;
 mod exporter;
+mod functions;
 mod jsdoc;
 mod map_keys;
 mod opaque;
@@ -59,12 +60,17 @@ pub mod primitives;
 mod references;
 pub(crate) mod reserved_names;
 pub mod semantic;
+mod source_map;
 mod types;
 mod typescript;
 
 pub use branded::Branded;
 pub use error::{Error, ErrorTraceFrame};
-pub use exporter::{BrandedTypeExporter, Exporter, FrameworkExporter, Layout};
+pub use exporter::{
+    BrandedTypeExporter, Exporter, FrameworkExporter, ImportExtension, Layout, OptionalStyle,
+    UnitEnumStyle,
+};
+pub use functions::FunctionStyle;
 pub use jsdoc::JSDoc;
 pub use opaque::define;
 pub use references::{collect_references, with_module_path};
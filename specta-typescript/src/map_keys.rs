@@ -182,6 +182,15 @@ fn validate_map_key_inner(
             // Tauri channels, branded types -- still fail loudly.
             if r.downcast_ref::<crate::opaque::Define>().is_some() {
                 Ok(())
+            } else if let Some(literal) = r.downcast_ref::<specta::datatype::Literal>() {
+                if primitive_is_valid_key(literal.primitive()) {
+                    Ok(())
+                } else {
+                    Err(Error::invalid_map_key(
+                        path,
+                        invalid_primitive_reason(literal.primitive()),
+                    ))
+                }
             } else {
                 Err(Error::invalid_map_key(
                     path,
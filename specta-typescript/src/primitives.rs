@@ -6,20 +6,21 @@
 use std::{
     borrow::Cow,
     collections::{BTreeSet, HashMap, HashSet, hash_map::Entry},
+    ops::Deref,
 };
 
 use specta::{
     Format, Types,
     datatype::{
-        DataType, Deprecated, Enum, Field, Fields, GenericDefinition, GenericReference, List, Map,
-        NamedDataType, NamedReference, NamedReferenceType, OpaqueReference, Primitive, Reference,
-        Struct, Tuple, Variant,
+        Attributes, DataType, Deprecated, Enum, Field, Fields, GenericDefinition, GenericReference,
+        List, Literal, LiteralValue, Map, NamedDataType, NamedReference, NamedReferenceType,
+        OpaqueReference, Primitive, Reference, Struct, Tuple, Variant,
     },
 };
 
 use crate::{
-    Branded, BrandedTypeExporter, Error, Exporter, Layout, map_keys, opaque,
-    reserved_names::RESERVED_TYPE_NAMES,
+    Branded, BrandedTypeExporter, Error, Exporter, Layout, OptionalStyle, UnitEnumStyle, map_keys,
+    opaque, reserved_names::RESERVED_TYPE_NAMES, source_map,
 };
 
 const STRING: &str = "string";
@@ -27,6 +28,9 @@ const NULL: &str = "null";
 const NEVER: &str = "never";
 const FIELD_ALIAS_UNION_MARKER: &str = "specta_serde:deferred_alias_union";
 const FIELD_ALIAS_EXCLUSION_MARKER: &str = "specta_serde:alias_exclusion";
+const SERDE_ENUM_REPR: &str = "specta_serde:enum_repr";
+const SERDE_ENUM_TAG: &str = "specta_serde:enum_tag";
+const SERDE_VARIANT_TAG: &str = "specta_serde:variant_tag";
 
 fn path_string(location: &[Cow<'static, str>]) -> String {
     location.join(".")
@@ -49,14 +53,14 @@ fn module_prefixed_type_name(ndt: &NamedDataType) -> String {
     name
 }
 
-fn exported_type_name<'a>(exporter: &Exporter, ndt: &'a NamedDataType) -> Cow<'a, str> {
+fn exported_type_name<'a>(exporter: Render<'_>, ndt: &'a NamedDataType) -> Cow<'a, str> {
     match exporter.layout {
         Layout::ModulePrefixedName => Cow::Owned(module_prefixed_type_name(ndt)),
         _ => ndt.name.clone(),
     }
 }
 
-fn referenced_type_name<'a>(exporter: &Exporter, ndt: &'a NamedDataType) -> Cow<'a, str> {
+fn referenced_type_name<'a>(exporter: Render<'_>, ndt: &'a NamedDataType) -> Cow<'a, str> {
     match exporter.layout {
         Layout::ModulePrefixedName => Cow::Owned(module_prefixed_type_name(ndt)),
         Layout::Namespaces => {
@@ -119,6 +123,45 @@ fn inner_comments(
     out
 }
 
+/// Appends the `@default` and `@example` tags of an object field or variant to its docs.
+///
+/// JSDoc typedefs carry them in their `@property` descriptions instead, see [`jsdoc_description`].
+fn with_value_tags<'a>(
+    exporter: Render<'_>,
+    docs: &'a str,
+    attributes: &Attributes,
+) -> Cow<'a, str> {
+    if exporter.jsdoc {
+        return Cow::Borrowed(docs);
+    }
+
+    let mut docs = Cow::Borrowed(docs);
+    for (tag, value) in value_tags(attributes) {
+        let docs = docs.to_mut();
+        if !docs.is_empty() {
+            docs.push('\n');
+        }
+        docs.push_str(tag);
+        docs.push(' ');
+        docs.push_str(value);
+    }
+    docs
+}
+
+/// The `@default` and `@example` tags recorded on a field or variant.
+fn value_tags(attributes: &Attributes) -> impl Iterator<Item = (&'static str, &str)> {
+    attributes
+        .default_value()
+        .map(|value| ("@default", value))
+        .into_iter()
+        .chain(
+            attributes
+                .examples()
+                .iter()
+                .map(|value| ("@example", value.as_ref())),
+        )
+}
+
 pub(crate) fn is_identifier(name: &str) -> bool {
     let mut chars = name.chars();
     let Some(first) = chars.next() else {
@@ -129,6 +172,64 @@ pub(crate) fn is_identifier(name: &str) -> bool {
         && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$')
 }
 
+/// Largest integer which a Javascript `number` can represent exactly.
+const MAX_SAFE_INTEGER: u128 = (1 << 53) - 1;
+
+pub(crate) fn push_literal(
+    s: &mut String,
+    literal: &Literal,
+    location: &[Cow<'static, str>],
+) -> Result<(), Error> {
+    match literal.value() {
+        LiteralValue::Bool(v) => s.push_str(if v { "true" } else { "false" }),
+        LiteralValue::Int(v) if v.unsigned_abs() <= MAX_SAFE_INTEGER => s.push_str(&v.to_string()),
+        LiteralValue::UInt(v) if v <= MAX_SAFE_INTEGER => s.push_str(&v.to_string()),
+        LiteralValue::Int(_) | LiteralValue::UInt(_) => {
+            return Err(Error::unsupported_literal(
+                path_string(location),
+                literal.clone(),
+                "it is outside of `Number.MAX_SAFE_INTEGER`",
+            ));
+        }
+        LiteralValue::Float(v) if v.is_nan() => {
+            return Err(Error::unsupported_literal(
+                path_string(location),
+                literal.clone(),
+                "`NaN` has no literal type",
+            ));
+        }
+        LiteralValue::Float(v) if v.is_infinite() => {
+            return Err(Error::unsupported_literal(
+                path_string(location),
+                literal.clone(),
+                "`Infinity` has no literal type",
+            ));
+        }
+        LiteralValue::Float(v) => s.push_str(&v.to_string()),
+        LiteralValue::Char(v) => {
+            s.push('"');
+            s.push_str(&escape_typescript_string_literal(
+                v.encode_utf8(&mut [0; 4]),
+            ));
+            s.push('"');
+        }
+        LiteralValue::Str(v) => {
+            s.push('"');
+            s.push_str(&escape_typescript_string_literal(v));
+            s.push('"');
+        }
+        _ => {
+            return Err(Error::unsupported_literal(
+                path_string(location),
+                literal.clone(),
+                "this kind of literal is not supported",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 pub(crate) fn escape_typescript_string_literal(value: &str) -> Cow<'_, str> {
     if !value.chars().any(|ch| {
         ch == '"' || ch == '\\' || ch == '\u{2028}' || ch == '\u{2029}' || ch.is_control()
@@ -165,7 +266,7 @@ fn push_unicode_escape(s: &mut String, ch: char) {
     s.push(HEX[(value & 0xF) as usize] as char);
 }
 
-fn sanitise_key<'a>(field_name: Cow<'static, str>, force_string: bool) -> Cow<'a, str> {
+pub(crate) fn sanitise_key<'a>(field_name: Cow<'static, str>, force_string: bool) -> Cow<'a, str> {
     if force_string || !is_identifier(&field_name) {
         format!(r#""{}""#, escape_typescript_string_literal(&field_name)).into()
     } else {
@@ -173,7 +274,10 @@ fn sanitise_key<'a>(field_name: Cow<'static, str>, force_string: bool) -> Cow<'a
     }
 }
 
-fn sanitise_type_name(location: &[Cow<'static, str>], ident: &str) -> Result<String, Error> {
+pub(crate) fn sanitise_type_name(
+    location: &[Cow<'static, str>],
+    ident: &str,
+) -> Result<String, Error> {
     let path = path_string(location);
 
     if ident.is_empty() {
@@ -237,6 +341,24 @@ pub(crate) fn js_doc(s: &mut String, docs: &str, deprecated: Option<&Deprecated>
     s.push_str(" */\n");
 }
 
+/// The `@see` tag linking a type to its Rust source, when the exporter asks for it.
+fn see_tag(exporter: Render<'_>, ndt: &NamedDataType) -> Option<String> {
+    exporter.source_locations.then(|| {
+        format!(
+            "@see {}:{}",
+            source_map::source_path(ndt.location.file()),
+            ndt.location.line()
+        )
+    })
+}
+
+/// Marks the line written next as the declaration of `ndt`, when the exporter writes source maps.
+fn push_source_marker(s: &mut String, exporter: Render<'_>, ndt: &NamedDataType) {
+    if exporter.declaration_maps.is_some() {
+        s.push_str(&source_map::marker(&ndt.location));
+    }
+}
+
 pub(crate) fn escape_jsdoc_text(text: &str) -> Cow<'_, str> {
     if text.contains("*/") {
         Cow::Owned(text.replace("*/", "*\\/"))
@@ -275,7 +397,7 @@ pub fn export<'a>(
 ) -> Result<String, Error> {
     let mut s = String::new();
     export_internal(&mut s, exporter.as_ref(), None, types, ndts, indent)?;
-    Ok(s)
+    Ok(source_map::strip_markers(exporter.as_ref(), s))
 }
 
 pub(crate) fn export_internal<'a>(
@@ -286,18 +408,19 @@ pub(crate) fn export_internal<'a>(
     ndts: impl Iterator<Item = &'a NamedDataType>,
     indent: &str,
 ) -> Result<(), Error> {
+    let exporter = Render::from(exporter);
     let ndts = ndts.filter(|ndt| ndt.ty.is_some());
 
     if exporter.jsdoc {
-        let mut ndts = ndts.peekable();
-        if ndts.peek().is_none() {
+        let ndts = ndts.collect::<Vec<_>>();
+        if ndts.is_empty() {
             return Ok(());
         }
 
         s.push_str(indent);
         s.push_str("/**\n");
 
-        for (index, ndt) in ndts.enumerate() {
+        for (index, ndt) in ndts.iter().enumerate() {
             if index != 0 {
                 s.push_str(indent);
                 s.push_str("\t*\n");
@@ -308,6 +431,15 @@ pub(crate) fn export_internal<'a>(
 
         s.push_str(indent);
         s.push_str("\t*/\n");
+
+        // Values cannot live in the typedef comment, so unit enum objects follow it.
+        for ndt in ndts {
+            if let Some(members) = unit_enum_members(exporter, ndt) {
+                s.push('\n');
+                unit_enum_declaration(s, exporter, &ndt.name, &members, "", indent);
+            }
+            tagged_union_helpers(s, exporter, ndt, &ndt.name, indent);
+        }
         return Ok(());
     }
 
@@ -324,7 +456,7 @@ pub(crate) fn export_internal<'a>(
 
 fn export_single_internal(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     ndt: &NamedDataType,
@@ -338,6 +470,11 @@ fn export_single_internal(
             s.push_str(line);
             s.push('\n');
         }
+        if let Some(members) = unit_enum_members(exporter, ndt) {
+            s.push('\n');
+            unit_enum_declaration(s, exporter, &ndt.name, &members, "", indent);
+        }
+        tagged_union_helpers(s, exporter, ndt, &ndt.name, indent);
         return Ok(());
     }
 
@@ -345,8 +482,16 @@ fn export_single_internal(
     let name = sanitise_type_name(&[rust_type_path(ndt)], &raw_name)
         .map_err(|err| err.with_named_datatype(ndt))?;
 
+    let mut docs = Cow::Borrowed(ndt.docs.as_ref());
+    if let Some(see) = see_tag(exporter, ndt) {
+        let docs = docs.to_mut();
+        if !docs.is_empty() {
+            docs.push('\n');
+        }
+        docs.push_str(&see);
+    }
     let mut comments = String::new();
-    js_doc(&mut comments, &ndt.docs, ndt.deprecated.as_ref());
+    js_doc(&mut comments, &docs, ndt.deprecated.as_ref());
     if !comments.is_empty() {
         for line in comments.lines() {
             s.push_str(indent);
@@ -355,6 +500,12 @@ fn export_single_internal(
         }
     }
 
+    push_source_marker(s, exporter, ndt);
+    if let Some(members) = unit_enum_members(exporter, ndt) {
+        unit_enum_declaration(s, exporter, &name, &members, &comments, indent);
+        return Ok(());
+    }
+
     s.push_str(indent);
     s.push_str("export type ");
     s.push_str(&name);
@@ -376,6 +527,8 @@ fn export_single_internal(
     .map_err(|err| err.with_named_datatype(ndt))?;
     s.push_str(";\n");
 
+    tagged_union_helpers(s, exporter, ndt, &name, indent);
+
     Ok(())
 }
 
@@ -397,7 +550,7 @@ pub fn inline(
     let mut s = String::new();
     inline_datatype(
         &mut s,
-        exporter.as_ref(),
+        exporter.as_ref().into(),
         None,
         types,
         dt,
@@ -414,7 +567,7 @@ pub fn inline(
 // Externally this shouldn't be a concern so we don't expose it.
 pub(crate) fn typedef_internal(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     dt: &NamedDataType,
@@ -429,7 +582,7 @@ pub(crate) fn typedef_internal(
 
 fn append_jsdoc_properties(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     dt_name: &str,
@@ -459,6 +612,7 @@ fn append_jsdoc_properties(
                         Default::default(),
                     )?;
 
+                    push_optional_undefined(&mut ty_str, exporter, field);
                     push_jsdoc_property(
                         s,
                         &ty_str,
@@ -466,6 +620,7 @@ fn append_jsdoc_properties(
                         field.optional,
                         &field.docs,
                         field.deprecated.as_ref(),
+                        &field.attributes,
                         indent,
                     );
                 }
@@ -490,6 +645,7 @@ fn append_jsdoc_properties(
                         Default::default(),
                     )?;
 
+                    push_optional_undefined(&mut ty_str, exporter, field);
                     push_jsdoc_property(
                         s,
                         &ty_str,
@@ -497,6 +653,7 @@ fn append_jsdoc_properties(
                         field.optional,
                         &field.docs,
                         field.deprecated.as_ref(),
+                        &field.attributes,
                         indent,
                     );
                 }
@@ -527,6 +684,7 @@ fn append_jsdoc_properties(
                     false,
                     &variant.docs,
                     variant.deprecated.as_ref(),
+                    &variant.attributes,
                     indent,
                 );
             }
@@ -549,6 +707,7 @@ fn push_jsdoc_property(
     optional: bool,
     docs: &str,
     deprecated: Option<&Deprecated>,
+    attributes: &Attributes,
     indent: &str,
 ) {
     s.push_str(indent);
@@ -557,7 +716,7 @@ fn push_jsdoc_property(
     s.push_str("} ");
     s.push_str(&jsdoc_property_name(name, optional));
 
-    if let Some(description) = jsdoc_description(docs, deprecated) {
+    if let Some(description) = jsdoc_description(docs, deprecated, attributes) {
         s.push_str(" - ");
         s.push_str(&description);
     }
@@ -599,7 +758,7 @@ fn jsdoc_property_name(name: &str, optional: bool) -> String {
 
 fn append_typedef_body(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     dt: &NamedDataType,
@@ -615,21 +774,26 @@ fn append_typedef_body(
         &dt.generics,
     )?;
 
+    let unit_enum = unit_enum_members(exporter, dt).is_some();
     let mut typedef_ty = String::new();
-    let datatype_prefix = format!("{indent}\t*\t");
-    let body = resolve_named_export_body(types, dt)?;
-    datatype(
-        &mut typedef_ty,
-        exporter,
-        format,
-        types,
-        &body,
-        vec![rust_type_path(dt)],
-        Some(dt.name.as_ref()),
-        &datatype_prefix,
-        Default::default(),
-    )
-    .map_err(|err| err.with_named_datatype(dt))?;
+    if unit_enum {
+        typedef_ty = format!("(typeof {name})[keyof typeof {name}]");
+    } else {
+        let datatype_prefix = format!("{indent}\t*\t");
+        let body = resolve_named_export_body(types, dt)?;
+        datatype(
+            &mut typedef_ty,
+            exporter,
+            format,
+            types,
+            &body,
+            vec![rust_type_path(dt)],
+            Some(dt.name.as_ref()),
+            &datatype_prefix,
+            Default::default(),
+        )
+        .map_err(|err| err.with_named_datatype(dt))?;
+    }
 
     if !dt.docs.is_empty() {
         for line in dt.docs.lines() {
@@ -652,6 +816,14 @@ fn append_typedef_body(
         s.push('\n');
     }
 
+    if let Some(see) = see_tag(exporter, dt) {
+        s.push_str(indent);
+        s.push_str("\t* ");
+        s.push_str(&see);
+        s.push('\n');
+    }
+
+    push_source_marker(s, exporter, dt);
     s.push_str(indent);
     s.push_str("\t* @typedef {");
     push_jsdoc_type(s, &typedef_ty, indent);
@@ -659,7 +831,9 @@ fn append_typedef_body(
     s.push_str(&type_name);
     s.push('\n');
 
-    if let Some(ty) = &dt.ty {
+    if let Some(ty) = &dt.ty
+        && !unit_enum
+    {
         let dt_path = rust_type_path(dt);
         append_jsdoc_properties(s, exporter, format, types, dt_path.as_ref(), ty, indent)?;
     }
@@ -667,9 +841,287 @@ fn append_typedef_body(
     Ok(())
 }
 
+/// The string each variant of an enum serializes as, beside the variant, when every variant is a
+/// plain string and the exporter declares such enums as values.
+fn unit_enum_members<'a>(
+    exporter: Render<'_>,
+    ndt: &'a NamedDataType,
+) -> Option<Vec<(&'a str, &'a Variant)>> {
+    if exporter.unit_enum_style == UnitEnumStyle::Union || !ndt.generics.is_empty() {
+        return None;
+    }
+    let Some(DataType::Enum(e)) = &ndt.ty else {
+        return None;
+    };
+    if e.attributes.get_named_as(FIELD_ALIAS_UNION_MARKER) == Some(&true)
+        || e.attributes.get_named_as("specta:finite_number") == Some(&true)
+    {
+        return None;
+    }
+
+    let variants = active_variants(e);
+    if variants.is_empty() {
+        return None;
+    }
+    variants
+        .into_iter()
+        .map(|(name, variant)| {
+            let literal = match &variant.fields {
+                Fields::Unit => name,
+                // Serde formats lower a unit variant to a newtype of its string literal.
+                Fields::Unnamed(unnamed) => match unnamed.fields.as_slice() {
+                    [field] => match &field.ty {
+                        Some(DataType::Enum(literal)) => match literal.variants.as_slice() {
+                            [(name, literal)] if matches!(literal.fields, Fields::Unit) => name,
+                            _ => return None,
+                        },
+                        _ => return None,
+                    },
+                    _ => return None,
+                },
+                Fields::Named(_) => return None,
+            };
+            (!literal.is_empty()).then_some((literal.as_ref(), variant))
+        })
+        .collect()
+}
+
+/// The tag field of an internally or adjacently tagged enum and the tag of each variant, as
+/// recorded by `specta_serde` when it rewrote the enum, when the exporter emits helpers for
+/// such enums.
+fn tagged_union_variants<'a>(
+    exporter: Render<'_>,
+    ndt: &'a NamedDataType,
+) -> Option<(&'a str, Vec<&'a str>)> {
+    if !exporter.tagged_union_helpers {
+        return None;
+    }
+    let Some(DataType::Enum(e)) = &ndt.ty else {
+        return None;
+    };
+    if !matches!(
+        e.attributes
+            .get_named_as::<String>(SERDE_ENUM_REPR)
+            .map(String::as_str),
+        Some("internal" | "adjacent")
+    ) {
+        return None;
+    }
+    let key = e.attributes.get_named_as::<String>(SERDE_ENUM_TAG)?;
+
+    let mut tags = Vec::new();
+    for (_, variant) in active_variants(e) {
+        // Untagged and `#[serde(other)]` variants have no tag to match on.
+        let tag = variant
+            .attributes
+            .get_named_as::<String>(SERDE_VARIANT_TAG)?;
+        if tags.contains(&tag.as_str()) {
+            return None;
+        }
+        tags.push(tag.as_str());
+    }
+
+    Some((key, tags))
+}
+
+/// Emits the type guards and exhaustive matcher of a tagged enum: see
+/// [`Exporter::tagged_union_helpers`].
+fn tagged_union_helpers(
+    s: &mut String,
+    exporter: Render<'_>,
+    ndt: &NamedDataType,
+    name: &str,
+    indent: &str,
+) {
+    let Some((key, tags)) = tagged_union_variants(exporter, ndt) else {
+        return;
+    };
+
+    let generics = ndt
+        .generics
+        .iter()
+        .map(|generic| generic.name.as_ref())
+        .collect::<Vec<_>>();
+    let ty = format!("{name}{}", type_parameters(&generics));
+    let mut result = String::from("R");
+    while generics.contains(&result.as_str()) {
+        result.push('_');
+    }
+    let tag_access = if is_identifier(key) {
+        format!("value.{key}")
+    } else {
+        format!("value[\"{}\"]", escape_typescript_string_literal(key))
+    };
+    let tag_key = sanitise_key(Cow::Owned(key.to_string()), false);
+    let variant_ty = |tag: &str| {
+        format!(
+            "Extract<{ty}, {{ {tag_key}: \"{}\" }}>",
+            escape_typescript_string_literal(tag)
+        )
+    };
+
+    let mut out = String::new();
+    let mut guards = BTreeSet::new();
+    for tag in &tags {
+        let mut guard = format!(
+            "is{name}_{}",
+            tag.chars()
+                .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+                    c
+                } else {
+                    '_'
+                })
+                .collect::<String>()
+        );
+        while !guards.insert(guard.clone()) {
+            guard.push('_');
+        }
+        let literal = escape_typescript_string_literal(tag);
+
+        out.push('\n');
+        if exporter.jsdoc {
+            out.push_str("/**\n");
+            for generic in &generics {
+                out.push_str(&format!(" * @template {generic}\n"));
+            }
+            out.push_str(&format!(
+                " * @param {{{ty}}} value\n * @returns {{value is {}}}\n */\nexport function {guard}(value) {{\n",
+                variant_ty(tag)
+            ));
+        } else {
+            out.push_str(&format!(
+                "export function {guard}{}(value: {ty}): value is {} {{\n",
+                type_parameters(&generics),
+                variant_ty(tag)
+            ));
+        }
+        out.push_str(&format!("\treturn {tag_access} === \"{literal}\";\n}}\n"));
+    }
+
+    let cases = tags
+        .iter()
+        .map(|tag| {
+            format!(
+                "{}: (value: {}) => {result}",
+                sanitise_key(Cow::Owned(tag.to_string()), false),
+                variant_ty(tag)
+            )
+        })
+        .collect::<Vec<_>>();
+    out.push('\n');
+    if exporter.jsdoc {
+        out.push_str("/**\n");
+        for generic in generics.iter().chain([&result.as_str()]) {
+            out.push_str(&format!(" * @template {generic}\n"));
+        }
+        out.push_str(&format!(
+            " * @param {{{ty}}} value\n * @param {{{{ {} }}}} cases\n * @returns {{{result}}}\n */\nexport function match{name}(value, cases) {{\n\treturn /** @type {{(value: {ty}) => {result}}} */ (cases[{tag_access}])(value);\n}}\n",
+            cases.join(", ")
+        ));
+    } else {
+        let mut parameters = generics.clone();
+        parameters.push(&result);
+        out.push_str(&format!(
+            "export function match{name}{}(value: {ty}, cases: {{\n{}}}): {result} {{\n\treturn (cases[{tag_access}] as (value: {ty}) => {result})(value);\n}}\n",
+            type_parameters(&parameters),
+            cases
+                .iter()
+                .map(|case| format!("\t{case};\n"))
+                .collect::<String>()
+        ));
+    }
+
+    for line in out.lines() {
+        if !line.is_empty() {
+            s.push_str(indent);
+        }
+        s.push_str(line);
+        s.push('\n');
+    }
+}
+
+fn type_parameters(names: &[&str]) -> String {
+    if names.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", names.join(", "))
+    }
+}
+
+/// Declares a unit-only enum as a value, in the exporter's [`UnitEnumStyle`]. `comments` are the
+/// enum's own, repeated on the derived type of an object.
+fn unit_enum_declaration(
+    s: &mut String,
+    exporter: Render<'_>,
+    name: &str,
+    members: &[(&str, &Variant)],
+    comments: &str,
+    indent: &str,
+) {
+    let style = match exporter.unit_enum_style {
+        // JavaScript has no `enum` syntax.
+        _ if exporter.jsdoc => UnitEnumStyle::AsConst,
+        style => style,
+    };
+    let (open, separator, close) = match style {
+        UnitEnumStyle::Enum => (format!("export enum {name} {{"), " = ", "}"),
+        UnitEnumStyle::ConstEnum => (format!("export const enum {name} {{"), " = ", "}"),
+        _ if exporter.jsdoc => (
+            format!("export const {name} = Object.freeze(/** @type {{const}} */ ({{"),
+            ": ",
+            "}));",
+        ),
+        _ => (
+            format!("export const {name} = Object.freeze({{"),
+            ": ",
+            "} as const);",
+        ),
+    };
+
+    s.push_str(indent);
+    s.push_str(&open);
+    s.push('\n');
+    for (literal, variant) in members {
+        let mut member_comments = String::new();
+        js_doc(
+            &mut member_comments,
+            &variant.docs,
+            variant.deprecated.as_ref(),
+        );
+        for line in member_comments.lines() {
+            s.push_str(indent);
+            s.push('\t');
+            s.push_str(line);
+            s.push('\n');
+        }
+        s.push_str(indent);
+        s.push('\t');
+        s.push_str(&sanitise_key(Cow::Owned(literal.to_string()), false));
+        s.push_str(separator);
+        s.push_str(&sanitise_key(Cow::Owned(literal.to_string()), true));
+        s.push_str(",\n");
+    }
+    s.push_str(indent);
+    s.push_str(close);
+    s.push('\n');
+
+    if style == UnitEnumStyle::AsConst && !exporter.jsdoc {
+        s.push('\n');
+        for line in comments.lines() {
+            s.push_str(indent);
+            s.push_str(line);
+            s.push('\n');
+        }
+        s.push_str(indent);
+        s.push_str(&format!(
+            "export type {name} = (typeof {name})[keyof typeof {name}];\n"
+        ));
+    }
+}
+
 fn write_generic_parameters(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     types: &Types,
     parent_location: &[Cow<'static, str>],
     generics: &[GenericDefinition],
@@ -710,12 +1162,21 @@ fn write_generic_parameters(
     Ok(())
 }
 
-fn jsdoc_description(docs: &str, deprecated: Option<&Deprecated>) -> Option<String> {
+/// The description of a JSDoc `@property`, which is a single line so its tags are inlined.
+fn jsdoc_description(
+    docs: &str,
+    deprecated: Option<&Deprecated>,
+    attributes: &Attributes,
+) -> Option<String> {
     let docs = docs
         .lines()
         .map(str::trim)
         .filter(|line| !line.is_empty())
         .map(|line| escape_jsdoc_text(line).into_owned())
+        .chain(
+            value_tags(attributes)
+                .map(|(tag, value)| format!("{tag} {}", escape_jsdoc_text(value))),
+        )
         .collect::<Vec<_>>()
         .join(" ");
 
@@ -751,7 +1212,7 @@ pub fn reference(
     let mut s = String::new();
     datatype(
         &mut s,
-        exporter.as_ref(),
+        exporter.as_ref().into(),
         None,
         types,
         dt,
@@ -765,7 +1226,7 @@ pub fn reference(
 
 pub(crate) fn datatype_with_inline_attr(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     dt: &DataType,
@@ -911,7 +1372,7 @@ fn resolved_reference_generics(
 
 #[derive(Clone, Copy)]
 struct RenderCtx<'a> {
-    exporter: &'a Exporter,
+    exporter: Render<'a>,
     format: Option<&'a dyn Format>,
     types: &'a Types,
     parent_name: Option<&'a str>,
@@ -998,7 +1459,7 @@ fn render_datatype(
         (RenderMode::ShallowInline, DataType::List(list)) => {
             let mut inner = String::new();
             render_datatype(&mut inner, ctx, &list.ty, location, mode)?;
-            push_list(s, &inner, list.length);
+            push_list(s, ctx.exporter, &inner, list.length);
         }
         (RenderMode::Normal, DataType::Map(map)) => {
             map_dt(
@@ -1055,6 +1516,7 @@ fn render_datatype(
         (RenderMode::ShallowInline, DataType::Tuple(tuple)) => match tuple.elements.as_slice() {
             [] => s.push_str(NULL),
             elements => {
+                push_readonly(s, ctx.exporter);
                 s.push('[');
                 for (idx, dt) in elements.iter().enumerate() {
                     if idx != 0 {
@@ -1135,13 +1597,14 @@ fn needs_array_parens(ty: &str) -> bool {
     ty.contains(' ') && (!ty.ends_with('}') || ty.contains('&') || ty.contains('|'))
 }
 
-fn push_list(s: &mut String, ty: &str, length: Option<usize>) {
+fn push_list(s: &mut String, exporter: Render<'_>, ty: &str, length: Option<usize>) {
     let ty = if needs_array_parens(ty) {
         Cow::Owned(format!("({ty})"))
     } else {
         Cow::Borrowed(ty)
     };
 
+    push_readonly(s, exporter);
     if let Some(length) = length {
         s.push('[');
         for i in 0..length {
@@ -1166,6 +1629,57 @@ fn intersection_part_is_union(ty: &DataType) -> bool {
     }
 }
 
+/// The [`Exporter`] a node is rendered with, and whether the node is `readonly`, which starts as
+/// [`Exporter::readonly`] and is overridden by [`Attributes::readonly`] for everything within the
+/// node carrying it.
+#[derive(Clone, Copy)]
+pub(crate) struct Render<'a> {
+    exporter: &'a Exporter,
+    readonly: bool,
+}
+
+impl<'a> From<&'a Exporter> for Render<'a> {
+    fn from(exporter: &'a Exporter) -> Self {
+        Self {
+            exporter,
+            readonly: exporter.readonly,
+        }
+    }
+}
+
+impl Deref for Render<'_> {
+    type Target = Exporter;
+
+    fn deref(&self) -> &Exporter {
+        self.exporter
+    }
+}
+
+impl Render<'_> {
+    /// Renders a node with the [`Attributes::readonly`] override in its `attributes`, if it has one.
+    fn with_attributes(mut self, attributes: &Attributes) -> Self {
+        if let Some(readonly) = attributes.readonly() {
+            self.readonly = readonly;
+        }
+        self
+    }
+}
+
+/// Marks the array, tuple or property written next `readonly`, when the exporter asks for it.
+fn push_readonly(s: &mut String, exporter: Render<'_>) {
+    if exporter.readonly {
+        s.push_str("readonly ");
+    }
+}
+
+/// Widens the type of an optional property written last with `undefined`, when the exporter asks
+/// for it.
+fn push_optional_undefined(s: &mut String, exporter: Render<'_>, field: &Field) {
+    if field.optional && exporter.optional_style == OptionalStyle::OptionalUndefined {
+        s.push_str(" | undefined");
+    }
+}
+
 fn push_nullable(s: &mut String, inner: &str) {
     s.push_str(inner);
     if inner != NULL && !inner.ends_with(" | null") {
@@ -1223,7 +1737,7 @@ fn is_exhaustive_map_key(
 
 fn render_map(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     map: &Map,
@@ -1244,7 +1758,9 @@ fn render_map(
         s.push_str("Partial<");
     }
 
-    s.push_str("{ [key in ");
+    s.push_str("{ ");
+    push_readonly(s, exporter);
+    s.push_str("[key in ");
     map_key_datatype(
         s,
         exporter,
@@ -1281,7 +1797,7 @@ fn render_map(
 
 fn shallow_inline_datatype(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     dt: &DataType,
@@ -1308,7 +1824,7 @@ fn shallow_inline_datatype(
 
 fn intersection_dt(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     parts: &[DataType],
@@ -1344,7 +1860,7 @@ fn intersection_dt(
 // Render an anonymous type while expanding core-provided inline references.
 fn inline_datatype(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     dt: &DataType,
@@ -1377,7 +1893,7 @@ fn inline_datatype(
                 prefix,
                 generics,
             )?;
-            push_list(s, &dt_str, l.length);
+            push_list(s, exporter, &dt_str, l.length);
         }
         DataType::Map(m) => map_dt(s, exporter, format, types, m, location, generics)?,
         DataType::Nullable(def) => {
@@ -1469,7 +1985,7 @@ fn inline_datatype(
 
 fn inline_struct_with_generics(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     st: &Struct,
@@ -1479,6 +1995,7 @@ fn inline_struct_with_generics(
     depth: usize,
     generics: &[(GenericReference, DataType)],
 ) -> Result<(), Error> {
+    let exporter = exporter.with_attributes(&st.attributes);
     match &st.fields {
         Fields::Unit => s.push_str(NULL),
         Fields::Unnamed(_) => struct_dt(
@@ -1502,9 +2019,11 @@ fn inline_struct_with_generics(
                 };
 
                 has_field = true;
+                let exporter = exporter.with_attributes(&field.attributes);
                 s.push('\n');
                 s.push_str(prefix);
                 s.push('\t');
+                push_readonly(s, exporter);
                 s.push_str(&sanitise_key(key.clone(), false));
                 if field.optional {
                     s.push('?');
@@ -1522,6 +2041,7 @@ fn inline_struct_with_generics(
                     depth + 1,
                     generics,
                 )?;
+                push_optional_undefined(s, exporter, field);
                 s.push(',');
             }
 
@@ -1539,7 +2059,7 @@ fn inline_struct_with_generics(
 
 pub(crate) fn datatype(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     dt: &DataType,
@@ -1581,7 +2101,7 @@ fn primitive_dt(p: &Primitive, location: Vec<Cow<'static, str>>) -> Result<&'sta
 
 fn list_dt(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     types: &Types,
     l: &List,
     location: Vec<Cow<'static, str>>,
@@ -1591,14 +2111,14 @@ fn list_dt(
     datatype(
         &mut dt, exporter, None, types, &l.ty, location, None, "", generics,
     )?;
-    push_list(s, &dt, l.length);
+    push_list(s, exporter, &dt, l.length);
 
     Ok(())
 }
 
 fn map_key_datatype(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     key_ty: &DataType,
@@ -1627,7 +2147,7 @@ fn map_key_datatype(
 
 fn map_dt(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     m: &Map,
@@ -1677,7 +2197,7 @@ fn bool_key_literal_datatype() -> DataType {
 
 fn unnamed_fields_datatype(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     fields: &[(&Field, &DataType)],
@@ -1718,6 +2238,7 @@ fn unnamed_fields_datatype(
         fields => {
             let optional_from = trailing_optional_run(fields.iter().map(|(field, _)| *field));
 
+            push_readonly(s, exporter);
             s.push('[');
             for (i, (field, ty)) in fields.iter().enumerate() {
                 if i != 0 {
@@ -1803,7 +2324,7 @@ fn optional_element_needs_parens(ty: &DataType) -> bool {
 
 fn struct_dt(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     st: &Struct,
@@ -1812,6 +2333,7 @@ fn struct_dt(
     prefix: &str,
     generics: &[(GenericReference, DataType)],
 ) -> Result<(), Error> {
+    let exporter = exporter.with_attributes(&st.attributes);
     match &st.fields {
         Fields::Unit => s.push_str(NULL),
         Fields::Unnamed(unnamed) => unnamed_fields_datatype(
@@ -1866,7 +2388,7 @@ fn struct_dt(
 
                 unflattened_fields.push(inner_comments(
                     field.deprecated.as_ref(),
-                    &field.docs,
+                    &with_value_tags(exporter, &field.docs, &field.attributes),
                     other,
                     false,
                     &field_prefix,
@@ -1890,7 +2412,7 @@ fn struct_dt(
 
 fn object_field_to_ts(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     key: Cow<'static, str>,
@@ -1902,6 +2424,7 @@ fn object_field_to_ts(
     force_inline: bool,
     ty_override: Option<&str>,
 ) -> Result<(), Error> {
+    let exporter = exporter.with_attributes(&field.attributes);
     let field_name_safe = sanitise_key(key, false);
     let key = if field.optional {
         format!("{field_name_safe}?").into()
@@ -1909,7 +2432,7 @@ fn object_field_to_ts(
         field_name_safe
     };
 
-    let value = match ty_override {
+    let mut value = match ty_override {
         Some(ty_override) => ty_override.to_string(),
         None => {
             let mut value = String::new();
@@ -1929,7 +2452,10 @@ fn object_field_to_ts(
         }
     };
 
+    push_optional_undefined(&mut value, exporter, field);
+
     s.push_str(prefix);
+    push_readonly(s, exporter);
     s.push_str(&key);
     s.push_str(": ");
     s.push_str(&value);
@@ -2131,7 +2657,7 @@ fn push_union(s: &mut String, variants: Vec<String>) {
 }
 
 fn enum_variant_datatype(
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     name: Cow<'static, str>,
@@ -2190,7 +2716,7 @@ fn enum_variant_datatype(
 
                 regular_fields.push(inner_comments(
                     field.deprecated.as_ref(),
-                    &field.docs,
+                    &with_value_tags(exporter, &field.docs, &field.attributes),
                     other,
                     true,
                     prefix,
@@ -2247,11 +2773,12 @@ fn enum_variant_datatype(
                 })
                 .collect::<Result<Vec<_>, Error>>()?;
 
+            let readonly = if exporter.readonly { "readonly " } else { "" };
             Ok(match &fields[..] {
-                [] if obj.fields.is_empty() => Some("[]".to_string()),
+                [] if obj.fields.is_empty() => Some(format!("{readonly}[]")),
                 [] => None,
                 [field] if is_newtype => Some(field.to_string()),
-                fields => Some(format!("[{}]", fields.join(", "))),
+                fields => Some(format!("{readonly}[{}]", fields.join(", "))),
             })
         }
     }
@@ -2668,6 +3195,7 @@ fn substitute_generics(dt: &mut DataType, subst: &GenericSubst) -> Result<(), Co
                 || opaque_ref.downcast_ref::<opaque::Never>().is_some()
                 || opaque_ref.downcast_ref::<opaque::Number>().is_some()
                 || opaque_ref.downcast_ref::<opaque::BigInt>().is_some()
+                || opaque_ref.downcast_ref::<Literal>().is_some()
             {
                 // This crate's remaining opaque payloads can't embed a
                 // datatype or mention a parameter at all.
@@ -3083,13 +3611,14 @@ fn resolve_named_export_body<'a>(
 
 fn enum_dt(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     types: &Types,
     e: &Enum,
     location: Vec<Cow<'static, str>>,
     prefix: &str,
     generics: &[(GenericReference, DataType)],
 ) -> Result<(), Error> {
+    let exporter = exporter.with_attributes(&e.attributes);
     let is_finite_number = e.attributes.get_named_as("specta:finite_number") == Some(&true);
 
     if e.attributes.get_named_as(FIELD_ALIAS_UNION_MARKER) == Some(&true) {
@@ -3153,7 +3682,7 @@ fn enum_dt(
         .map(|((_, variant), rendered)| {
             inner_comments(
                 variant.deprecated.as_ref(),
-                &variant.docs,
+                &with_value_tags(exporter, &variant.docs, &variant.attributes),
                 rendered.value,
                 true,
                 prefix,
@@ -3177,7 +3706,7 @@ fn enum_dt(
 /// union, while serde's optional/omitted behavior remains represented.
 fn alias_field_union_dt(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     types: &Types,
     e: &Enum,
     location: Vec<Cow<'static, str>>,
@@ -3236,7 +3765,7 @@ fn alias_field_union_dt(
 
 fn tuple_dt(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     types: &Types,
     t: &Tuple,
     location: Vec<Cow<'static, str>>,
@@ -3245,6 +3774,7 @@ fn tuple_dt(
     match t.elements.as_slice() {
         [] => s.push_str(NULL),
         elements => {
+            push_readonly(s, exporter);
             s.push('[');
             for (idx, dt) in elements.iter().enumerate() {
                 if idx != 0 {
@@ -3273,7 +3803,7 @@ fn tuple_dt(
 
 fn reference_dt(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     r: &Reference,
@@ -3305,7 +3835,7 @@ fn reference_dt(
 
 fn reference_opaque_dt(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     format: Option<&dyn Format>,
     types: &Types,
     r: &OpaqueReference,
@@ -3341,6 +3871,11 @@ fn reference_opaque_dt(
         return Ok(());
     }
 
+    if let Some(literal) = r.downcast_ref::<Literal>() {
+        push_literal(s, literal, &location)?;
+        return Ok(());
+    }
+
     if let Some(def) = r.downcast_ref::<Branded>() {
         if let Some(branded_type) = exporter
             .branded_type_impl
@@ -3348,7 +3883,7 @@ fn reference_opaque_dt(
             .map(|builder| {
                 (builder.0)(
                     BrandedTypeExporter {
-                        exporter,
+                        exporter: &exporter,
                         format,
                         types,
                     },
@@ -3392,7 +3927,7 @@ fn reference_opaque_dt(
 
 fn reference_named_dt(
     s: &mut String,
-    exporter: &Exporter,
+    exporter: Render<'_>,
     types: &Types,
     r: &NamedReference,
     location: Vec<Cow<'static, str>>,
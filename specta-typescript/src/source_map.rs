@@ -0,0 +1,219 @@
+//! Source maps from exported types back to the Rust types they were exported from.
+//!
+//! While rendering, the line declaring each type is marked with the type's [`Location`],
+//! delimited by private use characters which are never part of the output otherwise. Once a file
+//! is complete its markers are stripped and become the mappings of a
+//! [source map](https://tc39.es/ecma426/), so they survive any indentation or wrapping applied to
+//! the declarations in between.
+
+use std::{
+    fmt::Write as _,
+    panic::Location,
+    path::{Path, PathBuf},
+};
+
+use crate::Exporter;
+
+const MARKER_START: char = '\u{E000}';
+const MARKER_END: char = '\u{E001}';
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Marks the start of a declaration exported from the Rust type at `location`.
+pub(crate) fn marker(location: &Location<'_>) -> String {
+    format!(
+        "{MARKER_START}{}:{}:{}{MARKER_END}",
+        location.file(),
+        location.line(),
+        location.column()
+    )
+}
+
+/// The path of a source file as source maps and `@see` tags record it.
+pub(crate) fn source_path(file: &str) -> String {
+    file.replace('\\', "/")
+}
+
+struct Mapping {
+    line: usize,
+    column: usize,
+    source: String,
+    source_line: usize,
+    source_column: usize,
+}
+
+/// Removes the markers from `s`, returning where each one was.
+///
+/// Columns count UTF-16 code units, as source maps do.
+fn strip(s: &str) -> (String, Vec<Mapping>) {
+    let mut out = String::with_capacity(s.len());
+    let mut mappings = Vec::new();
+    let (mut line, mut column) = (0, 0);
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            MARKER_START => {
+                let marker = chars
+                    .by_ref()
+                    .take_while(|c| *c != MARKER_END)
+                    .collect::<String>();
+                // The file may contain `:` itself, so the position is split off the end.
+                let mut parts = marker.rsplitn(3, ':');
+                if let (Some(source_column), Some(source_line), Some(source)) =
+                    (parts.next(), parts.next(), parts.next())
+                    && let (Ok(source_line), Ok(source_column)) =
+                        (source_line.parse::<usize>(), source_column.parse::<usize>())
+                {
+                    mappings.push(Mapping {
+                        line,
+                        column,
+                        source: source_path(source),
+                        source_line: source_line.saturating_sub(1),
+                        source_column: source_column.saturating_sub(1),
+                    });
+                }
+            }
+            '\n' => {
+                out.push(c);
+                line += 1;
+                column = 0;
+            }
+            c => {
+                out.push(c);
+                column += c.len_utf16();
+            }
+        }
+    }
+    (out, mappings)
+}
+
+/// Removes the markers from output which has no file to map.
+pub(crate) fn strip_markers(exporter: &Exporter, s: String) -> String {
+    match exporter.declaration_maps {
+        Some(_) => strip(&s).0,
+        None => s,
+    }
+}
+
+/// Removes the markers from `content`, which is written to `path`.
+///
+/// When the exporter writes declaration maps and the file declares any types, this also returns
+/// the source map to write beside it, which the content links to.
+pub(crate) fn finish(
+    exporter: &Exporter,
+    path: &Path,
+    content: String,
+) -> (String, Option<(PathBuf, String)>) {
+    let Some(source_root) = &exporter.declaration_maps else {
+        return (content, None);
+    };
+    let (mut content, mappings) = strip(&content);
+    if mappings.is_empty() {
+        return (content, None);
+    }
+
+    let file = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let map_file = format!("{file}.map");
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(&format!("//# sourceMappingURL={map_file}\n"));
+
+    let map = render(
+        &file,
+        &source_path(&source_root.to_string_lossy()),
+        &mappings,
+    );
+    (content, Some((path.with_file_name(map_file), map)))
+}
+
+fn render(file: &str, source_root: &str, mappings: &[Mapping]) -> String {
+    let mut sources = Vec::<&str>::new();
+    let mut encoded = String::new();
+    let (mut line, mut column) = (0, 0);
+    let (mut source, mut source_line, mut source_column) = (0, 0, 0);
+    for mapping in mappings {
+        let index = match sources.iter().position(|s| *s == mapping.source) {
+            Some(index) => index,
+            None => {
+                sources.push(&mapping.source);
+                sources.len() - 1
+            }
+        };
+
+        if mapping.line != line {
+            for _ in line..mapping.line {
+                encoded.push(';');
+            }
+            line = mapping.line;
+            column = 0;
+        } else if !encoded.is_empty() && !encoded.ends_with(';') {
+            encoded.push(',');
+        }
+
+        for delta in [
+            mapping.column as i64 - column as i64,
+            index as i64 - source as i64,
+            mapping.source_line as i64 - source_line as i64,
+            mapping.source_column as i64 - source_column as i64,
+        ] {
+            vlq(&mut encoded, delta);
+        }
+        column = mapping.column;
+        source = index;
+        source_line = mapping.source_line;
+        source_column = mapping.source_column;
+    }
+
+    let sources = sources
+        .iter()
+        .map(|source| json_string(source))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(
+        "{{\"version\":3,\"file\":{},\"sourceRoot\":{},\"sources\":[{sources}],\"names\":[],\"mappings\":{}}}\n",
+        json_string(file),
+        json_string(source_root),
+        json_string(&encoded)
+    )
+}
+
+/// Appends `value` as a Base64 VLQ.
+fn vlq(s: &mut String, value: i64) {
+    let mut value = if value < 0 {
+        ((-value) << 1) | 1
+    } else {
+        value << 1
+    };
+    loop {
+        let mut digit = value & 0b11111;
+        value >>= 5;
+        if value > 0 {
+            digit |= 0b100000;
+        }
+        s.push(BASE64[digit as usize] as char);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut s = String::with_capacity(value.len() + 2);
+    s.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(s, "\\u{:04x}", c as u32);
+            }
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+    s
+}
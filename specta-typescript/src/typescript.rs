@@ -1,8 +1,14 @@
-use std::{borrow::Cow, path::Path};
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
 
-use specta::{Format, Types};
+use specta::{Format, Types, datatype::Function};
 
-use crate::{Branded, BrandedTypeExporter, Error, Exporter, Layout};
+use crate::{
+    Branded, BrandedTypeExporter, Error, Exporter, FunctionStyle, ImportExtension, Layout,
+    OptionalStyle, UnitEnumStyle,
+};
 
 /// JSDoc language exporter.
 #[derive(Debug, Clone)]
@@ -46,11 +52,85 @@ impl Typescript {
         Self(self.0.layout(layout))
     }
 
+    /// Configure the extension `Layout::Files` appends to import specifiers.
+    ///
+    /// See [`Exporter::import_extension`] for details.
+    pub fn import_extension(self, import_extension: ImportExtension) -> Self {
+        Self(self.0.import_extension(import_extension))
+    }
+
+    /// Configure whether `Layout::Files` imports with `import type`, which is the default.
+    ///
+    /// See [`Exporter::type_only_imports`] for details.
+    pub fn type_only_imports(self, type_only_imports: bool) -> Self {
+        Self(self.0.type_only_imports(type_only_imports))
+    }
+
+    /// Configure whether `Layout::Files` writes an `index` barrel into every module directory.
+    ///
+    /// See [`Exporter::barrels`] for details.
+    pub fn barrels(self, barrels: bool) -> Self {
+        Self(self.0.barrels(barrels))
+    }
+
+    /// Configure how enums whose every variant serializes as a string are exported.
+    ///
+    /// See [`Exporter::unit_enum_style`] for details.
+    pub fn unit_enum_style(self, unit_enum_style: UnitEnumStyle) -> Self {
+        Self(self.0.unit_enum_style(unit_enum_style))
+    }
+
+    /// Configure whether internally and adjacently tagged enums are exported with helpers.
+    ///
+    /// See [`Exporter::tagged_union_helpers`] for details.
+    pub fn tagged_union_helpers(self, tagged_union_helpers: bool) -> Self {
+        Self(self.0.tagged_union_helpers(tagged_union_helpers))
+    }
+
+    /// Configure whether exported data is immutable.
+    ///
+    /// See [`Exporter::readonly`] for details.
+    pub fn readonly(self, readonly: bool) -> Self {
+        Self(self.0.readonly(readonly))
+    }
+
+    /// Configure how optional object properties are exported.
+    ///
+    /// See [`Exporter::optional_style`] for details.
+    pub fn optional_style(self, optional_style: OptionalStyle) -> Self {
+        Self(self.0.optional_style(optional_style))
+    }
+
+    /// Configure whether each exported type's doc comment links to its Rust source.
+    ///
+    /// See [`Exporter::source_locations`] for details.
+    pub fn source_locations(self, source_locations: bool) -> Self {
+        Self(self.0.source_locations(source_locations))
+    }
+
+    /// Write a source map beside each exported file, mapping types to their Rust source.
+    ///
+    /// See [`Exporter::declaration_maps`] for details.
+    pub fn declaration_maps(self, source_root: impl Into<PathBuf>) -> Self {
+        Self(self.0.declaration_maps(source_root))
+    }
+
     /// Add raw TypeScript code that is exported as part of the bindings.
     pub fn with_raw(self, raw: impl Into<Cow<'static, str>>) -> Self {
         Self(self.0.with_raw(raw))
     }
 
+    /// Export a constant value as part of the bindings.
+    ///
+    /// Refer to [`Exporter::constant`] for more information.
+    pub fn constant(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<specta::datatype::Literal>,
+    ) -> Self {
+        Self(self.0.constant(name, value))
+    }
+
     /// Configure how `specta_typescript::branded!` types are rendered.
     ///
     /// See [`Exporter::branded_type_impl`] for details.
@@ -71,6 +151,19 @@ impl Typescript {
         self.0.export(types, format)
     }
 
+    /// Export the types into a single string, followed by the given functions.
+    ///
+    /// See [`Exporter::export_functions`] for details.
+    pub fn export_functions(
+        &self,
+        functions: &[Function],
+        style: FunctionStyle,
+        types: &Types,
+        format: impl Format,
+    ) -> Result<String, Error> {
+        self.0.export_functions(functions, style, types, format)
+    }
+
     /// Export the types to a specific file/folder.
     ///
     /// When configured when `format` is `Format::Files`, you must provide a directory path.
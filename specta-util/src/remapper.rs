@@ -1,6 +1,9 @@
 use specta::{
     Types,
-    datatype::{DataType, Fields, NamedReferenceType, Primitive, Reference},
+    datatype::{
+        DataType, Primitive,
+        visit::{self, VisitContext, VisitMut},
+    },
 };
 
 /// Recursively replaces [`DataType`]s within a [`DataType`] structure from a set of remap rules.
@@ -137,103 +140,49 @@ impl Remapper {
 
     /// Applies the remap operation to a datatype, returning the remapped datatype.
     pub fn remap_dt(&self, mut dt: DataType) -> DataType {
-        let active_rules = vec![false; self.rules.len()];
-        self.remap_internal(&mut dt, &active_rules);
+        self.visitor()
+            .visit_datatype_mut(&mut dt, &mut VisitContext::new());
         dt
     }
 
     /// Applies the remap operation to every datatype in a [`Types`] collection, returning the remapped collection.
     pub fn remap_types(&self, types: Types) -> Types {
         types.map(|mut ndt| {
-            ndt.generics.to_mut().iter_mut().for_each(|generic| {
-                if let Some(dt) = &mut generic.default {
-                    let active_rules = vec![false; self.rules.len()];
-                    self.remap_internal(dt, &active_rules);
-                }
-            });
-            if let Some(dt) = &mut ndt.ty {
-                let active_rules = vec![false; self.rules.len()];
-                self.remap_internal(dt, &active_rules);
-            }
+            self.visitor()
+                .visit_named_data_type_mut(&mut ndt, &mut VisitContext::new());
             ndt
         })
     }
 
-    fn remap_internal(&self, dt: &mut DataType, active_rules: &[bool]) {
-        let mut active_rules = active_rules.to_vec();
-        self.remap_rules(dt, &mut active_rules);
-
-        match dt {
-            DataType::Primitive(_) | DataType::Generic(_) => {}
-            DataType::List(list) => self.remap_internal(&mut list.ty, &active_rules),
-            DataType::Map(map) => {
-                self.remap_internal(map.key_ty_mut(), &active_rules);
-                self.remap_internal(map.value_ty_mut(), &active_rules);
-            }
-            DataType::Struct(s) => self.remap_fields(&mut s.fields, &active_rules),
-            DataType::Enum(e) => {
-                for (_, variant) in &mut e.variants {
-                    self.remap_fields(&mut variant.fields, &active_rules);
-                }
-            }
-            DataType::Tuple(tuple) => {
-                for dt in &mut tuple.elements {
-                    self.remap_internal(dt, &active_rules);
-                }
-            }
-            DataType::Nullable(dt) => self.remap_internal(dt, &active_rules),
-            DataType::Intersection(dts) => {
-                for dt in dts {
-                    self.remap_internal(dt, &active_rules);
-                }
-            }
-            DataType::Reference(r) => self.remap_reference(r, &active_rules),
+    fn visitor(&self) -> RemapVisitor<'_> {
+        RemapVisitor {
+            rules: &self.rules,
+            active_rules: vec![false; self.rules.len()],
         }
     }
+}
+
+/// Applies the rules of a [`Remapper`] to each visited [`DataType`].
+///
+/// `active_rules` tracks the rules which created the subtree currently being visited, so they are
+/// not reapplied within it.
+struct RemapVisitor<'a> {
+    rules: &'a [(DataType, DataType)],
+    active_rules: Vec<bool>,
+}
 
-    fn remap_rules(&self, dt: &mut DataType, active_rules: &mut [bool]) {
+impl VisitMut for RemapVisitor<'_> {
+    fn visit_datatype_mut(&mut self, dt: &mut DataType, cx: &mut VisitContext<'_>) {
+        let parent_rules = self.active_rules.clone();
         for (index, (from, to)) in self.rules.iter().enumerate() {
-            if !active_rules[index] && *dt == *from {
+            if !self.active_rules[index] && *dt == *from {
                 *dt = to.clone();
-                active_rules[index] = true;
+                self.active_rules[index] = true;
             }
         }
-    }
 
-    fn remap_fields(&self, fields: &mut Fields, active_rules: &[bool]) {
-        match fields {
-            Fields::Unit => {}
-            Fields::Unnamed(fields) => {
-                for field in &mut fields.fields {
-                    if let Some(dt) = &mut field.ty {
-                        self.remap_internal(dt, active_rules);
-                    }
-                }
-            }
-            Fields::Named(fields) => {
-                for (_, field) in &mut fields.fields {
-                    if let Some(dt) = &mut field.ty {
-                        self.remap_internal(dt, active_rules);
-                    }
-                }
-            }
-        }
-    }
-
-    fn remap_reference(&self, reference: &mut Reference, active_rules: &[bool]) {
-        let Reference::Named(reference) = reference else {
-            return;
-        };
-
-        match &mut reference.inner {
-            NamedReferenceType::Recursive(_) => {}
-            NamedReferenceType::Inline { dt, .. } => self.remap_internal(dt, active_rules),
-            NamedReferenceType::Reference { generics, .. } => {
-                for (_, dt) in generics {
-                    self.remap_internal(dt, active_rules);
-                }
-            }
-        }
+        visit::visit_datatype_mut(self, dt, cx);
+        self.active_rules = parent_rules;
     }
 }
 
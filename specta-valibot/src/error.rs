@@ -1,6 +1,6 @@
 use std::{borrow::Cow, error, fmt, io, panic::Location, path::PathBuf};
 
-use specta::datatype::OpaqueReference;
+use specta::datatype::{Literal, OpaqueReference};
 
 use crate::Layout;
 
@@ -54,6 +54,11 @@ enum ErrorKind {
         source: io::Error,
     },
     UnsupportedOpaqueReference(OpaqueReference),
+    UnsupportedLiteral {
+        path: String,
+        literal: Literal,
+        reason: &'static str,
+    },
     DanglingNamedReference {
         reference: String,
     },
@@ -167,6 +172,20 @@ impl Error {
         }
     }
 
+    pub(crate) fn unsupported_literal(
+        path: String,
+        literal: Literal,
+        reason: &'static str,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::UnsupportedLiteral {
+                path,
+                literal,
+                reason,
+            },
+        }
+    }
+
     pub(crate) fn unsupported_opaque_reference(reference: OpaqueReference) -> Self {
         Self {
             kind: ErrorKind::UnsupportedOpaqueReference(reference),
@@ -277,6 +296,14 @@ impl fmt::Display for Error {
                     path.display()
                 )
             }
+            ErrorKind::UnsupportedLiteral {
+                path,
+                literal,
+                reason,
+            } => write!(
+                f,
+                "Attempted to export {path:?} but the literal {literal:?} can't be represented by the Valibot exporter as {reason}."
+            ),
             ErrorKind::UnsupportedOpaqueReference(reference) => write!(
                 f,
                 "Found unsupported opaque reference '{}'. It is not supported by the Valibot exporter.",
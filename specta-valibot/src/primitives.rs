@@ -5,13 +5,16 @@ use std::{borrow::Cow, collections::HashSet, fmt::Write as _};
 use specta::{
     Types,
     datatype::{
-        DataType, Enum, Fields, GenericReference, List, Map, NamedDataType, NamedReference,
-        NamedReferenceType, OpaqueReference, Primitive, Reference, Struct, Tuple,
+        DataType, Enum, Fields, GenericReference, List, Literal, LiteralValue, Map, NamedDataType,
+        NamedReference, NamedReferenceType, OpaqueReference, Primitive, Reference, Struct, Tuple,
     },
 };
-use specta_typescript::{Layout as TypescriptLayout, Typescript};
+use specta_typescript::{Branded, Layout as TypescriptLayout, OptionalStyle, Typescript};
 
-use crate::{Error, Layout, Valibot, map_keys, opaque, reserved_names::RESERVED_TYPE_NAMES};
+use crate::{
+    BrandedSchemaExporter, Error, Layout, Valibot, map_keys, opaque,
+    reserved_names::RESERVED_TYPE_NAMES,
+};
 
 pub(crate) type TypeRenderStack = Vec<(Cow<'static, str>, Cow<'static, str>)>;
 
@@ -106,19 +109,44 @@ fn export_single_internal(
     type_render_stack.push((ndt.module_path.clone(), ndt.name.clone()));
 
     let result = (|| {
-        let typescript = Typescript::default().layout(match exporter.layout {
-            Layout::Namespaces => TypescriptLayout::Namespaces,
-            Layout::FlatFile => TypescriptLayout::FlatFile,
-            Layout::ModulePrefixedName => TypescriptLayout::ModulePrefixedName,
-            Layout::Files => TypescriptLayout::Files,
-        });
+        let typescript = Typescript::default()
+            .layout(match exporter.layout {
+                Layout::Namespaces => TypescriptLayout::Namespaces,
+                Layout::FlatFile => TypescriptLayout::FlatFile,
+                Layout::ModulePrefixedName => TypescriptLayout::ModulePrefixedName,
+                Layout::Files => TypescriptLayout::Files,
+            })
+            .optional_style(exporter.optional_style);
+        let typescript = match &exporter.branded_type_impl {
+            Some(branded_type_impl) => {
+                let branded_type_impl = branded_type_impl.0.clone();
+                typescript.branded_type_impl(move |ctx, branded| branded_type_impl(ctx, branded))
+            }
+            None => typescript.branded_type_impl(|ctx, branded| {
+                let ty = ctx.reference(branded.ty())?;
+                let ty = match ty.contains(" | ") {
+                    true => format!("({ty})"),
+                    false => ty,
+                };
+                Ok(Cow::Owned(format!(
+                    "{ty} & v.Brand<\"{}\">",
+                    escape_string(branded.brand())
+                )))
+            }),
+        };
         let mut alias_ndt = ndt.clone();
         alias_ndt.generics.to_mut().iter_mut().for_each(|generic| {
             if let Some(default) = &mut generic.default {
+                if let Some(semantic) = &exporter.semantic {
+                    semantic.apply_alias(types, default);
+                }
                 typescript_alias_datatype(default, false, types);
             }
         });
         if let Some(ty) = &mut alias_ndt.ty {
+            if let Some(semantic) = &exporter.semantic {
+                semantic.apply_alias(types, ty);
+            }
             typescript_alias_datatype(ty, false, types);
         }
         let render_type_alias = || {
@@ -230,9 +258,14 @@ fn export_single_internal(
             )?;
             let schema_expr = indent_continuations(&schema_expr, indent);
 
+            // Semantic schemas transform their input, so it no longer matches the exported type.
+            let schema_type = match exporter.semantic {
+                Some(_) => format!("unknown, {base_name}"),
+                None => base_name.to_string(),
+            };
             writeln!(
                 s,
-                "{indent}export const {schema_name}: v.GenericSchema<{base_name}> = {schema_expr};"
+                "{indent}export const {schema_name}: v.GenericSchema<{schema_type}> = {schema_expr};"
             )?;
             return Ok(());
         }
@@ -351,7 +384,10 @@ fn export_single_internal(
                         format!("{base_name}<{type_arguments}>")
                     }
                 };
-                let input_type = apply_type_arguments(input_type_arguments);
+                let input_type = match exporter.semantic {
+                    Some(_) => "unknown".to_string(),
+                    None => apply_type_arguments(input_type_arguments),
+                };
                 let output_type = apply_type_arguments(output_type_arguments);
                 let generics = if generics.is_empty() {
                     String::new()
@@ -524,6 +560,13 @@ fn typescript_alias_datatype(dt: &mut DataType, map_key: bool, types: &Types) {
             };
             if let Some(ty) = ty {
                 *dt = DataType::Reference(specta_typescript::define(ty));
+            } else if let Some(branded) = reference.downcast_ref::<Branded>() {
+                let mut ty = branded.ty().clone();
+                typescript_alias_datatype(&mut ty, map_key, types);
+                *dt = DataType::Reference(Reference::opaque(Branded::new(
+                    branded.brand().clone(),
+                    ty,
+                )));
             }
         }
     }
@@ -581,6 +624,9 @@ fn contains_valibot_define(
             .any(|dt| contains_valibot_define(dt, types, generics, stack)),
         DataType::Reference(Reference::Opaque(reference)) => {
             reference.downcast_ref::<opaque::Define>().is_some()
+                || reference.downcast_ref::<Branded>().is_some_and(|branded| {
+                    contains_valibot_define(branded.ty(), types, generics, stack)
+                })
         }
         DataType::Reference(Reference::Named(reference)) => match &reference.inner {
             NamedReferenceType::Inline { dt, .. } => {
@@ -958,7 +1004,7 @@ fn datatype(
     type_render_stack: &mut TypeRenderStack,
 ) -> Result<(), Error> {
     match dt {
-        DataType::Primitive(p) => s.push_str(primitive_dt(p, location)?),
+        DataType::Primitive(p) => s.push_str(primitive_dt(exporter, p, location)?),
         DataType::List(l) => list_dt(s, exporter, types, l, location, generics, type_render_stack)?,
         DataType::Map(m) => map_dt(s, exporter, types, m, location, generics, type_render_stack)?,
         DataType::Nullable(def) => {
@@ -1007,7 +1053,9 @@ fn datatype(
         DataType::Reference(r) => {
             if force_inline_ref {
                 match r {
-                    Reference::Named(named) => {
+                    Reference::Named(named)
+                        if semantic_schema(exporter, types, named).is_none() =>
+                    {
                         let ty = named_reference_ty(types, named)?;
                         let reference_generics = named_reference_generics(named)?;
                         datatype(
@@ -1327,9 +1375,21 @@ fn flattened_datatype(
     Ok(())
 }
 
-fn primitive_dt(p: &Primitive, location: Vec<Cow<'static, str>>) -> Result<&'static str, Error> {
+fn primitive_dt(
+    exporter: &Valibot,
+    p: &Primitive,
+    location: Vec<Cow<'static, str>>,
+) -> Result<&'static str, Error> {
     use Primitive::*;
 
+    if let Some(bigint) = exporter
+        .semantic
+        .as_ref()
+        .and_then(|semantic| semantic.bigint(p))
+    {
+        return Ok(bigint);
+    }
+
     Ok(match p {
         i8 => "v.pipe(v.number(), v.integer(), v.minValue(-128), v.maxValue(127))",
         i16 => "v.pipe(v.number(), v.integer(), v.minValue(-32768), v.maxValue(32767))",
@@ -1683,7 +1743,17 @@ fn substitute_generics(dt: &mut DataType, generics: &[(GenericReference, DataTyp
                 .for_each(|(_, dt)| substitute_generics(dt, generics)),
             NamedReferenceType::Recursive(_) => {}
         },
-        DataType::Primitive(_) | DataType::Reference(Reference::Opaque(_)) => {}
+        DataType::Reference(Reference::Opaque(reference)) => {
+            if let Some(branded) = reference.downcast_ref::<Branded>() {
+                let mut ty = branded.ty().clone();
+                substitute_generics(&mut ty, generics);
+                *dt = DataType::Reference(Reference::opaque(Branded::new(
+                    branded.brand().clone(),
+                    ty,
+                )));
+            }
+        }
+        DataType::Primitive(_) => {}
     }
 }
 
@@ -1830,7 +1900,7 @@ fn struct_dt(
                     type_render_stack,
                 )?;
                 if field.optional {
-                    write!(schema, "v.optional({value}),")?;
+                    write!(schema, "{},", optional(exporter, &value))?;
                 } else {
                     write!(schema, "{value},")?;
                 }
@@ -2210,7 +2280,7 @@ fn enum_variant_dt(
 
                 let key = sanitise_key(field_name);
                 if field.optional {
-                    write!(schema, "\n\t{key}: v.optional({value}),")?;
+                    write!(schema, "\n\t{key}: {},", optional(exporter, &value))?;
                 } else {
                     write!(schema, "\n\t{key}: {value},")?;
                 }
@@ -2309,7 +2379,9 @@ fn reference_dt(
         Reference::Named(r) => {
             reference_named_dt(s, exporter, types, r, location, generics, type_render_stack)
         }
-        Reference::Opaque(r) => reference_opaque_dt(s, r),
+        Reference::Opaque(r) => {
+            reference_opaque_dt(s, exporter, types, r, location, generics, type_render_stack)
+        }
     }
 }
 
@@ -2317,7 +2389,48 @@ fn generic_dt(s: &mut String, g: &GenericReference) {
     s.push_str(g.name());
 }
 
-fn reference_opaque_dt(s: &mut String, r: &OpaqueReference) -> Result<(), Error> {
+fn reference_opaque_dt(
+    s: &mut String,
+    exporter: &Valibot,
+    types: &Types,
+    r: &OpaqueReference,
+    location: Vec<Cow<'static, str>>,
+    generics: &[(GenericReference, DataType)],
+    type_render_stack: &mut TypeRenderStack,
+) -> Result<(), Error> {
+    if let Some(branded) = r.downcast_ref::<Branded>() {
+        if let Some(branded_schema_impl) = &exporter.branded_schema_impl {
+            s.push_str(&(branded_schema_impl.0)(
+                BrandedSchemaExporter { exporter, types },
+                branded,
+            )?);
+            return Ok(());
+        }
+
+        let mut inner = String::new();
+        datatype(
+            &mut inner,
+            exporter,
+            types,
+            branded.ty(),
+            location,
+            generics,
+            false,
+            type_render_stack,
+        )?;
+        write!(
+            s,
+            "v.pipe({inner}, v.brand(\"{}\"))",
+            escape_string(branded.brand())
+        )?;
+        return Ok(());
+    }
+    if let Some(literal) = r.downcast_ref::<Literal>() {
+        s.push_str("v.literal(");
+        literal_value(s, literal, &location)?;
+        s.push(')');
+        return Ok(());
+    }
     if let Some(def) = r.downcast_ref::<opaque::Define>() {
         s.push_str(&def.0);
         return Ok(());
@@ -2351,6 +2464,11 @@ fn reference_named_dt(
         .get(r)
         .ok_or_else(|| Error::dangling_named_reference(format!("{r:?}")))?;
 
+    if let Some(schema) = semantic_schema(exporter, types, r) {
+        s.push_str(schema);
+        return Ok(());
+    }
+
     if matches!(r.inner, NamedReferenceType::Inline { .. }) {
         let ty = named_reference_ty(types, r)?;
         let reference_generics = named_reference_generics(r)?;
@@ -2465,6 +2583,15 @@ fn reference_named_dt(
     Ok(())
 }
 
+fn semantic_schema<'a>(
+    exporter: &'a Valibot,
+    types: &Types,
+    r: &NamedReference,
+) -> Option<&'a str> {
+    let rule = exporter.semantic.as_ref()?.rule(types, r)?;
+    Some(&rule.schema)
+}
+
 pub(crate) fn exported_type_name(exporter: &Valibot, ndt: &NamedDataType) -> Cow<'static, str> {
     match exporter.layout {
         Layout::Namespaces | Layout::FlatFile | Layout::Files => ndt.name.clone(),
@@ -2499,6 +2626,16 @@ fn validate_type_name(name: &str, path: String) -> Result<(), Error> {
     Ok(())
 }
 
+/// The schema of an optional object property, following [`Valibot::optional_style`].
+fn optional(exporter: &Valibot, value: &str) -> String {
+    match exporter.optional_style {
+        OptionalStyle::ExactOptional => format!("v.exactOptional({value})"),
+        OptionalStyle::Optional | OptionalStyle::OptionalUndefined => {
+            format!("v.optional({value})")
+        }
+    }
+}
+
 fn sanitise_key(field_name: &str) -> String {
     if field_name == "__proto__" {
         // `__proto__: value` is prototype-setter syntax in JavaScript object
@@ -2530,6 +2667,42 @@ fn is_identifier(name: &str) -> bool {
         && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$')
 }
 
+/// Largest integer which a Javascript `number` can represent exactly.
+const MAX_SAFE_INTEGER: u128 = (1 << 53) - 1;
+
+fn literal_value(
+    s: &mut String,
+    literal: &Literal,
+    location: &[Cow<'static, str>],
+) -> Result<(), Error> {
+    let unsupported = |reason| {
+        Err(Error::unsupported_literal(
+            location.join("."),
+            literal.clone(),
+            reason,
+        ))
+    };
+
+    match literal.value() {
+        LiteralValue::Bool(v) => s.push_str(if v { "true" } else { "false" }),
+        LiteralValue::Int(v) if v.unsigned_abs() <= MAX_SAFE_INTEGER => s.push_str(&v.to_string()),
+        LiteralValue::UInt(v) if v <= MAX_SAFE_INTEGER => s.push_str(&v.to_string()),
+        LiteralValue::Int(_) | LiteralValue::UInt(_) => {
+            return unsupported("it is outside of `Number.MAX_SAFE_INTEGER`");
+        }
+        LiteralValue::Float(v) if v.is_nan() => return unsupported("`NaN` has no literal value"),
+        LiteralValue::Float(v) if v.is_infinite() => {
+            return unsupported("`Infinity` has no literal value");
+        }
+        LiteralValue::Float(v) => s.push_str(&v.to_string()),
+        LiteralValue::Char(v) => write!(s, "\"{}\"", escape_string(v.encode_utf8(&mut [0; 4])))?,
+        LiteralValue::Str(v) => write!(s, "\"{}\"", escape_string(v))?,
+        _ => return unsupported("this kind of literal is not supported"),
+    }
+
+    Ok(())
+}
+
 fn escape_string(value: &str) -> Cow<'_, str> {
     if !value.chars().any(|ch| {
         ch == '"' || ch == '\\' || ch == '\u{2028}' || ch == '\u{2029}' || ch.is_control()
@@ -0,0 +1,247 @@
+//! Runtime parsing of semantic types.
+//!
+//! [`specta_typescript::semantic`] remaps types such as [`chrono::DateTime`](https://docs.rs/chrono/latest/chrono/struct.DateTime.html)
+//! to richer JavaScript types at the type level. This module does the same for Valibot schemas,
+//! so parsing a value transforms its JSON wire representation into the richer type.
+//!
+//! Valibot has no way to reverse a transform, so values must be converted back into their wire
+//! representation before they are sent to Rust.
+//!
+//! This enables the following default rules:
+//!  - [`bytes::Bytes`](https://docs.rs/bytes/latest/bytes/struct.Bytes.html) and [`bytes::BytesMut`](https://docs.rs/bytes/latest/bytes/struct.BytesMut.html) decode to a [`Uint8Array`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array)
+//!  - [`url::Url`](https://docs.rs/url/latest/url/struct.Url.html) decodes to a [`URL`](https://developer.mozilla.org/en-US/docs/Web/API/URL/URL)
+//!  - [`chrono::DateTime`](https://docs.rs/chrono/latest/chrono/struct.DateTime.html) and [`jiff::Timestamp`](https://docs.rs/jiff/latest/jiff/struct.Timestamp.html) decode to a [`Date`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+//!  - [`chrono::NaiveDate`](https://docs.rs/chrono/latest/chrono/struct.NaiveDate.html) and [`jiff::civil::Date`](https://docs.rs/jiff/latest/jiff/civil/struct.Date.html) decode to a [`Date`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+//!
+//! Configure it with [`Valibot::semantic`](crate::Valibot::semantic).
+
+use std::borrow::Cow;
+
+use specta::{
+    Type, Types,
+    datatype::{DataType, Fields, NamedReference, NamedReferenceType, Primitive, Reference},
+};
+
+/// A rule for a specific named data type.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Rule {
+    /// Matched against [`NamedDataType::name`](specta::datatype::NamedDataType::name) to determine if rule should apply
+    pub name: Cow<'static, str>,
+    /// Matched against [`NamedDataType::module_path`](specta::datatype::NamedDataType::module_path) to determine if rule should apply
+    pub module_path: Cow<'static, str>,
+    /// The Valibot schema validating the type, which transforms it into [`Rule::ty`].
+    pub(crate) schema: Cow<'static, str>,
+    /// The TypeScript type the schema outputs.
+    pub(crate) ty: Cow<'static, str>,
+}
+
+/// Configuration for runtime parsing of semantic types.
+///
+/// By default this contains a set of default rules as defined on [the module](crate::semantic). If you don't want them use [`Configuration::empty()`](Configuration::empty) instead.
+///
+/// You can add your own rules via [`Configuration::define(...)`](Configuration::define).
+#[derive(Debug, Clone)]
+pub struct Configuration {
+    rules: Vec<Rule>,
+    lossless_bigint: bool,
+}
+
+fn rule(
+    name: &'static str,
+    module_path: &'static str,
+    schema: &'static str,
+    ty: &'static str,
+) -> Rule {
+    Rule {
+        name: name.into(),
+        module_path: module_path.into(),
+        schema: schema.into(),
+        ty: ty.into(),
+    }
+}
+
+const BYTES: &str = "v.pipe(v.array(v.pipe(v.number(), v.integer(), v.minValue(0), v.maxValue(255))), v.transform((value) => new Uint8Array(value)))";
+const DATE_TIME: &str =
+    "v.pipe(v.string(), v.isoTimestamp(), v.transform((value) => new Date(value)))";
+const DATE: &str = "v.pipe(v.string(), v.isoDate(), v.transform((value) => new Date(value)))";
+const BIGINT: &str =
+    "v.pipe(v.string(), v.regex(/^-?\\d+$/), v.transform((value) => BigInt(value)))";
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                rule("Bytes", "bytes", BYTES, "Uint8Array"),
+                rule("BytesMut", "bytes", BYTES, "Uint8Array"),
+                rule(
+                    "Url",
+                    "url",
+                    "v.pipe(v.string(), v.url(), v.transform((value) => new URL(value)))",
+                    "URL",
+                ),
+                rule("DateTime", "chrono", DATE_TIME, "Date"),
+                rule("NaiveDate", "chrono", DATE, "Date"),
+                rule("Timestamp", "jiff", DATE_TIME, "Date"),
+                rule("Date", "jiff::civil", DATE, "Date"),
+            ],
+            lossless_bigint: false,
+        }
+    }
+}
+
+impl Configuration {
+    /// Construct a [`Configuration`] without the default rules.
+    pub fn empty() -> Self {
+        Self {
+            rules: Default::default(),
+            lossless_bigint: false,
+        }
+    }
+
+    /// Exposes the rules applies to this instance for manual manipulation.
+    ///
+    /// This could be used to filter the default rules if you want to exclude certain ones.
+    pub fn rules_mut(&mut self) -> &mut Vec<Rule> {
+        &mut self.rules
+    }
+
+    /// Define a new rule for a given type `T`.
+    ///
+    /// `schema` is the Valibot expression validating `T`, and `ty` is the TypeScript type it
+    /// parses into, typically through a [`v.transform`](https://valibot.dev/api/transform/).
+    ///
+    /// This only works for named types, such as types generated by the
+    /// [`Type`] derive macro. It does not work for primitives.
+    ///
+    /// ```rust
+    /// use specta::Type;
+    /// use specta_valibot::semantic::Configuration;
+    ///
+    /// #[derive(Type)]
+    /// struct MyCustomUrl(String);
+    ///
+    /// let semantic_types = Configuration::empty().define::<MyCustomUrl>(
+    ///     "v.pipe(v.string(), v.url(), v.transform((value) => new URL(value)))",
+    ///     "URL",
+    /// );
+    /// ```
+    pub fn define<T: Type>(
+        mut self,
+        schema: impl Into<Cow<'static, str>>,
+        ty: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let mut types = Types::default();
+        let ndt = match T::definition(&mut types) {
+            DataType::Reference(Reference::Named(r)) => types.get(&r).cloned(),
+            _ => None,
+        };
+        if let Some(ndt) = ndt {
+            self.rules.push(Rule {
+                name: ndt.name,
+                module_path: ndt.module_path,
+                schema: schema.into(),
+                ty: ty.into(),
+            });
+        }
+
+        self
+    }
+
+    /// Enable lossless support for large integer types (`BigInt`s).
+    ///
+    /// This parses `usize`, `isize`, `u64`, `i64`, `u128`, and `i128` into a
+    /// [`BigInt`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt),
+    /// which would otherwise be rejected.
+    ///
+    /// The integers must be sent as decimal strings, such as with
+    /// [`serde_with::DisplayFromStr`](https://docs.rs/serde_with/latest/serde_with/struct.DisplayFromStr.html),
+    /// as a JSON number may have lost precision before the schema sees it.
+    pub fn enable_lossless_bigints(mut self) -> Self {
+        self.lossless_bigint = true;
+        self
+    }
+
+    pub(crate) fn rule(&self, types: &Types, reference: &NamedReference) -> Option<&Rule> {
+        let ndt = types.get(reference)?;
+        self.rules
+            .iter()
+            .find(|rule| rule.name == ndt.name && rule.module_path == ndt.module_path)
+    }
+
+    pub(crate) fn bigint(&self, primitive: &Primitive) -> Option<&'static str> {
+        (self.lossless_bigint && is_bigint(primitive)).then_some(BIGINT)
+    }
+
+    /// Replaces the semantic types in a datatype with the types their schemas parse into, for the
+    /// TypeScript type exported beside a schema.
+    pub(crate) fn apply_alias(&self, types: &Types, dt: &mut DataType) {
+        match dt {
+            DataType::Primitive(primitive) => {
+                if self.lossless_bigint && is_bigint(primitive) {
+                    *dt = DataType::Reference(specta_typescript::define("bigint"));
+                }
+            }
+            DataType::Generic(_) => {}
+            DataType::List(list) => self.apply_alias(types, &mut list.ty),
+            // Map keys are always strings on the wire, so they are never decoded.
+            DataType::Map(map) => self.apply_alias(types, map.value_ty_mut()),
+            DataType::Nullable(inner) => self.apply_alias(types, inner),
+            DataType::Struct(strct) => self.apply_alias_fields(types, &mut strct.fields),
+            DataType::Enum(enm) => enm
+                .variants
+                .iter_mut()
+                .for_each(|(_, variant)| self.apply_alias_fields(types, &mut variant.fields)),
+            DataType::Tuple(tuple) => tuple
+                .elements
+                .iter_mut()
+                .for_each(|dt| self.apply_alias(types, dt)),
+            DataType::Intersection(dts) => {
+                dts.iter_mut().for_each(|dt| self.apply_alias(types, dt))
+            }
+            DataType::Reference(Reference::Named(reference)) => {
+                if let Some(rule) = self.rule(types, reference) {
+                    *dt = DataType::Reference(specta_typescript::define(rule.ty.clone()));
+                    return;
+                }
+
+                match &mut reference.inner {
+                    NamedReferenceType::Inline { dt, .. } => self.apply_alias(types, dt),
+                    NamedReferenceType::Reference { generics, .. } => generics
+                        .iter_mut()
+                        .for_each(|(_, dt)| self.apply_alias(types, dt)),
+                    NamedReferenceType::Recursive(_) => {}
+                }
+            }
+            DataType::Reference(Reference::Opaque(_)) => {}
+        }
+    }
+
+    fn apply_alias_fields(&self, types: &Types, fields: &mut Fields) {
+        match fields {
+            Fields::Unit => {}
+            Fields::Unnamed(fields) => fields
+                .fields
+                .iter_mut()
+                .filter_map(|field| field.ty.as_mut())
+                .for_each(|dt| self.apply_alias(types, dt)),
+            Fields::Named(fields) => fields
+                .fields
+                .iter_mut()
+                .filter_map(|(_, field)| field.ty.as_mut())
+                .for_each(|dt| self.apply_alias(types, dt)),
+        }
+    }
+}
+
+fn is_bigint(primitive: &Primitive) -> bool {
+    matches!(
+        primitive,
+        Primitive::usize
+            | Primitive::isize
+            | Primitive::u64
+            | Primitive::i64
+            | Primitive::u128
+            | Primitive::i128
+    )
+}
@@ -13,7 +13,11 @@ use specta::{
     datatype::{DataType, Fields, NamedDataType, Reference},
 };
 
-use crate::{Error, primitives, references, reserved_names::RESERVED_TYPE_NAMES};
+use specta_typescript::{Branded, BrandedTypeExporter, OptionalStyle};
+
+use crate::{
+    Error, primitives, references, reserved_names::RESERVED_TYPE_NAMES, semantic::Configuration,
+};
 
 const GENERATED_FILE_MARKER: &str =
     "// This file has been generated by Specta's Valibot exporter. Do not edit this file manually.";
@@ -181,6 +185,41 @@ impl fmt::Debug for RuntimeFn {
     }
 }
 
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub(crate) struct BrandedSchemaFn(
+    pub(crate)  Arc<
+        dyn for<'a> Fn(BrandedSchemaExporter<'a>, &Branded) -> Result<Cow<'static, str>, Error>
+            + Send
+            + Sync,
+    >,
+);
+
+impl fmt::Debug for BrandedSchemaFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BrandedSchemaFn({:p})", self.0)
+    }
+}
+
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub(crate) struct BrandedTypeFn(
+    pub(crate)  Arc<
+        dyn for<'a> Fn(
+                BrandedTypeExporter<'a>,
+                &Branded,
+            ) -> Result<Cow<'static, str>, specta_typescript::Error>
+            + Send
+            + Sync,
+    >,
+);
+
+impl fmt::Debug for BrandedTypeFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BrandedTypeFn({:p})", self.0)
+    }
+}
+
 /// Valibot language exporter.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -192,6 +231,10 @@ pub struct Valibot {
     framework_prelude: Cow<'static, str>,
     /// Output layout mode for generated Valibot TypeScript.
     pub layout: Layout,
+    pub(crate) optional_style: OptionalStyle,
+    pub(crate) branded_schema_impl: Option<BrandedSchemaFn>,
+    pub(crate) branded_type_impl: Option<BrandedTypeFn>,
+    pub(crate) semantic: Option<Configuration>,
 }
 
 impl Default for Valibot {
@@ -209,6 +252,10 @@ impl Valibot {
             framework_runtime: None,
             framework_prelude: Cow::Borrowed("import * as v from \"valibot\";"),
             layout: Default::default(),
+            optional_style: Default::default(),
+            branded_schema_impl: None,
+            branded_type_impl: None,
+            semantic: None,
         }
     }
 
@@ -248,6 +295,64 @@ impl Valibot {
         self
     }
 
+    /// Configure how optional object properties are validated and typed.
+    ///
+    /// Use the same [`OptionalStyle`] as the TypeScript exporter of the project, so the schemas
+    /// and the types exported beside them agree.
+    pub fn optional_style(mut self, optional_style: OptionalStyle) -> Self {
+        self.optional_style = optional_style;
+        self
+    }
+
+    /// Configure how `specta_typescript::branded!` types are validated.
+    ///
+    /// By default a brand renders as `v.pipe(schema, v.brand("accountId"))` around the schema of the inner type, so parsing produces a branded value. Configure
+    /// [`Valibot::branded_type_impl`] alongside this, so the type each schema is annotated with is
+    /// the one it infers.
+    pub fn branded_schema_impl(
+        mut self,
+        builder: impl for<'a> Fn(
+            BrandedSchemaExporter<'a>,
+            &Branded,
+        ) -> Result<Cow<'static, str>, Error>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.branded_schema_impl = Some(BrandedSchemaFn(Arc::new(builder)));
+        self
+    }
+
+    /// Configure how `specta_typescript::branded!` types are rendered in the TypeScript types
+    /// exported beside the schemas.
+    ///
+    /// This works like [`Typescript::branded_type_impl`](specta_typescript::Typescript::branded_type_impl).
+    /// By default a brand renders as `string & v.Brand<"accountId">`, the type `v.brand("accountId")` infers.
+    pub fn branded_type_impl(
+        mut self,
+        builder: impl for<'a> Fn(
+            BrandedTypeExporter<'a>,
+            &Branded,
+        ) -> Result<Cow<'static, str>, specta_typescript::Error>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.branded_type_impl = Some(BrandedTypeFn(Arc::new(builder)));
+        self
+    }
+
+    /// Parse semantic types, such as dates, into richer JavaScript values.
+    ///
+    /// Types matching a rule of the [`Configuration`](crate::semantic::Configuration) are
+    /// validated by the rule's schema, which transforms the wire value when parsing. The
+    /// TypeScript types exported beside the schemas use the transformed types, so each schema is
+    /// annotated with an `unknown` input type.
+    pub fn semantic(mut self, semantic: Configuration) -> Self {
+        self.semantic = Some(semantic);
+        self
+    }
+
     /// Export files into a single string.
     pub fn export(&self, types: &Types, format: impl Format) -> Result<String, Error> {
         let exporter = self.clone();
@@ -643,7 +748,9 @@ fn map_datatype_format(
                 | specta::datatype::NamedReferenceType::Recursive(_) => false,
             },
             DataType::Generic(_) => true,
-            DataType::Reference(Reference::Opaque(_)) => false,
+            DataType::Reference(Reference::Opaque(reference)) => reference
+                .downcast_ref::<Branded>()
+                .is_some_and(|branded| contains_generic_reference(branded.ty())),
         }
     }
 
@@ -746,7 +853,16 @@ fn map_datatype_format_children(
                 }
             }
         }
-        DataType::Reference(Reference::Opaque(_)) | DataType::Generic(_) => {}
+        DataType::Reference(Reference::Opaque(reference)) => {
+            if let Some(branded) = reference.downcast_ref::<Branded>() {
+                dt = Reference::opaque(Branded::new(
+                    branded.brand().clone(),
+                    map_datatype_format(format, types, branded.ty())?,
+                ))
+                .into();
+            }
+        }
+        DataType::Generic(_) => {}
     }
 
     Ok(dt)
@@ -804,6 +920,40 @@ impl AsMut<Valibot> for Valibot {
     }
 }
 
+/// Reference to the Valibot exporter for branded schema callbacks.
+pub struct BrandedSchemaExporter<'a> {
+    pub(crate) exporter: &'a Valibot,
+    /// Collected types currently being exported.
+    pub types: &'a Types,
+}
+
+impl fmt::Debug for BrandedSchemaExporter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.exporter.fmt(f)
+    }
+}
+
+impl AsRef<Valibot> for BrandedSchemaExporter<'_> {
+    fn as_ref(&self) -> &Valibot {
+        self.exporter
+    }
+}
+
+impl Deref for BrandedSchemaExporter<'_> {
+    type Target = Valibot;
+
+    fn deref(&self) -> &Self::Target {
+        self.exporter
+    }
+}
+
+impl BrandedSchemaExporter<'_> {
+    /// [primitives::inline]
+    pub fn inline(&self, dt: &DataType) -> Result<String, Error> {
+        primitives::inline(self, self.types, dt)
+    }
+}
+
 /// Reference to the Valibot exporter for framework callbacks.
 pub struct FrameworkExporter<'a> {
     exporter: &'a Valibot,
@@ -1,8 +1,8 @@
 use std::{borrow::Cow, error, fmt, io, panic::Location, path::PathBuf};
 
-use specta::datatype::OpaqueReference;
+use specta::datatype::{Literal, OpaqueReference};
 
-use crate::Layout;
+use crate::{Layout, Target};
 
 /// The error type for the Zod exporter.
 #[non_exhaustive]
@@ -53,6 +53,11 @@ enum ErrorKind {
         source: io::Error,
     },
     UnsupportedOpaqueReference(OpaqueReference),
+    UnsupportedLiteral {
+        path: String,
+        literal: Literal,
+        reason: &'static str,
+    },
     DanglingNamedReference {
         reference: String,
     },
@@ -61,6 +66,11 @@ enum ErrorKind {
         source: FrameworkSource,
     },
     UnableToExport(Layout),
+    UnsupportedByTarget {
+        path: String,
+        target: Target,
+        construct: &'static str,
+    },
 }
 
 impl Error {
@@ -157,6 +167,20 @@ impl Error {
         }
     }
 
+    pub(crate) fn unsupported_literal(
+        path: String,
+        literal: Literal,
+        reason: &'static str,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::UnsupportedLiteral {
+                path,
+                literal,
+                reason,
+            },
+        }
+    }
+
     pub(crate) fn unsupported_opaque_reference(reference: OpaqueReference) -> Self {
         Self {
             kind: ErrorKind::UnsupportedOpaqueReference(reference),
@@ -175,6 +199,20 @@ impl Error {
         }
     }
 
+    pub(crate) fn unsupported_by_target(
+        path: String,
+        target: Target,
+        construct: &'static str,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::UnsupportedByTarget {
+                path,
+                target,
+                construct,
+            },
+        }
+    }
+
     pub(crate) fn format(
         message: impl Into<Cow<'static, str>>,
         source: specta::FormatError,
@@ -258,6 +296,14 @@ impl fmt::Display for Error {
                     path.display()
                 )
             }
+            ErrorKind::UnsupportedLiteral {
+                path,
+                literal,
+                reason,
+            } => write!(
+                f,
+                "Attempted to export {path:?} but the literal {literal:?} can't be represented by the Zod exporter as {reason}."
+            ),
             ErrorKind::UnsupportedOpaqueReference(reference) => write!(
                 f,
                 "Found unsupported opaque reference '{}'. It is not supported by the Zod exporter.",
@@ -283,6 +329,14 @@ impl fmt::Display for Error {
                     "Unable to export layout {layout} with `Zod::export`. Use `Zod::export_to` or change layout."
                 )
             }
+            ErrorKind::UnsupportedByTarget {
+                path,
+                target,
+                construct,
+            } => write!(
+                f,
+                "Attempted to export {path:?} but {target} has no way to express {construct}. Change the type or target a newer version of Zod."
+            ),
         }
     }
 }
@@ -30,11 +30,13 @@ mod opaque;
 pub mod primitives;
 mod references;
 mod reserved_names;
+pub mod semantic;
 mod types;
 mod zod;
 
 pub use error::Error;
 pub use opaque::define;
 pub use references::collect_references;
+pub use specta_typescript::OptionalStyle;
 pub use types::{Any, Never, Unknown};
-pub use zod::{FrameworkExporter, Layout, Zod};
+pub use zod::{BrandedSchemaExporter, FrameworkExporter, Layout, Target, Zod};
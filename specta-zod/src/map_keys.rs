@@ -2,9 +2,11 @@ use std::collections::HashSet;
 
 use specta::{
     Types,
-    datatype::{DataType, Fields, Generic, NamedReferenceType, Primitive, Reference},
+    datatype::{DataType, Fields, Generic, Literal, NamedReferenceType, Primitive, Reference},
 };
 
+use specta_typescript::Branded;
+
 use crate::{Error, opaque};
 
 const SERDE_CONTAINER_UNTAGGED: &str = "serde:container:untagged";
@@ -155,6 +157,18 @@ fn validate_map_key_inner(
         {
             Ok(())
         }
+        DataType::Reference(Reference::Opaque(reference))
+            if let Some(literal) = reference.downcast_ref::<Literal>() =>
+        {
+            if primitive_is_valid_key(literal.primitive()) {
+                Ok(())
+            } else {
+                Err(Error::invalid_map_key(
+                    path,
+                    "literal keys must be a string, number, boolean or char",
+                ))
+            }
+        }
         DataType::Reference(Reference::Opaque(_)) => Err(Error::invalid_map_key(
             path,
             "opaque references cannot be validated as serde_json map keys",
@@ -208,7 +222,17 @@ fn substitute_generics(dt: &mut DataType, generics: &[(Generic, DataType)]) {
         DataType::Intersection(types) => types
             .iter_mut()
             .for_each(|ty| substitute_generics(ty, generics)),
-        DataType::Primitive(_) | DataType::Reference(Reference::Opaque(_)) => {}
+        DataType::Reference(Reference::Opaque(reference)) => {
+            if let Some(branded) = reference.downcast_ref::<Branded>() {
+                let mut ty = branded.ty().clone();
+                substitute_generics(&mut ty, generics);
+                *dt = DataType::Reference(Reference::opaque(Branded::new(
+                    branded.brand().clone(),
+                    ty,
+                )));
+            }
+        }
+        DataType::Primitive(_) => {}
     }
 }
 
@@ -5,16 +5,21 @@ use std::{borrow::Cow, collections::HashSet, fmt::Write as _};
 use specta::{
     Types,
     datatype::{
-        DataType, Enum, Fields, GenericReference, List, Map, NamedDataType, NamedReference,
-        NamedReferenceType, OpaqueReference, Primitive, Reference, Struct, Tuple,
+        DataType, Enum, Fields, GenericReference, List, Literal, LiteralValue, Map, NamedDataType,
+        NamedReference, NamedReferenceType, OpaqueReference, Primitive, Reference, Struct, Tuple,
     },
 };
-use specta_typescript::{Layout as TypescriptLayout, Typescript};
+use specta_typescript::{Branded, Layout as TypescriptLayout, OptionalStyle, Typescript};
 
-use crate::{Error, Layout, Zod, map_keys, opaque, reserved_names::RESERVED_TYPE_NAMES};
+use crate::{
+    BrandedSchemaExporter, Error, Layout, Target, Zod, map_keys, opaque,
+    reserved_names::RESERVED_TYPE_NAMES,
+};
 
 pub(crate) type TypeRenderStack = Vec<(Cow<'static, str>, Cow<'static, str>)>;
 
+const CHAR_REFINEMENT: &str = "(value) => [...value].length === 1";
+
 const STRICT_OBJECT_MARKER: &str = "specta:strict_object";
 const OPTIONAL_FLATTEN_UNION_MARKER: &str = "specta_serde:optional_flatten_union";
 
@@ -103,19 +108,48 @@ fn export_single_internal(
     type_render_stack.push((ndt.module_path.clone(), ndt.name.clone()));
 
     let result = (|| {
-        let typescript = Typescript::default().layout(match exporter.layout {
-            Layout::Namespaces => TypescriptLayout::Namespaces,
-            Layout::FlatFile => TypescriptLayout::FlatFile,
-            Layout::ModulePrefixedName => TypescriptLayout::ModulePrefixedName,
-            Layout::Files => TypescriptLayout::Files,
-        });
+        let typescript = Typescript::default()
+            .layout(match exporter.layout {
+                Layout::Namespaces => TypescriptLayout::Namespaces,
+                Layout::FlatFile => TypescriptLayout::FlatFile,
+                Layout::ModulePrefixedName => TypescriptLayout::ModulePrefixedName,
+                Layout::Files => TypescriptLayout::Files,
+            })
+            .optional_style(exporter.optional_style);
+        let brand_type = match exporter.target {
+            Target::Zod3 => "z.BRAND",
+            Target::Zod4 | Target::Mini => "z.$brand",
+        };
+        let typescript = match &exporter.branded_type_impl {
+            Some(branded_type_impl) => {
+                let branded_type_impl = branded_type_impl.0.clone();
+                typescript.branded_type_impl(move |ctx, branded| branded_type_impl(ctx, branded))
+            }
+            None => typescript.branded_type_impl(move |ctx, branded| {
+                let ty = ctx.reference(branded.ty())?;
+                let ty = match ty.contains(" | ") {
+                    true => format!("({ty})"),
+                    false => ty,
+                };
+                Ok(Cow::Owned(format!(
+                    "{ty} & {brand_type}<\"{}\">",
+                    escape_string(branded.brand())
+                )))
+            }),
+        };
         let mut alias_ndt = ndt.clone();
         alias_ndt.generics.to_mut().iter_mut().for_each(|generic| {
             if let Some(default) = &mut generic.default {
+                if let Some(semantic) = &exporter.semantic {
+                    semantic.apply_alias(types, default);
+                }
                 typescript_alias_datatype(default, false, types);
             }
         });
         if let Some(ty) = &mut alias_ndt.ty {
+            if let Some(semantic) = &exporter.semantic {
+                semantic.apply_alias(types, ty);
+            }
             typescript_alias_datatype(ty, false, types);
         }
         let render_type_alias = || {
@@ -225,11 +259,13 @@ fn export_single_internal(
 
             writeln!(
                 s,
-                "{indent}export const {schema_name}: z.ZodType<{base_name}> = {schema_expr};"
+                "{indent}export const {schema_name}: {} = {schema_expr};",
+                annotation(exporter, &base_name)
             )?;
             return Ok(());
         }
 
+        let zod_type = schema_type(exporter);
         let map_key_generics = named_map_key_parameters(ndt, types);
         let mut generic_params = Vec::with_capacity(ndt.generics.len());
         let mut fn_params = Vec::with_capacity(ndt.generics.len());
@@ -239,7 +275,7 @@ fn export_single_internal(
             let name = generic.name.as_ref();
             validate_type_name(name, format!("{name_path}.<generic {name}>"))?;
 
-            let mut generic_group = vec![format!("{name} extends z.ZodType")];
+            let mut generic_group = vec![format!("{name} extends {zod_type}")];
             let mut fn_group = Vec::new();
             let mut overload_group = vec![format!("{name}: {name}")];
             if let Some(default) = &generic.default {
@@ -255,7 +291,7 @@ fn export_single_internal(
                     false,
                     type_render_stack,
                 )?;
-                fn_group.push(format!("{name}: z.ZodType = {default_schema}"));
+                fn_group.push(format!("{name}: {zod_type} = {default_schema}"));
                 if map_key_generics.contains(name) {
                     let key_name = map_key_generic_name(name);
                     map_keys::validate_map_key(
@@ -276,17 +312,17 @@ fn export_single_internal(
                         &[],
                         type_render_stack,
                     )?;
-                    generic_group.push(format!("{key_name} extends z.ZodType<PropertyKey>"));
+                    generic_group.push(format!("{key_name} extends {zod_type}<PropertyKey>"));
                     overload_group.push(format!("{key_name}: {key_name}"));
                     fn_group.push(format!(
-                        "{key_name}: z.ZodType<PropertyKey> = {default_key_schema}"
+                        "{key_name}: {zod_type}<PropertyKey> = {default_key_schema}"
                     ));
                 }
             } else {
                 fn_group.push(format!("{name}: {name}"));
                 if map_key_generics.contains(name) {
                     let key_name = map_key_generic_name(name);
-                    generic_group.push(format!("{key_name} extends z.ZodType<PropertyKey>"));
+                    generic_group.push(format!("{key_name} extends {zod_type}<PropertyKey>"));
                     overload_group.push(format!("{key_name}: {key_name}"));
                     fn_group.push(format!("{key_name}: {key_name}"));
                 }
@@ -340,12 +376,13 @@ fn export_single_internal(
                 };
                 writeln!(
                     s,
-                    "{indent}export function {schema_name}{generics}({params}): z.ZodType<{base_name}{type_arguments}>;"
+                    "{indent}export function {schema_name}{generics}({params}): {};",
+                    annotation(exporter, &format!("{base_name}{type_arguments}"))
                 )?;
             }
             writeln!(
                 s,
-                "{indent}export function {schema_name}({}): z.ZodType<any> {{\n{indent}\treturn {schema_expr};\n{indent}}}",
+                "{indent}export function {schema_name}({}): {zod_type}<any> {{\n{indent}\treturn {schema_expr};\n{indent}}}",
                 fn_params
                     .into_iter()
                     .flatten()
@@ -493,6 +530,13 @@ fn typescript_alias_datatype(dt: &mut DataType, map_key: bool, types: &Types) {
             };
             if let Some(ty) = ty {
                 *dt = DataType::Reference(specta_typescript::define(ty));
+            } else if let Some(branded) = reference.downcast_ref::<Branded>() {
+                let mut ty = branded.ty().clone();
+                typescript_alias_datatype(&mut ty, map_key, types);
+                *dt = DataType::Reference(Reference::opaque(Branded::new(
+                    branded.brand().clone(),
+                    ty,
+                )));
             }
         }
     }
@@ -549,6 +593,9 @@ fn contains_zod_define(
             .any(|dt| contains_zod_define(dt, types, generics, stack)),
         DataType::Reference(Reference::Opaque(reference)) => {
             reference.downcast_ref::<opaque::Define>().is_some()
+                || reference.downcast_ref::<Branded>().is_some_and(|branded| {
+                    contains_zod_define(branded.ty(), types, generics, stack)
+                })
         }
         DataType::Reference(Reference::Named(reference)) => match &reference.inner {
             NamedReferenceType::Inline { dt, .. } => {
@@ -912,7 +959,7 @@ fn datatype(
     type_render_stack: &mut TypeRenderStack,
 ) -> Result<(), Error> {
     match dt {
-        DataType::Primitive(p) => s.push_str(primitive_dt(p, location)?),
+        DataType::Primitive(p) => s.push_str(&primitive_dt(exporter, p, location)?),
         DataType::List(l) => list_dt(s, exporter, types, l, location, generics, type_render_stack)?,
         DataType::Map(m) => map_dt(s, exporter, types, m, location, generics, type_render_stack)?,
         DataType::Nullable(def) => {
@@ -927,7 +974,7 @@ fn datatype(
                 force_inline_ref,
                 type_render_stack,
             )?;
-            write!(s, "{inner}.nullable()")?;
+            s.push_str(&nullable(exporter, &inner));
         }
         DataType::Struct(st) => struct_dt(
             s,
@@ -960,7 +1007,9 @@ fn datatype(
         DataType::Reference(r) => {
             if force_inline_ref {
                 match r {
-                    Reference::Named(named) => {
+                    Reference::Named(named)
+                        if semantic_schema(exporter, types, named).is_none() =>
+                    {
                         let ty = named_reference_ty(types, named)?;
                         let reference_generics = named_reference_generics(named)?;
                         datatype(
@@ -1053,34 +1102,43 @@ fn datatype(
                 }
                 parts.push(part);
             }
-            s.push_str(&parts.join(".and("));
-            for _ in 1..parts.len() {
-                s.push(')');
-            }
+            s.push_str(&intersect(exporter, &parts));
         }
     }
 
     Ok(())
 }
 
-fn primitive_dt(p: &Primitive, location: Vec<Cow<'static, str>>) -> Result<&'static str, Error> {
+fn primitive_dt(
+    exporter: &Zod,
+    p: &Primitive,
+    location: Vec<Cow<'static, str>>,
+) -> Result<String, Error> {
     use Primitive::*;
 
+    if let Some(bigint) = exporter
+        .semantic
+        .as_ref()
+        .and_then(|semantic| semantic.bigint(p))
+    {
+        return Ok(bigint.render(exporter.target).to_string());
+    }
+
     Ok(match p {
-        i8 => "z.int().min(-128).max(127)",
-        i16 => "z.int().min(-32768).max(32767)",
-        i32 => "z.int().min(-2147483648).max(2147483647)",
-        u8 => "z.int().min(0).max(255)",
-        u16 => "z.int().min(0).max(65535)",
-        u32 => "z.int().min(0).max(4294967295)",
+        i8 => integer(exporter, -128, 127),
+        i16 => integer(exporter, -32768, 32767),
+        i32 => integer(exporter, -2147483648, 2147483647),
+        u8 => integer(exporter, 0, 255),
+        u16 => integer(exporter, 0, 65535),
+        u32 => integer(exporter, 0, 4294967295),
         // JSON serializers encode non-finite floats as `null`.
-        f16 | f32 | f64 => "z.number().nullable()",
+        f16 | f32 | f64 => nullable(exporter, "z.number()"),
         usize | isize | i64 | u64 | i128 | u128 | f128 => {
             return Err(Error::bigint_forbidden(location.join(".")));
         }
-        Primitive::bool => "z.boolean()",
-        str => "z.string()",
-        char => "z.string().refine((value) => [...value].length === 1)",
+        Primitive::bool => "z.boolean()".into(),
+        str => "z.string()".into(),
+        char => checked_string(exporter, None, Some(CHAR_REFINEMENT)),
     })
 }
 
@@ -1160,11 +1218,13 @@ fn map_dt(
         type_render_stack,
     )?;
 
-    let constructor = if map_key_is_finite(m.key_ty(), types, generics) {
-        "z.partialRecord"
-    } else {
-        "z.record"
-    };
+    // Zod 3 records are already partial when their keys are finite.
+    let constructor =
+        if map_key_is_finite(m.key_ty(), types, generics) && exporter.target != Target::Zod3 {
+            "z.partialRecord"
+        } else {
+            "z.record"
+        };
     write!(s, "{constructor}({key}, {value})")?;
     Ok(())
 }
@@ -1232,34 +1292,48 @@ fn map_key_datatype(
     match dt {
         DataType::Primitive(Primitive::str) => s.push_str("z.string()"),
         DataType::Primitive(Primitive::char) => {
-            s.push_str("z.string().refine((value) => [...value].length === 1)")
+            s.push_str(&checked_string(exporter, None, Some(CHAR_REFINEMENT)))
         }
         DataType::Primitive(Primitive::bool) => s.push_str(r#"z.enum(["true", "false"])"#),
-        DataType::Primitive(Primitive::i8) => s.push_str(
-            r"z.string().regex(/^-?\d+$/).refine((value) => Number(value) >= -128 && Number(value) <= 127)",
-        ),
-        DataType::Primitive(Primitive::i16) => s.push_str(
-            r"z.string().regex(/^-?\d+$/).refine((value) => Number(value) >= -32768 && Number(value) <= 32767)",
-        ),
-        DataType::Primitive(Primitive::i32) => s.push_str(
-            r"z.string().regex(/^-?\d+$/).refine((value) => Number(value) >= -2147483648 && Number(value) <= 2147483647)",
-        ),
+        DataType::Primitive(Primitive::i8) => s.push_str(&checked_string(
+            exporter,
+            Some(r"/^-?\d+$/"),
+            Some("(value) => Number(value) >= -128 && Number(value) <= 127"),
+        )),
+        DataType::Primitive(Primitive::i16) => s.push_str(&checked_string(
+            exporter,
+            Some(r"/^-?\d+$/"),
+            Some("(value) => Number(value) >= -32768 && Number(value) <= 32767"),
+        )),
+        DataType::Primitive(Primitive::i32) => s.push_str(&checked_string(
+            exporter,
+            Some(r"/^-?\d+$/"),
+            Some("(value) => Number(value) >= -2147483648 && Number(value) <= 2147483647"),
+        )),
         DataType::Primitive(Primitive::isize | Primitive::usize) => {
             return Err(Error::bigint_forbidden(location.join(".")));
         }
-        DataType::Primitive(Primitive::u8) => s.push_str(
-            r"z.string().regex(/^\d+$/).refine((value) => Number(value) <= 255)",
-        ),
-        DataType::Primitive(Primitive::u16) => s.push_str(
-            r"z.string().regex(/^\d+$/).refine((value) => Number(value) <= 65535)",
-        ),
-        DataType::Primitive(Primitive::u32) => s.push_str(
-            r"z.string().regex(/^\d+$/).refine((value) => Number(value) <= 4294967295)",
-        ),
+        DataType::Primitive(Primitive::u8) => s.push_str(&checked_string(
+            exporter,
+            Some(r"/^\d+$/"),
+            Some("(value) => Number(value) <= 255"),
+        )),
+        DataType::Primitive(Primitive::u16) => s.push_str(&checked_string(
+            exporter,
+            Some(r"/^\d+$/"),
+            Some("(value) => Number(value) <= 65535"),
+        )),
+        DataType::Primitive(Primitive::u32) => s.push_str(&checked_string(
+            exporter,
+            Some(r"/^\d+$/"),
+            Some("(value) => Number(value) <= 4294967295"),
+        )),
         DataType::Primitive(Primitive::f16 | Primitive::f32 | Primitive::f64) => {
-            s.push_str(
-                r"z.string().regex(/^-?(?:0|[1-9]\d*)(?:\.\d+)?(?:[eE][+-]?\d+)?$/).refine((value) => Number.isFinite(Number(value)))",
-            )
+            s.push_str(&checked_string(
+                exporter,
+                Some(r"/^-?(?:0|[1-9]\d*)(?:\.\d+)?(?:[eE][+-]?\d+)?$/"),
+                Some("(value) => Number.isFinite(Number(value))"),
+            ))
         }
         DataType::Struct(strct) => {
             let Fields::Unnamed(fields) = &strct.fields else {
@@ -1462,7 +1536,17 @@ fn substitute_generics(dt: &mut DataType, generics: &[(GenericReference, DataTyp
                 .for_each(|(_, dt)| substitute_generics(dt, generics)),
             NamedReferenceType::Recursive(_) => {}
         },
-        DataType::Primitive(_) | DataType::Reference(Reference::Opaque(_)) => {}
+        DataType::Reference(Reference::Opaque(reference)) => {
+            if let Some(branded) = reference.downcast_ref::<Branded>() {
+                let mut ty = branded.ty().clone();
+                substitute_generics(&mut ty, generics);
+                *dt = DataType::Reference(Reference::opaque(Branded::new(
+                    branded.brand().clone(),
+                    ty,
+                )));
+            }
+        }
+        DataType::Primitive(_) => {}
     }
 }
 
@@ -1565,19 +1649,22 @@ fn struct_dt(
                         if i != 0 {
                             s.push_str(", ");
                         }
+                        let location = child_location(&location, i.to_string());
+                        let mut item = String::new();
                         datatype_with_inline_attr(
-                            s,
+                            &mut item,
                             exporter,
                             types,
                             ty,
-                            child_location(&location, i.to_string()),
+                            location.clone(),
                             generics,
                             false,
                             type_render_stack,
                         )?;
                         if i >= optional_from {
-                            s.push_str(".optional()");
+                            item = optional_element(exporter, &item, &location)?;
                         }
+                        s.push_str(&item);
                     }
                     s.push_str("])");
                 }
@@ -1599,24 +1686,26 @@ fn struct_dt(
 
             let mut schema = String::from("z.object({");
             for (name, field, ty) in &non_flattened {
-                let key = sanitise_key(name.as_ref());
-                write!(schema, "\n\t{key}: ")?;
-                let mut value = String::new();
-                datatype_with_inline_attr(
-                    &mut value,
+                let location = child_location(&location, name.to_string());
+                object_property(
+                    &mut schema,
                     exporter,
-                    types,
-                    ty,
-                    child_location(&location, name.to_string()),
-                    generics,
-                    false,
-                    type_render_stack,
+                    name,
+                    field.optional,
+                    &location,
+                    |value| {
+                        datatype_with_inline_attr(
+                            value,
+                            exporter,
+                            types,
+                            ty,
+                            location.clone(),
+                            generics,
+                            false,
+                            type_render_stack,
+                        )
+                    },
                 )?;
-                if field.optional {
-                    write!(schema, "{value}.optional(),")?;
-                } else {
-                    write!(schema, "{value},")?;
-                }
             }
             if !non_flattened.is_empty() {
                 schema.push('\n');
@@ -1782,11 +1871,17 @@ fn enum_dt(
                     .collect::<Vec<_>>();
                 missing.sort_unstable();
                 if !missing.is_empty() {
-                    rendered.push_str(".and(z.object({");
+                    let mut exclusion = String::from("z.object({");
                     for key in missing {
-                        write!(rendered, "\n\t{}: z.never().optional(),", sanitise_key(key))?;
+                        write!(
+                            exclusion,
+                            "\n\t{}: {},",
+                            sanitise_key(key),
+                            plain_optional(exporter, "z.never()")
+                        )?;
                     }
-                    rendered.push_str("\n}))");
+                    exclusion.push_str("\n})");
+                    rendered = intersect(exporter, &[rendered, exclusion]);
                 }
             }
 
@@ -1845,24 +1940,26 @@ fn enum_variant_dt(
                 };
 
                 has_field = true;
-                let mut value = String::new();
-                datatype_with_inline_attr(
-                    &mut value,
+                let location = child_location(&location, field_name.to_string());
+                object_property(
+                    &mut schema,
                     exporter,
-                    types,
-                    ty,
-                    child_location(&location, field_name.to_string()),
-                    generics,
-                    false,
-                    type_render_stack,
+                    field_name,
+                    field.optional,
+                    &location,
+                    |value| {
+                        datatype_with_inline_attr(
+                            value,
+                            exporter,
+                            types,
+                            ty,
+                            location.clone(),
+                            generics,
+                            false,
+                            type_render_stack,
+                        )
+                    },
                 )?;
-
-                let key = sanitise_key(field_name);
-                if field.optional {
-                    write!(schema, "\n\t{key}: {value}.optional(),")?;
-                } else {
-                    write!(schema, "\n\t{key}: {value},")?;
-                }
             }
 
             if strict_object {
@@ -1876,7 +1973,11 @@ fn enum_variant_dt(
                     }
                     has_field = true;
                     let key = sanitise_key(field_name);
-                    write!(schema, "\n\t{key}: z.unknown().optional(),")?;
+                    write!(
+                        schema,
+                        "\n\t{key}: {},",
+                        plain_optional(exporter, "z.unknown()")
+                    )?;
                 }
             }
 
@@ -1929,21 +2030,22 @@ fn enum_variant_dt(
                         if i != 0 {
                             out.push_str(", ");
                         }
+                        let location = child_location(&location, i.to_string());
                         let mut item = String::new();
                         datatype_with_inline_attr(
                             &mut item,
                             exporter,
                             types,
                             ty,
-                            child_location(&location, i.to_string()),
+                            location.clone(),
                             generics,
                             false,
                             type_render_stack,
                         )?;
-                        out.push_str(&item);
                         if i >= optional_from {
-                            out.push_str(".optional()");
+                            item = optional_element(exporter, &item, &location)?;
                         }
+                        out.push_str(&item);
                     }
                     out.push_str("])");
                     Some(out)
@@ -1966,7 +2068,9 @@ fn reference_dt(
         Reference::Named(r) => {
             reference_named_dt(s, exporter, types, r, location, generics, type_render_stack)
         }
-        Reference::Opaque(r) => reference_opaque_dt(s, r),
+        Reference::Opaque(r) => {
+            reference_opaque_dt(s, exporter, types, r, location, generics, type_render_stack)
+        }
     }
 }
 
@@ -1974,7 +2078,44 @@ fn generic_dt(s: &mut String, g: &GenericReference) {
     s.push_str(g.name());
 }
 
-fn reference_opaque_dt(s: &mut String, r: &OpaqueReference) -> Result<(), Error> {
+fn reference_opaque_dt(
+    s: &mut String,
+    exporter: &Zod,
+    types: &Types,
+    r: &OpaqueReference,
+    location: Vec<Cow<'static, str>>,
+    generics: &[(GenericReference, DataType)],
+    type_render_stack: &mut TypeRenderStack,
+) -> Result<(), Error> {
+    if let Some(branded) = r.downcast_ref::<Branded>() {
+        if let Some(branded_schema_impl) = &exporter.branded_schema_impl {
+            s.push_str(&(branded_schema_impl.0)(
+                BrandedSchemaExporter { exporter, types },
+                branded,
+            )?);
+            return Ok(());
+        }
+
+        let mut inner = String::new();
+        datatype(
+            &mut inner,
+            exporter,
+            types,
+            branded.ty(),
+            location,
+            generics,
+            false,
+            type_render_stack,
+        )?;
+        write!(s, "{inner}.brand<\"{}\">()", escape_string(branded.brand()))?;
+        return Ok(());
+    }
+    if let Some(literal) = r.downcast_ref::<Literal>() {
+        s.push_str("z.literal(");
+        literal_value(s, literal, &location)?;
+        s.push(')');
+        return Ok(());
+    }
     if let Some(def) = r.downcast_ref::<opaque::Define>() {
         s.push_str(&def.0);
         return Ok(());
@@ -2008,6 +2149,11 @@ fn reference_named_dt(
         .get(r)
         .ok_or_else(|| Error::dangling_named_reference(format!("{r:?}")))?;
 
+    if let Some(schema) = semantic_schema(exporter, types, r) {
+        s.push_str(schema);
+        return Ok(());
+    }
+
     if matches!(r.inner, NamedReferenceType::Inline { .. }) {
         let ty = named_reference_ty(types, r)?;
         let reference_generics = named_reference_generics(r)?;
@@ -2113,11 +2259,20 @@ fn reference_named_dt(
 
     // Declarations are sorted, so this reference may target a schema whose
     // initializer has not run yet. Laziness also handles recursive references.
-    write!(s, "z.lazy(() => {reference_expr})")?;
+    if crate::references::reference_directly() {
+        s.push_str(&reference_expr);
+    } else {
+        write!(s, "z.lazy(() => {reference_expr})")?;
+    }
 
     Ok(())
 }
 
+fn semantic_schema<'a>(exporter: &'a Zod, types: &Types, r: &NamedReference) -> Option<&'a str> {
+    let rule = exporter.semantic.as_ref()?.rule(types, r)?;
+    Some(rule.schema.render(exporter.target))
+}
+
 pub(crate) fn exported_type_name(exporter: &Zod, ndt: &NamedDataType) -> Cow<'static, str> {
     match exporter.layout {
         Layout::Namespaces | Layout::FlatFile | Layout::Files => ndt.name.clone(),
@@ -2152,6 +2307,150 @@ fn validate_type_name(name: &str, path: String) -> Result<(), Error> {
     Ok(())
 }
 
+/// Appends an object property, whose schema `render` writes.
+///
+/// With Zod Mini, a property referencing other schemas becomes a getter which references them
+/// directly, as they are initialized by the time it is called.
+fn object_property(
+    schema: &mut String,
+    exporter: &Zod,
+    name: &str,
+    optional_field: bool,
+    location: &[Cow<'static, str>],
+    render: impl FnOnce(&mut String) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let mut value = String::new();
+    let (result, getter) = match exporter.target {
+        Target::Mini => crate::references::defer(|| render(&mut value)),
+        Target::Zod3 | Target::Zod4 => (render(&mut value), false),
+    };
+    result?;
+    if optional_field {
+        value = optional(exporter, &value, location)?;
+    }
+
+    let key = sanitise_key(name);
+    match getter {
+        true => write!(schema, "\n\tget {key}() {{ return {value}; }},")?,
+        false => write!(schema, "\n\t{key}: {value},")?,
+    }
+    Ok(())
+}
+
+/// An optional object property, in the configured [`OptionalStyle`].
+fn optional(exporter: &Zod, value: &str, location: &[Cow<'static, str>]) -> Result<String, Error> {
+    match (exporter.optional_style, exporter.target) {
+        (OptionalStyle::ExactOptional, Target::Zod3) => Err(Error::unsupported_by_target(
+            location.join("."),
+            exporter.target,
+            "exact optional properties",
+        )),
+        (OptionalStyle::ExactOptional, Target::Zod4) => Ok(format!("{value}.exactOptional()")),
+        (OptionalStyle::ExactOptional, Target::Mini) => Ok(format!("z.exactOptional({value})")),
+        (OptionalStyle::Optional | OptionalStyle::OptionalUndefined, _) => {
+            Ok(plain_optional(exporter, value))
+        }
+    }
+}
+
+fn plain_optional(exporter: &Zod, value: &str) -> String {
+    match exporter.target {
+        Target::Zod3 | Target::Zod4 => format!("{value}.optional()"),
+        Target::Mini => format!("z.optional({value})"),
+    }
+}
+
+/// A trailing tuple element which may be missing.
+fn optional_element(
+    exporter: &Zod,
+    value: &str,
+    location: &[Cow<'static, str>],
+) -> Result<String, Error> {
+    match exporter.target {
+        Target::Zod3 => Err(Error::unsupported_by_target(
+            location.join("."),
+            exporter.target,
+            "optional tuple elements",
+        )),
+        Target::Zod4 | Target::Mini => Ok(plain_optional(exporter, value)),
+    }
+}
+
+fn nullable(exporter: &Zod, value: &str) -> String {
+    match exporter.target {
+        Target::Zod3 | Target::Zod4 => format!("{value}.nullable()"),
+        Target::Mini => format!("z.nullable({value})"),
+    }
+}
+
+/// The intersection of all `parts`, nested to the right.
+fn intersect(exporter: &Zod, parts: &[String]) -> String {
+    match parts {
+        [] => "z.unknown()".into(),
+        [part] => part.clone(),
+        [part, rest @ ..] => {
+            let rest = intersect(exporter, rest);
+            match exporter.target {
+                Target::Zod3 | Target::Zod4 => format!("{part}.and({rest})"),
+                Target::Mini => format!("z.intersection({part}, {rest})"),
+            }
+        }
+    }
+}
+
+/// An integer schema between `min` and `max`.
+fn integer(exporter: &Zod, min: i64, max: i64) -> String {
+    match exporter.target {
+        Target::Zod3 => format!("z.number().int().min({min}).max({max})"),
+        Target::Zod4 => format!("z.int().min({min}).max({max})"),
+        Target::Mini => format!("z.int().check(z.gte({min}), z.lte({max}))"),
+    }
+}
+
+/// A string schema, optionally matching `regex` and passing `refine`.
+fn checked_string(exporter: &Zod, regex: Option<&str>, refine: Option<&str>) -> String {
+    match exporter.target {
+        Target::Zod3 | Target::Zod4 => {
+            let mut s = String::from("z.string()");
+            if let Some(regex) = regex {
+                s.push_str(&format!(".regex({regex})"));
+            }
+            if let Some(refine) = refine {
+                s.push_str(&format!(".refine({refine})"));
+            }
+            s
+        }
+        Target::Mini => {
+            let checks = regex
+                .map(|regex| format!("z.regex({regex})"))
+                .into_iter()
+                .chain(refine.map(|refine| format!("z.refine({refine})")))
+                .collect::<Vec<_>>();
+            match checks.is_empty() {
+                true => "z.string()".into(),
+                false => format!("z.string().check({})", checks.join(", ")),
+            }
+        }
+    }
+}
+
+/// The type annotating a schema which outputs `output`.
+fn annotation(exporter: &Zod, output: &str) -> String {
+    match (exporter.target, &exporter.semantic) {
+        // Zod 3 defaults the input type to the output type, which semantic schemas transform.
+        (Target::Zod3, Some(_)) => format!("z.ZodType<{output}, z.ZodTypeDef, unknown>"),
+        _ => format!("{}<{output}>", schema_type(exporter)),
+    }
+}
+
+/// The schema type of the target, such as `z.ZodType`.
+fn schema_type(exporter: &Zod) -> &'static str {
+    match exporter.target {
+        Target::Zod3 | Target::Zod4 => "z.ZodType",
+        Target::Mini => "z.ZodMiniType",
+    }
+}
+
 fn sanitise_key(field_name: &str) -> String {
     if field_name == "__proto__" {
         // `__proto__: value` is prototype-setter syntax in JavaScript object
@@ -2183,6 +2482,42 @@ fn is_identifier(name: &str) -> bool {
         && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$')
 }
 
+/// Largest integer which a Javascript `number` can represent exactly.
+const MAX_SAFE_INTEGER: u128 = (1 << 53) - 1;
+
+fn literal_value(
+    s: &mut String,
+    literal: &Literal,
+    location: &[Cow<'static, str>],
+) -> Result<(), Error> {
+    let unsupported = |reason| {
+        Err(Error::unsupported_literal(
+            location.join("."),
+            literal.clone(),
+            reason,
+        ))
+    };
+
+    match literal.value() {
+        LiteralValue::Bool(v) => s.push_str(if v { "true" } else { "false" }),
+        LiteralValue::Int(v) if v.unsigned_abs() <= MAX_SAFE_INTEGER => s.push_str(&v.to_string()),
+        LiteralValue::UInt(v) if v <= MAX_SAFE_INTEGER => s.push_str(&v.to_string()),
+        LiteralValue::Int(_) | LiteralValue::UInt(_) => {
+            return unsupported("it is outside of `Number.MAX_SAFE_INTEGER`");
+        }
+        LiteralValue::Float(v) if v.is_nan() => return unsupported("`NaN` has no literal value"),
+        LiteralValue::Float(v) if v.is_infinite() => {
+            return unsupported("`Infinity` has no literal value");
+        }
+        LiteralValue::Float(v) => s.push_str(&v.to_string()),
+        LiteralValue::Char(v) => write!(s, "\"{}\"", escape_string(v.encode_utf8(&mut [0; 4])))?,
+        LiteralValue::Str(v) => write!(s, "\"{}\"", escape_string(v))?,
+        _ => return unsupported("this kind of literal is not supported"),
+    }
+
+    Ok(())
+}
+
 fn escape_string(value: &str) -> Cow<'_, str> {
     if !value.chars().any(|ch| {
         ch == '"' || ch == '\\' || ch == '\u{2028}' || ch == '\u{2029}' || ch.is_control()
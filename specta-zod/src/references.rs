@@ -1,10 +1,51 @@
-use std::{cell::RefCell, collections::HashSet};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+};
 
 use specta::datatype::NamedReference;
 
 thread_local! {
     static REFERENCED_TYPES: RefCell<Option<Vec<HashSet<NamedReference>>>> = const { RefCell::new(None) };
     static MODULE_PATH_CONTEXT: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    /// Set while rendering code which runs after every schema is initialized, recording whether
+    /// a schema was referenced directly within it.
+    static DEFERRED: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Render code which is only evaluated after every schema is initialized, such as the body of a
+/// getter, so schemas may be referenced directly rather than through `z.lazy`.
+///
+/// Returns whether a schema was referenced directly. Nested calls report to the outermost one.
+pub(crate) fn defer<R>(func: impl FnOnce() -> R) -> (R, bool) {
+    if DEFERRED.get().is_some() {
+        return (func(), false);
+    }
+
+    struct Guard;
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            DEFERRED.set(None);
+        }
+    }
+
+    DEFERRED.set(Some(false));
+    let guard = Guard;
+    let result = func();
+    let deferred = DEFERRED.get().unwrap_or_default();
+    drop(guard);
+    (result, deferred)
+}
+
+/// Whether a schema may be referenced directly, marking it as referenced if so.
+pub(crate) fn reference_directly() -> bool {
+    match DEFERRED.get() {
+        Some(_) => {
+            DEFERRED.set(Some(true));
+            true
+        }
+        None => false,
+    }
 }
 
 pub(crate) fn with_module_path<R>(module_path: &str, func: impl FnOnce() -> R) -> R {
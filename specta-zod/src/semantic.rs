@@ -0,0 +1,283 @@
+//! Runtime parsing of semantic types.
+//!
+//! [`specta_typescript::semantic`] remaps types such as [`chrono::DateTime`](https://docs.rs/chrono/latest/chrono/struct.DateTime.html)
+//! to richer JavaScript types at the type level. This module does the same for Zod schemas, so
+//! parsing a value decodes its JSON wire representation into the richer type and encoding it
+//! with [`z.encode`](https://zod.dev/codecs) produces the wire representation again. Zod 3 has
+//! no codecs, so with [`Target::Zod3`](crate::Target::Zod3) values are only decoded.
+//!
+//! This enables the following default rules:
+//!  - [`bytes::Bytes`](https://docs.rs/bytes/latest/bytes/struct.Bytes.html) and [`bytes::BytesMut`](https://docs.rs/bytes/latest/bytes/struct.BytesMut.html) decode to a [`Uint8Array`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array)
+//!  - [`url::Url`](https://docs.rs/url/latest/url/struct.Url.html) decodes to a [`URL`](https://developer.mozilla.org/en-US/docs/Web/API/URL/URL)
+//!  - [`chrono::DateTime`](https://docs.rs/chrono/latest/chrono/struct.DateTime.html) and [`jiff::Timestamp`](https://docs.rs/jiff/latest/jiff/struct.Timestamp.html) decode to a [`Date`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+//!  - [`chrono::NaiveDate`](https://docs.rs/chrono/latest/chrono/struct.NaiveDate.html) and [`jiff::civil::Date`](https://docs.rs/jiff/latest/jiff/civil/struct.Date.html) decode to a [`Date`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+//!
+//! Configure it with [`Zod::semantic`](crate::Zod::semantic).
+
+use std::borrow::Cow;
+
+use specta::{
+    Type, Types,
+    datatype::{DataType, Fields, NamedReference, NamedReferenceType, Primitive, Reference},
+};
+
+use crate::Target;
+
+/// A rule for a specific named data type.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Rule {
+    /// Matched against [`NamedDataType::name`](specta::datatype::NamedDataType::name) to determine if rule should apply
+    pub name: Cow<'static, str>,
+    /// Matched against [`NamedDataType::module_path`](specta::datatype::NamedDataType::module_path) to determine if rule should apply
+    pub module_path: Cow<'static, str>,
+    /// The Zod schema validating the type, which decodes into [`Rule::ty`].
+    pub(crate) schema: Schema,
+    /// The TypeScript type the schema outputs.
+    pub(crate) ty: Cow<'static, str>,
+}
+
+/// Configuration for runtime parsing of semantic types.
+///
+/// By default this contains a set of default rules as defined on [the module](crate::semantic). If you don't want them use [`Configuration::empty()`](Configuration::empty) instead.
+///
+/// You can add your own rules via [`Configuration::define(...)`](Configuration::define).
+#[derive(Debug, Clone)]
+pub struct Configuration {
+    rules: Vec<Rule>,
+    lossless_bigint: bool,
+}
+
+/// The schema of a [`Rule`], which for the default rules depends on the [`Target`].
+#[derive(Debug, Clone)]
+pub(crate) enum Schema {
+    Custom(Cow<'static, str>),
+    Bytes,
+    Url,
+    DateTime,
+    Date,
+    BigInt,
+}
+
+impl Schema {
+    pub(crate) fn render(&self, target: Target) -> &str {
+        match (self, target) {
+            (Self::Custom(schema), _) => schema,
+            (Self::Bytes, Target::Zod3) => {
+                "z.array(z.number().int().min(0).max(255)).transform((value) => new Uint8Array(value))"
+            }
+            (Self::Bytes, Target::Zod4) => {
+                "z.codec(z.array(z.int().min(0).max(255)), z.instanceof(Uint8Array), { decode: (value) => new Uint8Array(value), encode: (value) => [...value] })"
+            }
+            (Self::Bytes, Target::Mini) => {
+                "z.codec(z.array(z.int().check(z.gte(0), z.lte(255))), z.instanceof(Uint8Array), { decode: (value) => new Uint8Array(value), encode: (value) => [...value] })"
+            }
+            (Self::Url, Target::Zod3) => "z.string().url().transform((value) => new URL(value))",
+            (Self::Url, Target::Zod4 | Target::Mini) => {
+                "z.codec(z.url(), z.instanceof(URL), { decode: (value) => new URL(value), encode: (value) => value.href })"
+            }
+            (Self::DateTime, Target::Zod3) => {
+                "z.string().datetime({ offset: true }).transform((value) => new Date(value))"
+            }
+            (Self::DateTime, Target::Zod4 | Target::Mini) => {
+                "z.codec(z.iso.datetime({ offset: true }), z.date(), { decode: (value) => new Date(value), encode: (value) => value.toISOString() })"
+            }
+            (Self::Date, Target::Zod3) => "z.string().date().transform((value) => new Date(value))",
+            (Self::Date, Target::Zod4 | Target::Mini) => {
+                "z.codec(z.iso.date(), z.date(), { decode: (value) => new Date(value), encode: (value) => value.toISOString().slice(0, 10) })"
+            }
+            (Self::BigInt, Target::Zod3) => {
+                "z.string().regex(/^-?\\d+$/).transform((value) => BigInt(value))"
+            }
+            (Self::BigInt, Target::Zod4) => {
+                "z.codec(z.string().regex(/^-?\\d+$/), z.bigint(), { decode: (value) => BigInt(value), encode: (value) => value.toString() })"
+            }
+            (Self::BigInt, Target::Mini) => {
+                "z.codec(z.string().check(z.regex(/^-?\\d+$/)), z.bigint(), { decode: (value) => BigInt(value), encode: (value) => value.toString() })"
+            }
+        }
+    }
+}
+
+fn rule(name: &'static str, module_path: &'static str, schema: Schema, ty: &'static str) -> Rule {
+    Rule {
+        name: name.into(),
+        module_path: module_path.into(),
+        schema,
+        ty: ty.into(),
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                rule("Bytes", "bytes", Schema::Bytes, "Uint8Array"),
+                rule("BytesMut", "bytes", Schema::Bytes, "Uint8Array"),
+                rule("Url", "url", Schema::Url, "URL"),
+                rule("DateTime", "chrono", Schema::DateTime, "Date"),
+                rule("NaiveDate", "chrono", Schema::Date, "Date"),
+                rule("Timestamp", "jiff", Schema::DateTime, "Date"),
+                rule("Date", "jiff::civil", Schema::Date, "Date"),
+            ],
+            lossless_bigint: false,
+        }
+    }
+}
+
+impl Configuration {
+    /// Construct a [`Configuration`] without the default rules.
+    pub fn empty() -> Self {
+        Self {
+            rules: Default::default(),
+            lossless_bigint: false,
+        }
+    }
+
+    /// Exposes the rules applies to this instance for manual manipulation.
+    ///
+    /// This could be used to filter the default rules if you want to exclude certain ones.
+    pub fn rules_mut(&mut self) -> &mut Vec<Rule> {
+        &mut self.rules
+    }
+
+    /// Define a new rule for a given type `T`.
+    ///
+    /// `schema` is the Zod expression validating `T`, and `ty` is the TypeScript type it parses
+    /// into. Use a [`z.codec`](https://zod.dev/codecs) so values can be encoded back into their
+    /// wire representation.
+    ///
+    /// This only works for named types, such as types generated by the
+    /// [`Type`] derive macro. It does not work for primitives.
+    ///
+    /// ```rust
+    /// use specta::Type;
+    /// use specta_zod::semantic::Configuration;
+    ///
+    /// #[derive(Type)]
+    /// struct MyCustomUrl(String);
+    ///
+    /// let semantic_types = Configuration::empty().define::<MyCustomUrl>(
+    ///     "z.codec(z.url(), z.instanceof(URL), { decode: (value) => new URL(value), encode: (value) => value.href })",
+    ///     "URL",
+    /// );
+    /// ```
+    pub fn define<T: Type>(
+        mut self,
+        schema: impl Into<Cow<'static, str>>,
+        ty: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let mut types = Types::default();
+        let ndt = match T::definition(&mut types) {
+            DataType::Reference(Reference::Named(r)) => types.get(&r).cloned(),
+            _ => None,
+        };
+        if let Some(ndt) = ndt {
+            self.rules.push(Rule {
+                name: ndt.name,
+                module_path: ndt.module_path,
+                schema: Schema::Custom(schema.into()),
+                ty: ty.into(),
+            });
+        }
+
+        self
+    }
+
+    /// Enable lossless support for large integer types (`BigInt`s).
+    ///
+    /// This parses `usize`, `isize`, `u64`, `i64`, `u128`, and `i128` into a
+    /// [`BigInt`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt),
+    /// which would otherwise be rejected.
+    ///
+    /// The integers must be sent as decimal strings, such as with
+    /// [`serde_with::DisplayFromStr`](https://docs.rs/serde_with/latest/serde_with/struct.DisplayFromStr.html),
+    /// as a JSON number may have lost precision before the schema sees it.
+    pub fn enable_lossless_bigints(mut self) -> Self {
+        self.lossless_bigint = true;
+        self
+    }
+
+    pub(crate) fn rule(&self, types: &Types, reference: &NamedReference) -> Option<&Rule> {
+        let ndt = types.get(reference)?;
+        self.rules
+            .iter()
+            .find(|rule| rule.name == ndt.name && rule.module_path == ndt.module_path)
+    }
+
+    pub(crate) fn bigint(&self, primitive: &Primitive) -> Option<Schema> {
+        (self.lossless_bigint && is_bigint(primitive)).then_some(Schema::BigInt)
+    }
+
+    /// Replaces the semantic types in a datatype with the types their schemas parse into, for the
+    /// TypeScript type exported beside a schema.
+    pub(crate) fn apply_alias(&self, types: &Types, dt: &mut DataType) {
+        match dt {
+            DataType::Primitive(primitive) => {
+                if self.lossless_bigint && is_bigint(primitive) {
+                    *dt = DataType::Reference(specta_typescript::define("bigint"));
+                }
+            }
+            DataType::Generic(_) => {}
+            DataType::List(list) => self.apply_alias(types, &mut list.ty),
+            // Map keys are always strings on the wire, so they are never decoded.
+            DataType::Map(map) => self.apply_alias(types, map.value_ty_mut()),
+            DataType::Nullable(inner) => self.apply_alias(types, inner),
+            DataType::Struct(strct) => self.apply_alias_fields(types, &mut strct.fields),
+            DataType::Enum(enm) => enm
+                .variants
+                .iter_mut()
+                .for_each(|(_, variant)| self.apply_alias_fields(types, &mut variant.fields)),
+            DataType::Tuple(tuple) => tuple
+                .elements
+                .iter_mut()
+                .for_each(|dt| self.apply_alias(types, dt)),
+            DataType::Intersection(dts) => {
+                dts.iter_mut().for_each(|dt| self.apply_alias(types, dt))
+            }
+            DataType::Reference(Reference::Named(reference)) => {
+                if let Some(rule) = self.rule(types, reference) {
+                    *dt = DataType::Reference(specta_typescript::define(rule.ty.clone()));
+                    return;
+                }
+
+                match &mut reference.inner {
+                    NamedReferenceType::Inline { dt, .. } => self.apply_alias(types, dt),
+                    NamedReferenceType::Reference { generics, .. } => generics
+                        .iter_mut()
+                        .for_each(|(_, dt)| self.apply_alias(types, dt)),
+                    NamedReferenceType::Recursive(_) => {}
+                }
+            }
+            DataType::Reference(Reference::Opaque(_)) => {}
+        }
+    }
+
+    fn apply_alias_fields(&self, types: &Types, fields: &mut Fields) {
+        match fields {
+            Fields::Unit => {}
+            Fields::Unnamed(fields) => fields
+                .fields
+                .iter_mut()
+                .filter_map(|field| field.ty.as_mut())
+                .for_each(|dt| self.apply_alias(types, dt)),
+            Fields::Named(fields) => fields
+                .fields
+                .iter_mut()
+                .filter_map(|(_, field)| field.ty.as_mut())
+                .for_each(|dt| self.apply_alias(types, dt)),
+        }
+    }
+}
+
+fn is_bigint(primitive: &Primitive) -> bool {
+    matches!(
+        primitive,
+        Primitive::usize
+            | Primitive::isize
+            | Primitive::u64
+            | Primitive::i64
+            | Primitive::u128
+            | Primitive::i128
+    )
+}
@@ -12,7 +12,11 @@ use specta::{
     datatype::{DataType, Fields, NamedDataType, Reference},
 };
 
-use crate::{Error, primitives, references, reserved_names::RESERVED_TYPE_NAMES};
+use specta_typescript::{Branded, BrandedTypeExporter, OptionalStyle};
+
+use crate::{
+    Error, primitives, references, reserved_names::RESERVED_TYPE_NAMES, semantic::Configuration,
+};
 
 /// Allows configuring the format of generated output.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -34,6 +38,35 @@ impl fmt::Display for Layout {
     }
 }
 
+/// The Zod API generated schemas are written against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Target {
+    /// [Zod 3](https://v3.zod.dev), imported from `zod`.
+    ///
+    /// Zod 3 has no exact optional properties and no optional tuple elements, so exporting them
+    /// is an error. Semantic types are parsed but can't be encoded back into their wire
+    /// representation.
+    Zod3,
+    /// [Zod 4](https://zod.dev), imported from `zod`.
+    #[default]
+    Zod4,
+    /// The functional API of [Zod Mini](https://zod.dev/packages/mini), imported from `zod/mini`.
+    ///
+    /// Properties referencing other schemas are defined as getters rather than through `z.lazy`,
+    /// which is how Zod Mini defines recursive objects.
+    Mini,
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Zod3 => write!(f, "Zod 3"),
+            Self::Zod4 => write!(f, "Zod 4"),
+            Self::Mini => write!(f, "Zod Mini"),
+        }
+    }
+}
+
 #[derive(Clone)]
 #[allow(clippy::type_complexity)]
 struct RuntimeFn(
@@ -46,6 +79,41 @@ impl fmt::Debug for RuntimeFn {
     }
 }
 
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub(crate) struct BrandedSchemaFn(
+    pub(crate)  Arc<
+        dyn for<'a> Fn(BrandedSchemaExporter<'a>, &Branded) -> Result<Cow<'static, str>, Error>
+            + Send
+            + Sync,
+    >,
+);
+
+impl fmt::Debug for BrandedSchemaFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BrandedSchemaFn({:p})", self.0)
+    }
+}
+
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub(crate) struct BrandedTypeFn(
+    pub(crate)  Arc<
+        dyn for<'a> Fn(
+                BrandedTypeExporter<'a>,
+                &Branded,
+            ) -> Result<Cow<'static, str>, specta_typescript::Error>
+            + Send
+            + Sync,
+    >,
+);
+
+impl fmt::Debug for BrandedTypeFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BrandedTypeFn({:p})", self.0)
+    }
+}
+
 /// Zod language exporter.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -54,9 +122,14 @@ pub struct Zod {
     pub header: Cow<'static, str>,
     raw: Vec<Cow<'static, str>>,
     framework_runtime: Option<RuntimeFn>,
-    framework_prelude: Cow<'static, str>,
+    framework_prelude: Option<Cow<'static, str>>,
     /// Output layout mode for generated Zod TypeScript.
     pub layout: Layout,
+    pub(crate) target: Target,
+    pub(crate) optional_style: OptionalStyle,
+    pub(crate) branded_schema_impl: Option<BrandedSchemaFn>,
+    pub(crate) branded_type_impl: Option<BrandedTypeFn>,
+    pub(crate) semantic: Option<Configuration>,
 }
 
 impl Default for Zod {
@@ -72,16 +145,21 @@ impl Zod {
             header: Cow::Borrowed(""),
             raw: Vec::new(),
             framework_runtime: None,
-            framework_prelude: Cow::Borrowed(
-                "import { z } from \"zod\";\n// This file has been generated by Specta. Do not edit this file manually.",
-            ),
+            framework_prelude: None,
             layout: Default::default(),
+            target: Default::default(),
+            optional_style: Default::default(),
+            branded_schema_impl: None,
+            branded_type_impl: None,
+            semantic: None,
         }
     }
 
     /// Provide a prelude which is added to the start of all exported files.
+    ///
+    /// By default this imports `z` from the package of the configured [`Target`].
     pub fn framework_prelude(mut self, prelude: impl Into<Cow<'static, str>>) -> Self {
-        self.framework_prelude = prelude.into();
+        self.framework_prelude = Some(prelude.into());
         self
     }
 
@@ -115,6 +193,84 @@ impl Zod {
         self
     }
 
+    /// Configure the Zod API the schemas are written against.
+    pub fn target(mut self, target: Target) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Configure how optional object properties are validated and typed.
+    ///
+    /// Use the same [`OptionalStyle`] as the TypeScript exporter of the project, so the schemas
+    /// and the types exported beside them agree.
+    pub fn optional_style(mut self, optional_style: OptionalStyle) -> Self {
+        self.optional_style = optional_style;
+        self
+    }
+
+    /// Configure how `specta_typescript::branded!` types are validated.
+    ///
+    /// By default a brand renders as `.brand<"accountId">()` on the schema of the inner type, so parsing produces a branded value. Configure
+    /// [`Zod::branded_type_impl`] alongside this, so the type each schema is annotated with is
+    /// the one it infers.
+    pub fn branded_schema_impl(
+        mut self,
+        builder: impl for<'a> Fn(
+            BrandedSchemaExporter<'a>,
+            &Branded,
+        ) -> Result<Cow<'static, str>, Error>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.branded_schema_impl = Some(BrandedSchemaFn(Arc::new(builder)));
+        self
+    }
+
+    /// Configure how `specta_typescript::branded!` types are rendered in the TypeScript types
+    /// exported beside the schemas.
+    ///
+    /// This works like [`Typescript::branded_type_impl`](specta_typescript::Typescript::branded_type_impl).
+    /// By default a brand renders as `string & z.$brand<"accountId">`, the type `.brand<"accountId">()` infers.
+    pub fn branded_type_impl(
+        mut self,
+        builder: impl for<'a> Fn(
+            BrandedTypeExporter<'a>,
+            &Branded,
+        ) -> Result<Cow<'static, str>, specta_typescript::Error>
+        + Send
+        + Sync
+        + 'static,
+    ) -> Self {
+        self.branded_type_impl = Some(BrandedTypeFn(Arc::new(builder)));
+        self
+    }
+
+    /// Parse semantic types, such as dates, into richer JavaScript values.
+    ///
+    /// Types matching a rule of the [`Configuration`](crate::semantic::Configuration) are
+    /// validated by the rule's schema, typically a [`z.codec`](https://zod.dev/codecs) which
+    /// decodes the wire value when parsing and encodes it back with `z.encode`. The TypeScript
+    /// types exported beside the schemas use the decoded types.
+    pub fn semantic(mut self, semantic: Configuration) -> Self {
+        self.semantic = Some(semantic);
+        self
+    }
+
+    fn prelude(&self) -> Cow<'static, str> {
+        match &self.framework_prelude {
+            Some(prelude) => prelude.clone(),
+            None => Cow::Borrowed(match self.target {
+                Target::Zod3 | Target::Zod4 => {
+                    "import { z } from \"zod\";\n// This file has been generated by Specta. Do not edit this file manually."
+                }
+                Target::Mini => {
+                    "import { z } from \"zod/mini\";\n// This file has been generated by Specta. Do not edit this file manually."
+                }
+            }),
+        }
+    }
+
     /// Export files into a single string.
     pub fn export(&self, types: &Types, format: impl Format) -> Result<String, Error> {
         let exporter = self.clone();
@@ -439,7 +595,9 @@ fn map_datatype_format(
                 | specta::datatype::NamedReferenceType::Recursive(_) => false,
             },
             DataType::Generic(_) => true,
-            DataType::Reference(Reference::Opaque(_)) => false,
+            DataType::Reference(Reference::Opaque(reference)) => reference
+                .downcast_ref::<Branded>()
+                .is_some_and(|branded| contains_generic_reference(branded.ty())),
         }
     }
 
@@ -542,7 +700,16 @@ fn map_datatype_format_children(
                 }
             }
         }
-        DataType::Reference(Reference::Opaque(_)) | DataType::Generic(_) => {}
+        DataType::Reference(Reference::Opaque(reference)) => {
+            if let Some(branded) = reference.downcast_ref::<Branded>() {
+                dt = Reference::opaque(Branded::new(
+                    branded.brand().clone(),
+                    map_datatype_format(format, types, branded.ty())?,
+                ))
+                .into();
+            }
+        }
+        DataType::Generic(_) => {}
     }
 
     Ok(dt)
@@ -600,6 +767,40 @@ impl AsMut<Zod> for Zod {
     }
 }
 
+/// Reference to the Zod exporter for branded schema callbacks.
+pub struct BrandedSchemaExporter<'a> {
+    pub(crate) exporter: &'a Zod,
+    /// Collected types currently being exported.
+    pub types: &'a Types,
+}
+
+impl fmt::Debug for BrandedSchemaExporter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.exporter.fmt(f)
+    }
+}
+
+impl AsRef<Zod> for BrandedSchemaExporter<'_> {
+    fn as_ref(&self) -> &Zod {
+        self.exporter
+    }
+}
+
+impl Deref for BrandedSchemaExporter<'_> {
+    type Target = Zod;
+
+    fn deref(&self) -> &Self::Target {
+        self.exporter
+    }
+}
+
+impl BrandedSchemaExporter<'_> {
+    /// [primitives::inline]
+    pub fn inline(&self, dt: &DataType) -> Result<String, Error> {
+        primitives::inline(self, self.types, dt)
+    }
+}
+
 /// Reference to the Zod exporter for framework callbacks.
 pub struct FrameworkExporter<'a> {
     exporter: &'a Zod,
@@ -715,8 +916,9 @@ fn render_file_header(exporter: &Zod) -> String {
         out.push('\n');
     }
 
-    out.push_str(&exporter.framework_prelude);
-    if !exporter.framework_prelude.is_empty() {
+    let prelude = exporter.prelude();
+    out.push_str(&prelude);
+    if !prelude.is_empty() {
         out.push('\n');
     }
 
@@ -936,9 +1138,9 @@ fn collect_existing_files(root: &Path) -> Result<HashSet<PathBuf>, Error> {
 }
 
 fn is_generated_specta_file(path: &Path, exporter: &Zod) -> Result<bool, Error> {
+    let prelude = exporter.prelude();
     match std::fs::read_to_string(path) {
-        Ok(contents) => Ok((!exporter.framework_prelude.is_empty()
-            && contents.contains(exporter.framework_prelude.as_ref()))
+        Ok(contents) => Ok((!prelude.is_empty() && contents.contains(prelude.as_ref()))
             || contents.contains("generated by Specta")),
         Err(err) if err.kind() == std::io::ErrorKind::InvalidData => Ok(false),
         Err(source) => Err(Error::from(source)),
@@ -5,12 +5,14 @@ use crate::{Type, Types};
 // Global type store for collecting custom types to export.
 //
 // We intentionally store functions over a `Types` directly to ensure any internal panics aren't done in CTOR.
+// Each function is stored with the group it was collected into, if any.
 #[allow(clippy::type_complexity)]
-static TYPES: OnceLock<Mutex<Vec<fn(&mut Types)>>> = OnceLock::new();
+static TYPES: OnceLock<Mutex<Vec<(Option<&'static str>, fn(&mut Types))>>> = OnceLock::new();
 
 /// Get the global type store containing all automatically collected types.
 ///
 /// All types with the [`Type`](macro@crate::Type) macro will automatically be registered here unless they have been explicitly disabled with `#[specta(collect = false)]`.
+/// This includes types collected into a named group with `#[specta(collect = "group")]`, use [`collect_group`] to get only a single group.
 ///
 /// Note that when enabling the `export` feature, you will not be able to enable the `unsafe_code` lint as [`small_ctor`] (which is used internally) is marked unsafe.
 ///
@@ -54,14 +56,57 @@ static TYPES: OnceLock<Mutex<Vec<fn(&mut Types)>>> = OnceLock::new();
 /// }
 /// ```
 pub fn collect() -> Types {
+    collect_filtered(|_| true)
+}
+
+/// Get the types collected into the group `name` with `#[specta(collect = "name")]`.
+///
+/// Types without a group are not included, however any named types which the group's types depend on are.
+/// This allows generating separate bindings for multiple services which are linked into the same binary.
+///
+/// # Example
+///
+/// ```no_run
+/// use specta::Type;
+/// use specta_typescript::Typescript;
+///
+/// #[derive(Type)]
+/// #[specta(collect = "billing")]
+/// pub struct Invoice {
+///     id: String,
+///     amount: u64,
+/// }
+///
+/// #[derive(Type)]
+/// #[specta(collect = "accounts")]
+/// pub struct Account {
+///     id: String,
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     // Only contains `Invoice`
+///     let output = Typescript::default().export(&specta::collect_group("billing"), specta_serde::Format)?;
+///
+///     println!("{output}");
+///
+///     Ok(())
+/// }
+/// ```
+pub fn collect_group(name: &str) -> Types {
+    collect_filtered(|group| group == Some(name))
+}
+
+fn collect_filtered(filter: impl Fn(Option<&str>) -> bool) -> Types {
     let types = TYPES
         .get_or_init(Default::default)
         .lock()
         .unwrap_or_else(PoisonError::into_inner);
 
     let mut map = Types::default();
-    for export in types.iter() {
-        export(&mut map);
+    for (group, export) in types.iter() {
+        if filter(*group) {
+            export(&mut map);
+        }
     }
     map
 }
@@ -71,14 +116,14 @@ pub mod internal {
     use super::*;
 
     // Called within ctor functions to register a type.
-    pub fn register<T: Type>() {
+    pub fn register<T: Type>(group: Option<&'static str>) {
         TYPES
             .get_or_init(Default::default)
             .lock()
             .unwrap_or_else(PoisonError::into_inner)
-            .push(|types| {
+            .push((group, |types| {
                 types.register_mut::<T>();
-            });
+            }));
     }
 
     // We expose this for the macros
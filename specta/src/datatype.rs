@@ -6,12 +6,14 @@ mod fields;
 mod function;
 mod generic;
 mod list;
+mod literal;
 mod map;
 mod named;
 mod primitive;
 mod reference;
 mod r#struct;
 mod tuple;
+pub mod visit;
 
 pub use attributes::Attributes;
 pub use r#enum::{Enum, Variant, VariantBuilder};
@@ -19,7 +21,7 @@ pub use fields::{Field, Fields, NamedFields, StructBuilder, UnnamedFields};
 pub use function::Function;
 pub use generic::{Generic, Generic as GenericReference, GenericDefinition};
 pub use list::List;
-// pub use literal::Literal;
+pub use literal::{Literal, LiteralType, LiteralValue};
 pub use map::Map;
 pub use named::{Deprecated, NamedDataType, inline};
 pub use primitive::Primitive;
@@ -30,6 +32,7 @@ pub use reference::{
 pub(crate) use reference::RecursiveInlineFrame;
 pub use r#struct::Struct;
 pub use tuple::Tuple;
+pub use visit::{Fold, PathSegment, Visit, VisitContext, VisitMut};
 
 pub(crate) use reference::NamedId;
 
@@ -7,6 +7,8 @@ use std::{
     sync::Arc,
 };
 
+use super::Literal;
+
 trait DynAttributeValue: Send + Sync {
     fn value_any(&self) -> &dyn Any;
     fn eq_dyn(&self, other: &dyn DynAttributeValue) -> bool;
@@ -132,8 +134,100 @@ impl Attributes {
             .get(key)
             .and_then(|value| value.value_any().downcast_ref::<T>())
     }
+
+    /// Returns the export name set with `#[specta(rename = "...")]` or
+    /// `#[specta(rename_all = "...")]`.
+    ///
+    /// Unlike `#[serde(rename = "...")]`, this only renames the identifier in
+    /// the exported code and never the serialized name. For named types the
+    /// rename is already applied to [`NamedDataType::name`](super::NamedDataType::name).
+    /// For fields and variants, exporters which map identifiers to serialized
+    /// names, like Swift's `CodingKeys`, use it in place of their own naming
+    /// convention, while exporters where the identifier is the serialized name,
+    /// like TypeScript, ignore it.
+    pub fn rename(&self) -> Option<&str> {
+        self.get_named_as::<Cow<'static, str>>(RENAME)
+            .map(|name| name.as_ref())
+    }
+
+    /// Sets the export name returned by [`Attributes::rename`].
+    pub fn set_rename(&mut self, name: impl Into<Cow<'static, str>>) {
+        self.insert(RENAME, name.into());
+    }
+
+    /// Returns the discriminant of an enum variant.
+    ///
+    /// `#[derive(Type)]` records it for every variant of a fieldless enum with an integer
+    /// `#[repr(..)]`, typed as that integer, so formats like `specta_serde` can export
+    /// `serde_repr` enums by value and exporters can render them as numeric enums.
+    pub fn discriminant(&self) -> Option<&Literal> {
+        self.get_named_as::<Literal>(DISCRIMINANT)
+    }
+
+    /// Sets the discriminant returned by [`Attributes::discriminant`].
+    pub fn set_discriminant(&mut self, value: impl Into<Literal>) {
+        self.insert(DISCRIMINANT, value.into());
+    }
+
+    /// Returns the example values of a field or enum variant, each encoded as JSON.
+    ///
+    /// `#[derive(Type)]` records these from `#[specta(example = ..)]` and
+    /// `#[specta(examples = [..])]` when specta's `serde_json` feature is enabled.
+    pub fn examples(&self) -> &[Cow<'static, str>] {
+        self.get_named_as::<Vec<Cow<'static, str>>>(EXAMPLES)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Sets the JSON encoded examples returned by [`Attributes::examples`].
+    pub fn set_examples<T: Into<Cow<'static, str>>>(
+        &mut self,
+        examples: impl IntoIterator<Item = T>,
+    ) {
+        let examples = examples.into_iter().map(Into::into).collect::<Vec<_>>();
+        if examples.is_empty() {
+            self.remove(EXAMPLES);
+        } else {
+            self.insert(EXAMPLES, examples);
+        }
+    }
+
+    /// Returns the default value of a field, encoded as JSON.
+    ///
+    /// `#[derive(Type)]` records it from `#[specta(default = ..)]`, or from
+    /// `#[serde(default)]` when the value can be evaluated at registration time, when specta's
+    /// `serde_json` feature is enabled.
+    pub fn default_value(&self) -> Option<&str> {
+        self.get_named_as::<Cow<'static, str>>(DEFAULT_VALUE)
+            .map(|value| value.as_ref())
+    }
+
+    /// Sets the JSON encoded default returned by [`Attributes::default_value`].
+    pub fn set_default_value(&mut self, value: impl Into<Cow<'static, str>>) {
+        self.insert(DEFAULT_VALUE, value.into());
+    }
+
+    /// Returns whether a struct, enum or field, and everything within it, is exported immutable.
+    ///
+    /// `#[derive(Type)]` records it from `#[specta(readonly)]` and `#[specta(readonly = false)]`.
+    /// Exporters with a notion of immutability, like TypeScript's `readonly`, use it to override
+    /// their own setting.
+    pub fn readonly(&self) -> Option<bool> {
+        self.get_named_as::<bool>(READONLY).copied()
+    }
+
+    /// Sets the override returned by [`Attributes::readonly`].
+    pub fn set_readonly(&mut self, readonly: bool) {
+        self.insert(READONLY, readonly);
+    }
 }
 
+const RENAME: &str = "specta:rename";
+const DISCRIMINANT: &str = "specta:discriminant";
+const EXAMPLES: &str = "specta:examples";
+const DEFAULT_VALUE: &str = "specta:default";
+const READONLY: &str = "specta:readonly";
+
 impl PartialEq for Attributes {
     fn eq(&self, other: &Self) -> bool {
         self.0.len() == other.0.len()
@@ -1,9 +1,3 @@
-//! This works but isn't being shipped for now as we don't need it for const generics anymore.
-//! Also it suffers from the following concerns:
-//!  - For a floating point number if it's a `NaN` or `Infinity` or `-Infinity` we should yield an error in the Typescript exporter as we can't export it to a Typescript type.
-//!  - What do we do about bigint primitives. Mainly Tauri Specta/TauRPC need to be able to deal with this somehow?
-//!
-
 use std::{
     any::Any,
     borrow::Cow,
@@ -12,28 +6,59 @@ use std::{
     sync::Arc,
 };
 
-use crate::{
-    Type, Types,
-    datatype::{DataType, Reference},
-};
+use crate::datatype::{DataType, Primitive, Reference};
 
 /// A type-erased literal value stored inside an opaque [`Reference`].
+///
+/// A literal is a type with exactly one value, like `"v1"` or `404`. Exporters which support
+/// literals render it with [`Literal::value`], and other exporters may fall back to
+/// [`Literal::primitive`].
+///
+/// Floating point literals can hold `NaN` and infinite values, which most languages and JSON
+/// can't express, so exporters are expected to reject them.
 #[derive(Clone)]
 pub struct Literal(Arc<dyn LiteralType>);
 
+/// The value held by a [`Literal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum LiteralValue<'a> {
+    /// A [`bool`] literal.
+    Bool(bool),
+    /// A signed integer literal.
+    Int(i128),
+    /// An unsigned integer literal.
+    UInt(u128),
+    /// A floating point literal. It may be `NaN` or infinite.
+    ///
+    /// [`f32`] and `f16` literals are widened through their shortest representation, so `1.1f32`
+    /// is `1.1` like it's serialized, and `f128` literals are rounded to the nearest [`f64`].
+    Float(f64),
+    /// A [`char`] literal.
+    Char(char),
+    /// A string literal.
+    Str(&'a str),
+}
+
 impl Literal {
     /// Construct a literal [`DataType`] from a concrete value.
     ///
     /// T can be any of [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`], [`f32`], [`f64`], [`bool`], [`char`], [`&'static str`], [`String`], [`Cow<'static, str>`].
     ///
     /// On nightly, [`f16`] and [`f128`] are also supported.
+    #[allow(clippy::new_ret_no_self)]
     pub fn new<T: LiteralType>(value: T) -> DataType {
         DataType::Reference(Reference::opaque(Literal::from(value)))
     }
 
-    /// Returns the underlying datatype represented by this literal value.
-    pub fn definition(&self, types: &mut Types) -> DataType {
-        self.0.definition(types)
+    /// Returns the primitive type this literal is a value of.
+    pub fn primitive(&self) -> Primitive {
+        self.0.primitive()
+    }
+
+    /// Returns the value of this literal.
+    pub fn value(&self) -> LiteralValue<'_> {
+        self.0.value()
     }
 
     /// Attempt to downcast the stored literal to a concrete type.
@@ -42,19 +67,30 @@ impl Literal {
     }
 }
 
+mod sealed {
+    pub trait Sealed {}
+}
+
 /// Trait used by type-erased literal values.
 ///
 /// Sealed so we can add implementations in minor releases
-pub trait LiteralType: Any + Send + Sync + 'static {
-    /// Returns the underlying datatype represented by this literal value.
-    fn definition(&self, types: &mut Types) -> DataType;
+pub trait LiteralType: sealed::Sealed + Any + Send + Sync + 'static {
+    /// Returns the primitive type this literal is a value of.
+    fn primitive(&self) -> Primitive;
+
+    /// Returns the value of this literal.
+    fn value(&self) -> LiteralValue<'_>;
 
+    #[doc(hidden)]
     fn eq_dyn(&self, other: &dyn LiteralType) -> bool;
 
+    #[doc(hidden)]
     fn hash_dyn(&self, state: &mut dyn hash::Hasher);
 
+    #[doc(hidden)]
     fn fmt_dyn(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
 
+    #[doc(hidden)]
     fn as_any(&self) -> &dyn Any;
 }
 
@@ -84,12 +120,25 @@ impl hash::Hash for Literal {
     }
 }
 
+impl From<Literal> for DataType {
+    fn from(literal: Literal) -> Self {
+        DataType::Reference(Reference::opaque(literal))
+    }
+}
+
 macro_rules! impl_literal_type {
-    ($($ty:ty),+ $(,)?) => {
+    ($($ty:ty => $primitive:ident, |$this:ident| $value:expr;)+) => {
         $(
+            impl sealed::Sealed for $ty {}
+
             impl LiteralType for $ty {
-                fn definition(&self, types: &mut Types) -> DataType {
-                    <$ty as Type>::definition(types)
+                fn primitive(&self) -> Primitive {
+                    Primitive::$primitive
+                }
+
+                fn value(&self) -> LiteralValue<'_> {
+                    let $this = self;
+                    $value
                 }
 
                 fn eq_dyn(&self, other: &dyn LiteralType) -> bool {
@@ -113,23 +162,23 @@ macro_rules! impl_literal_type {
 }
 
 impl_literal_type!(
-    i8,
-    i16,
-    i32,
-    i64,
-    i128,
-    isize,
-    u8,
-    u16,
-    u32,
-    u64,
-    u128,
-    usize,
-    bool,
-    char,
-    &'static str,
-    String,
-    Cow<'static, str>,
+    i8 => i8, |v| LiteralValue::Int(*v as i128);
+    i16 => i16, |v| LiteralValue::Int(*v as i128);
+    i32 => i32, |v| LiteralValue::Int(*v as i128);
+    i64 => i64, |v| LiteralValue::Int(*v as i128);
+    i128 => i128, |v| LiteralValue::Int(*v);
+    isize => isize, |v| LiteralValue::Int(*v as i128);
+    u8 => u8, |v| LiteralValue::UInt(*v as u128);
+    u16 => u16, |v| LiteralValue::UInt(*v as u128);
+    u32 => u32, |v| LiteralValue::UInt(*v as u128);
+    u64 => u64, |v| LiteralValue::UInt(*v as u128);
+    u128 => u128, |v| LiteralValue::UInt(*v);
+    usize => usize, |v| LiteralValue::UInt(*v as u128);
+    bool => bool, |v| LiteralValue::Bool(*v);
+    char => char, |v| LiteralValue::Char(*v);
+    &'static str => str, |v| LiteralValue::Str(v);
+    String => str, |v| LiteralValue::Str(v);
+    Cow<'static, str> => str, |v| LiteralValue::Str(v);
 );
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -141,7 +190,7 @@ enum FloatKey<Bits> {
     NaN,
 }
 
-trait FloatLiteral: Copy + PartialEq + Type + fmt::Debug + Send + Sync + 'static {
+trait FloatLiteral: Copy + PartialEq + fmt::Debug + Send + Sync + 'static {
     type Bits: Copy + Eq + Hash;
 
     const INFINITY: Self;
@@ -151,6 +200,7 @@ trait FloatLiteral: Copy + PartialEq + Type + fmt::Debug + Send + Sync + 'static
     fn is_nan(self) -> bool;
     fn is_negative_zero(self) -> bool;
     fn to_bits(self) -> Self::Bits;
+    fn to_f64(self) -> f64;
 }
 
 impl<T: FloatLiteral> From<T> for FloatKey<T::Bits> {
@@ -170,7 +220,7 @@ impl<T: FloatLiteral> From<T> for FloatKey<T::Bits> {
 }
 
 macro_rules! impl_float_literal {
-    ($ty:ty, $bits:ty) => {
+    ($ty:ident, $bits:ty, |$v:ident| $to_f64:expr) => {
         impl FloatLiteral for $ty {
             type Bits = $bits;
 
@@ -189,11 +239,22 @@ macro_rules! impl_float_literal {
             fn to_bits(self) -> Self::Bits {
                 self.to_bits()
             }
+
+            fn to_f64(self) -> f64 {
+                let $v = self;
+                $to_f64
+            }
         }
 
+        impl sealed::Sealed for $ty {}
+
         impl LiteralType for $ty {
-            fn definition(&self, types: &mut Types) -> DataType {
-                <$ty as Type>::definition(types)
+            fn primitive(&self) -> Primitive {
+                Primitive::$ty
+            }
+
+            fn value(&self) -> LiteralValue<'_> {
+                LiteralValue::Float(FloatLiteral::to_f64(*self))
             }
 
             fn eq_dyn(&self, other: &dyn LiteralType) -> bool {
@@ -218,11 +279,17 @@ macro_rules! impl_float_literal {
     };
 }
 
-impl_float_literal!(f32, u32);
-impl_float_literal!(f64, u64);
+impl_float_literal!(f32, u32, |v| widen(v));
+impl_float_literal!(f64, u64, |v| v);
 
 #[cfg(is_nightly)]
-impl_float_literal!(f16, u16);
+impl_float_literal!(f16, u16, |v| widen(v));
 
 #[cfg(is_nightly)]
-impl_float_literal!(f128, u128);
+impl_float_literal!(f128, u128, |v| v as f64);
+
+/// Widens a narrower float through its shortest [`Display`](fmt::Display) representation, which is
+/// what serializers emit, rather than its exact binary value.
+fn widen(value: impl fmt::Display) -> f64 {
+    value.to_string().parse().unwrap_or(f64::NAN)
+}
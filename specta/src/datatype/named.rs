@@ -53,6 +53,11 @@ pub struct NamedDataType {
     pub module_path: Cow<'static, str>,
     /// Source location where this named datatype was created.
     pub location: Location<'static>,
+    /// Tags attached with `#[specta(selection_tag = "...")]`.
+    ///
+    /// These have no effect on the exported type but can be used to select a subset of
+    /// [`Types`] with [`Selection`](crate::Selection).
+    pub tags: Cow<'static, [Cow<'static, str>]>,
     /// Generic parameters declared by this named datatype.
     pub generics: Cow<'static, [GenericDefinition]>,
     /// The generalised datatype of this specific named data type.
@@ -80,6 +85,7 @@ impl NamedDataType {
                 .map(Into::into)
                 .unwrap_or(Cow::Borrowed("virtual")),
             location: location.to_owned(),
+            tags: Cow::Borrowed(&[]),
             generics: Cow::Borrowed(&[]),
             ty: None,
         };
@@ -150,6 +156,7 @@ impl NamedDataType {
                 id: id.clone(),
                 location,
                 // `build_ndt` will just override all of this.
+                tags: Cow::Borrowed(&[]),
                 generics: Cow::Borrowed(&[]),
                 ty: None,
                 name: Cow::Borrowed(""),
@@ -90,6 +90,10 @@ impl RecursiveInlineType {
         &self.inner.generics
     }
 
+    pub(crate) fn with_generics(&self, generics: Vec<(Generic, DataType)>) -> Self {
+        Self::from_cycle(self.inner.cycle.clone(), generics)
+    }
+
     fn last_frame(&self) -> Option<&RecursiveInlineFrame> {
         self.inner.cycle.last()
     }
@@ -382,6 +386,8 @@ pub(crate) enum NamedId {
     // A unique string identifying the type (module_path::TypeName).
     Static(&'static str),
     Dynamic(Arc<()>),
+    // A generic type instantiated with concrete arguments by [`Monomorphise`](crate::Monomorphise).
+    Instance(Arc<(NamedId, Vec<DataType>)>),
 }
 
 impl PartialEq for NamedId {
@@ -389,6 +395,7 @@ impl PartialEq for NamedId {
         match (self, other) {
             (NamedId::Static(a), NamedId::Static(b)) => a == b,
             (NamedId::Dynamic(a), NamedId::Dynamic(b)) => Arc::ptr_eq(a, b),
+            (NamedId::Instance(a), NamedId::Instance(b)) => a == b,
             _ => false,
         }
     }
@@ -400,6 +407,7 @@ impl hash::Hash for NamedId {
         match self {
             NamedId::Static(s) => s.hash(state),
             NamedId::Dynamic(p) => std::ptr::hash(Arc::as_ptr(p), state),
+            NamedId::Instance(instance) => instance.hash(state),
         }
     }
 }
@@ -409,6 +417,7 @@ impl fmt::Debug for NamedId {
         match self {
             NamedId::Static(s) => write!(f, "s:{}", s),
             NamedId::Dynamic(p) => write!(f, "d{:p}", Arc::as_ptr(p)),
+            NamedId::Instance(instance) => write!(f, "i{:?}{:?}", instance.0, instance.1),
         }
     }
 }
@@ -0,0 +1,1021 @@
+//! Generic traversal of the [`DataType`] tree.
+//!
+//! This module provides three traits which follow the pattern used by [`syn`](https://docs.rs/syn):
+//!  - [`Visit`] walks a tree by shared reference.
+//!  - [`VisitMut`] walks a tree by mutable reference, allowing nodes to be modified in place.
+//!  - [`Fold`] consumes a tree and rebuilds it, allowing nodes to be replaced by value.
+//!
+//! Every trait has a method for each node which defaults to recursing into the node's children
+//! through the free function of the same name. When overriding a method you can call that function
+//! to continue the traversal into the node's children, or omit it to skip them.
+//!
+//! All traversals carry a [`VisitContext`] which tracks the [`PathSegment`]s leading to the current
+//! node. A context created with [`VisitContext::resolving`] also makes [`Visit`] follow named
+//! references into their definitions in [`Types`], visiting each definition at most once per path
+//! so recursive types terminate.
+//!
+//! # Examples
+//!
+//! Collect the path of every `u64` in a type:
+//!
+//! ```rust
+//! use specta::datatype::{
+//!     DataType, Field, Primitive, Struct,
+//!     visit::{Visit, VisitContext},
+//! };
+//!
+//! #[derive(Default)]
+//! struct FindU64(Vec<String>);
+//!
+//! impl<'a> Visit<'a> for FindU64 {
+//!     fn visit_primitive(&mut self, primitive: &'a Primitive, cx: &mut VisitContext<'a>) {
+//!         if *primitive == Primitive::u64 {
+//!             self.0.push(cx.path_string());
+//!         }
+//!     }
+//! }
+//!
+//! let dt = Struct::named()
+//!     .field("id", Field::new(Primitive::u64.into()))
+//!     .field("name", Field::new(Primitive::str.into()))
+//!     .build();
+//!
+//! let mut visitor = FindU64::default();
+//! visitor.visit_datatype(&dt, &mut VisitContext::new());
+//! assert_eq!(visitor.0, ["id"]);
+//! ```
+
+use std::{borrow::Cow, fmt, mem};
+
+use crate::{
+    Types,
+    datatype::{
+        DataType, Enum, Field, Fields, Generic, List, Map, NamedDataType, NamedId, NamedReference,
+        NamedReferenceType, Primitive, Reference, Struct, Tuple, Variant,
+    },
+};
+
+/// A single step in the path from the root of a traversal to the current node.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PathSegment {
+    /// The element type of a [`List`].
+    ListItem,
+    /// The key type of a [`Map`].
+    MapKey,
+    /// The value type of a [`Map`].
+    MapValue,
+    /// The inner type of a [`DataType::Nullable`].
+    Nullable,
+    /// An element of a [`Tuple`] or an unnamed field, by position.
+    Index(usize),
+    /// A named field.
+    Field(Cow<'static, str>),
+    /// An [`Enum`] variant.
+    Variant(Cow<'static, str>),
+    /// A member of a [`DataType::Intersection`], by position.
+    Intersection(usize),
+    /// A generic argument of a [`NamedReference`].
+    GenericArgument(Cow<'static, str>),
+    /// The definition of a named reference resolved through [`Types`].
+    Named(Cow<'static, str>),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ListItem => f.write_str("[]"),
+            Self::MapKey => f.write_str("<key>"),
+            Self::MapValue => f.write_str("<value>"),
+            Self::Nullable => f.write_str("?"),
+            Self::Index(index) | Self::Intersection(index) => write!(f, "{index}"),
+            Self::Field(name) | Self::Variant(name) | Self::Named(name) => f.write_str(name),
+            Self::GenericArgument(name) => write!(f, "<{name}>"),
+        }
+    }
+}
+
+/// State carried through a traversal.
+#[derive(Debug, Clone, Default)]
+pub struct VisitContext<'a> {
+    types: Option<&'a Types>,
+    path: Vec<PathSegment>,
+    resolving: Vec<NamedId>,
+}
+
+impl<'a> VisitContext<'a> {
+    /// Create a context which does not follow named references.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a context which makes [`Visit`] follow named references into their definition in
+    /// `types`.
+    ///
+    /// Resolved definitions are visited through [`Visit::visit_named_data_type`]. A definition which is
+    /// already being visited further up the path is skipped, so recursive types terminate.
+    ///
+    /// [`VisitMut`] and [`Fold`] never follow named references, as the definitions are not owned
+    /// by the tree being modified, but they can still look them up through [`VisitContext::types`].
+    pub fn resolving(types: &'a Types) -> Self {
+        Self {
+            types: Some(types),
+            ..Self::default()
+        }
+    }
+
+    /// The types named references are resolved against, if any.
+    pub fn types(&self) -> Option<&'a Types> {
+        self.types
+    }
+
+    /// The path from the root of the traversal to the current node.
+    pub fn path(&self) -> &[PathSegment] {
+        &self.path
+    }
+
+    /// The path from the root of the traversal to the current node, joined with `.`.
+    pub fn path_string(&self) -> String {
+        self.path
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Run `f` with `segment` appended to the path.
+    pub fn with_segment<R>(&mut self, segment: PathSegment, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.path.push(segment);
+        let result = f(self);
+        self.path.pop();
+        result
+    }
+}
+
+/// Traverse a [`DataType`] tree by shared reference.
+///
+/// Refer to the [module documentation](self) for more information.
+#[allow(unused_variables)]
+pub trait Visit<'a> {
+    /// Visit any [`DataType`].
+    fn visit_datatype(&mut self, dt: &'a DataType, cx: &mut VisitContext<'a>) {
+        visit_datatype(self, dt, cx)
+    }
+
+    /// Visit a [`Primitive`].
+    fn visit_primitive(&mut self, primitive: &'a Primitive, cx: &mut VisitContext<'a>) {}
+
+    /// Visit a [`List`].
+    fn visit_list(&mut self, list: &'a List, cx: &mut VisitContext<'a>) {
+        visit_list(self, list, cx)
+    }
+
+    /// Visit a [`Map`].
+    fn visit_map(&mut self, map: &'a Map, cx: &mut VisitContext<'a>) {
+        visit_map(self, map, cx)
+    }
+
+    /// Visit a [`Struct`].
+    fn visit_struct(&mut self, strct: &'a Struct, cx: &mut VisitContext<'a>) {
+        visit_struct(self, strct, cx)
+    }
+
+    /// Visit an [`Enum`].
+    fn visit_enum(&mut self, enm: &'a Enum, cx: &mut VisitContext<'a>) {
+        visit_enum(self, enm, cx)
+    }
+
+    /// Visit a [`Variant`] of an [`Enum`].
+    fn visit_variant(&mut self, name: &'a str, variant: &'a Variant, cx: &mut VisitContext<'a>) {
+        visit_variant(self, name, variant, cx)
+    }
+
+    /// Visit the [`Fields`] of a [`Struct`] or [`Variant`].
+    fn visit_fields(&mut self, fields: &'a Fields, cx: &mut VisitContext<'a>) {
+        visit_fields(self, fields, cx)
+    }
+
+    /// Visit a [`Field`]. The field's name or index is the last segment of the path.
+    fn visit_field(&mut self, field: &'a Field, cx: &mut VisitContext<'a>) {
+        visit_field(self, field, cx)
+    }
+
+    /// Visit a [`Tuple`].
+    fn visit_tuple(&mut self, tuple: &'a Tuple, cx: &mut VisitContext<'a>) {
+        visit_tuple(self, tuple, cx)
+    }
+
+    /// Visit the inner type of a [`DataType::Nullable`].
+    fn visit_nullable(&mut self, inner: &'a DataType, cx: &mut VisitContext<'a>) {
+        visit_nullable(self, inner, cx)
+    }
+
+    /// Visit the members of a [`DataType::Intersection`].
+    fn visit_intersection(&mut self, types: &'a [DataType], cx: &mut VisitContext<'a>) {
+        visit_intersection(self, types, cx)
+    }
+
+    /// Visit a [`Generic`] placeholder.
+    fn visit_generic(&mut self, generic: &'a Generic, cx: &mut VisitContext<'a>) {}
+
+    /// Visit a [`Reference`].
+    fn visit_reference(&mut self, reference: &'a Reference, cx: &mut VisitContext<'a>) {
+        visit_reference(self, reference, cx)
+    }
+
+    /// Visit a [`NamedReference`].
+    fn visit_named_reference(&mut self, reference: &'a NamedReference, cx: &mut VisitContext<'a>) {
+        visit_named_reference(self, reference, cx)
+    }
+
+    /// Visit the definition of a named reference which was resolved through
+    /// [`VisitContext::resolving`].
+    ///
+    /// Generic placeholders in the definition are not substituted with the reference's arguments.
+    fn visit_named_data_type(&mut self, ndt: &'a NamedDataType, cx: &mut VisitContext<'a>) {
+        visit_named_data_type(self, ndt, cx)
+    }
+}
+
+/// Recurse into the children of a [`DataType`].
+pub fn visit_datatype<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    dt: &'a DataType,
+    cx: &mut VisitContext<'a>,
+) {
+    match dt {
+        DataType::Primitive(primitive) => v.visit_primitive(primitive, cx),
+        DataType::List(list) => v.visit_list(list, cx),
+        DataType::Map(map) => v.visit_map(map, cx),
+        DataType::Struct(strct) => v.visit_struct(strct, cx),
+        DataType::Enum(enm) => v.visit_enum(enm, cx),
+        DataType::Tuple(tuple) => v.visit_tuple(tuple, cx),
+        DataType::Nullable(inner) => v.visit_nullable(inner, cx),
+        DataType::Intersection(types) => v.visit_intersection(types, cx),
+        DataType::Generic(generic) => v.visit_generic(generic, cx),
+        DataType::Reference(reference) => v.visit_reference(reference, cx),
+    }
+}
+
+/// Recurse into the element type of a [`List`].
+pub fn visit_list<'a, V: Visit<'a> + ?Sized>(v: &mut V, list: &'a List, cx: &mut VisitContext<'a>) {
+    cx.with_segment(PathSegment::ListItem, |cx| v.visit_datatype(&list.ty, cx));
+}
+
+/// Recurse into the key and value types of a [`Map`].
+pub fn visit_map<'a, V: Visit<'a> + ?Sized>(v: &mut V, map: &'a Map, cx: &mut VisitContext<'a>) {
+    cx.with_segment(PathSegment::MapKey, |cx| v.visit_datatype(map.key_ty(), cx));
+    cx.with_segment(PathSegment::MapValue, |cx| {
+        v.visit_datatype(map.value_ty(), cx)
+    });
+}
+
+/// Recurse into the fields of a [`Struct`].
+pub fn visit_struct<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    strct: &'a Struct,
+    cx: &mut VisitContext<'a>,
+) {
+    v.visit_fields(&strct.fields, cx);
+}
+
+/// Recurse into the variants of an [`Enum`].
+pub fn visit_enum<'a, V: Visit<'a> + ?Sized>(v: &mut V, enm: &'a Enum, cx: &mut VisitContext<'a>) {
+    for (name, variant) in &enm.variants {
+        cx.with_segment(PathSegment::Variant(name.clone()), |cx| {
+            v.visit_variant(name, variant, cx)
+        });
+    }
+}
+
+/// Recurse into the fields of a [`Variant`].
+pub fn visit_variant<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    _name: &'a str,
+    variant: &'a Variant,
+    cx: &mut VisitContext<'a>,
+) {
+    v.visit_fields(&variant.fields, cx);
+}
+
+/// Recurse into each [`Field`] of [`Fields`].
+pub fn visit_fields<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    fields: &'a Fields,
+    cx: &mut VisitContext<'a>,
+) {
+    match fields {
+        Fields::Unit => {}
+        Fields::Unnamed(fields) => {
+            for (index, field) in fields.fields.iter().enumerate() {
+                cx.with_segment(PathSegment::Index(index), |cx| v.visit_field(field, cx));
+            }
+        }
+        Fields::Named(fields) => {
+            for (name, field) in &fields.fields {
+                cx.with_segment(PathSegment::Field(name.clone()), |cx| {
+                    v.visit_field(field, cx)
+                });
+            }
+        }
+    }
+}
+
+/// Recurse into the type of a [`Field`], if it is not skipped.
+pub fn visit_field<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    field: &'a Field,
+    cx: &mut VisitContext<'a>,
+) {
+    if let Some(ty) = &field.ty {
+        v.visit_datatype(ty, cx);
+    }
+}
+
+/// Recurse into the elements of a [`Tuple`].
+pub fn visit_tuple<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    tuple: &'a Tuple,
+    cx: &mut VisitContext<'a>,
+) {
+    for (index, dt) in tuple.elements.iter().enumerate() {
+        cx.with_segment(PathSegment::Index(index), |cx| v.visit_datatype(dt, cx));
+    }
+}
+
+/// Recurse into the inner type of a [`DataType::Nullable`].
+pub fn visit_nullable<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    inner: &'a DataType,
+    cx: &mut VisitContext<'a>,
+) {
+    cx.with_segment(PathSegment::Nullable, |cx| v.visit_datatype(inner, cx));
+}
+
+/// Recurse into the members of a [`DataType::Intersection`].
+pub fn visit_intersection<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    types: &'a [DataType],
+    cx: &mut VisitContext<'a>,
+) {
+    for (index, dt) in types.iter().enumerate() {
+        cx.with_segment(PathSegment::Intersection(index), |cx| {
+            v.visit_datatype(dt, cx)
+        });
+    }
+}
+
+/// Recurse into a [`Reference`]. Opaque references have no children.
+pub fn visit_reference<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    reference: &'a Reference,
+    cx: &mut VisitContext<'a>,
+) {
+    if let Reference::Named(reference) = reference {
+        v.visit_named_reference(reference, cx);
+    }
+}
+
+/// Recurse into the generic arguments or inline type of a [`NamedReference`], and into its
+/// definition if the context is [resolving](VisitContext::resolving).
+pub fn visit_named_reference<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    reference: &'a NamedReference,
+    cx: &mut VisitContext<'a>,
+) {
+    match &reference.inner {
+        NamedReferenceType::Recursive(_) => {}
+        NamedReferenceType::Inline { dt } => v.visit_datatype(dt, cx),
+        NamedReferenceType::Reference { generics } => {
+            for (generic, dt) in generics {
+                cx.with_segment(PathSegment::GenericArgument(generic.name().clone()), |cx| {
+                    v.visit_datatype(dt, cx)
+                });
+            }
+
+            if let Some(ndt) = cx.types.and_then(|types| types.get(reference))
+                && !cx.resolving.contains(&reference.id)
+            {
+                cx.resolving.push(reference.id.clone());
+                cx.with_segment(PathSegment::Named(ndt.name.clone()), |cx| {
+                    v.visit_named_data_type(ndt, cx)
+                });
+                cx.resolving.pop();
+            }
+        }
+    }
+}
+
+/// Recurse into the generic defaults and body of a [`NamedDataType`].
+pub fn visit_named_data_type<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    ndt: &'a NamedDataType,
+    cx: &mut VisitContext<'a>,
+) {
+    for generic in ndt.generics.iter() {
+        if let Some(default) = &generic.default {
+            cx.with_segment(PathSegment::GenericArgument(generic.name.clone()), |cx| {
+                v.visit_datatype(default, cx)
+            });
+        }
+    }
+    if let Some(ty) = &ndt.ty {
+        v.visit_datatype(ty, cx);
+    }
+}
+
+/// Traverse a [`DataType`] tree by mutable reference.
+///
+/// Refer to the [module documentation](self) for more information.
+#[allow(unused_variables)]
+pub trait VisitMut {
+    /// Visit any [`DataType`].
+    fn visit_datatype_mut(&mut self, dt: &mut DataType, cx: &mut VisitContext<'_>) {
+        visit_datatype_mut(self, dt, cx)
+    }
+
+    /// Visit a [`Primitive`].
+    fn visit_primitive_mut(&mut self, primitive: &mut Primitive, cx: &mut VisitContext<'_>) {}
+
+    /// Visit a [`List`].
+    fn visit_list_mut(&mut self, list: &mut List, cx: &mut VisitContext<'_>) {
+        visit_list_mut(self, list, cx)
+    }
+
+    /// Visit a [`Map`].
+    fn visit_map_mut(&mut self, map: &mut Map, cx: &mut VisitContext<'_>) {
+        visit_map_mut(self, map, cx)
+    }
+
+    /// Visit a [`Struct`].
+    fn visit_struct_mut(&mut self, strct: &mut Struct, cx: &mut VisitContext<'_>) {
+        visit_struct_mut(self, strct, cx)
+    }
+
+    /// Visit an [`Enum`].
+    fn visit_enum_mut(&mut self, enm: &mut Enum, cx: &mut VisitContext<'_>) {
+        visit_enum_mut(self, enm, cx)
+    }
+
+    /// Visit a [`Variant`] of an [`Enum`].
+    fn visit_variant_mut(
+        &mut self,
+        name: &mut Cow<'static, str>,
+        variant: &mut Variant,
+        cx: &mut VisitContext<'_>,
+    ) {
+        visit_variant_mut(self, name, variant, cx)
+    }
+
+    /// Visit the [`Fields`] of a [`Struct`] or [`Variant`].
+    fn visit_fields_mut(&mut self, fields: &mut Fields, cx: &mut VisitContext<'_>) {
+        visit_fields_mut(self, fields, cx)
+    }
+
+    /// Visit a [`Field`]. The field's name or index is the last segment of the path.
+    fn visit_field_mut(&mut self, field: &mut Field, cx: &mut VisitContext<'_>) {
+        visit_field_mut(self, field, cx)
+    }
+
+    /// Visit a [`Tuple`].
+    fn visit_tuple_mut(&mut self, tuple: &mut Tuple, cx: &mut VisitContext<'_>) {
+        visit_tuple_mut(self, tuple, cx)
+    }
+
+    /// Visit the inner type of a [`DataType::Nullable`].
+    fn visit_nullable_mut(&mut self, inner: &mut DataType, cx: &mut VisitContext<'_>) {
+        visit_nullable_mut(self, inner, cx)
+    }
+
+    /// Visit the members of a [`DataType::Intersection`].
+    fn visit_intersection_mut(&mut self, types: &mut Vec<DataType>, cx: &mut VisitContext<'_>) {
+        visit_intersection_mut(self, types, cx)
+    }
+
+    /// Visit a [`Generic`] placeholder.
+    fn visit_generic_mut(&mut self, generic: &mut Generic, cx: &mut VisitContext<'_>) {}
+
+    /// Visit a [`Reference`].
+    fn visit_reference_mut(&mut self, reference: &mut Reference, cx: &mut VisitContext<'_>) {
+        visit_reference_mut(self, reference, cx)
+    }
+
+    /// Visit a [`NamedReference`].
+    fn visit_named_reference_mut(
+        &mut self,
+        reference: &mut NamedReference,
+        cx: &mut VisitContext<'_>,
+    ) {
+        visit_named_reference_mut(self, reference, cx)
+    }
+
+    /// Visit a [`NamedDataType`].
+    ///
+    /// This is never called while walking a [`DataType`], but can be used to walk each entry of
+    /// [`Types`] with [`Types::iter_mut`].
+    fn visit_named_data_type_mut(&mut self, ndt: &mut NamedDataType, cx: &mut VisitContext<'_>) {
+        visit_named_data_type_mut(self, ndt, cx)
+    }
+}
+
+/// Recurse into the children of a [`DataType`].
+pub fn visit_datatype_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    dt: &mut DataType,
+    cx: &mut VisitContext<'_>,
+) {
+    match dt {
+        DataType::Primitive(primitive) => v.visit_primitive_mut(primitive, cx),
+        DataType::List(list) => v.visit_list_mut(list, cx),
+        DataType::Map(map) => v.visit_map_mut(map, cx),
+        DataType::Struct(strct) => v.visit_struct_mut(strct, cx),
+        DataType::Enum(enm) => v.visit_enum_mut(enm, cx),
+        DataType::Tuple(tuple) => v.visit_tuple_mut(tuple, cx),
+        DataType::Nullable(inner) => v.visit_nullable_mut(inner, cx),
+        DataType::Intersection(types) => v.visit_intersection_mut(types, cx),
+        DataType::Generic(generic) => v.visit_generic_mut(generic, cx),
+        DataType::Reference(reference) => v.visit_reference_mut(reference, cx),
+    }
+}
+
+/// Recurse into the element type of a [`List`].
+pub fn visit_list_mut<V: VisitMut + ?Sized>(v: &mut V, list: &mut List, cx: &mut VisitContext<'_>) {
+    cx.with_segment(PathSegment::ListItem, |cx| {
+        v.visit_datatype_mut(&mut list.ty, cx)
+    });
+}
+
+/// Recurse into the key and value types of a [`Map`].
+pub fn visit_map_mut<V: VisitMut + ?Sized>(v: &mut V, map: &mut Map, cx: &mut VisitContext<'_>) {
+    cx.with_segment(PathSegment::MapKey, |cx| {
+        v.visit_datatype_mut(map.key_ty_mut(), cx)
+    });
+    cx.with_segment(PathSegment::MapValue, |cx| {
+        v.visit_datatype_mut(map.value_ty_mut(), cx)
+    });
+}
+
+/// Recurse into the fields of a [`Struct`].
+pub fn visit_struct_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    strct: &mut Struct,
+    cx: &mut VisitContext<'_>,
+) {
+    v.visit_fields_mut(&mut strct.fields, cx);
+}
+
+/// Recurse into the variants of an [`Enum`].
+pub fn visit_enum_mut<V: VisitMut + ?Sized>(v: &mut V, enm: &mut Enum, cx: &mut VisitContext<'_>) {
+    for (name, variant) in &mut enm.variants {
+        cx.with_segment(PathSegment::Variant(name.clone()), |cx| {
+            v.visit_variant_mut(name, variant, cx)
+        });
+    }
+}
+
+/// Recurse into the fields of a [`Variant`].
+pub fn visit_variant_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    _name: &mut Cow<'static, str>,
+    variant: &mut Variant,
+    cx: &mut VisitContext<'_>,
+) {
+    v.visit_fields_mut(&mut variant.fields, cx);
+}
+
+/// Recurse into each [`Field`] of [`Fields`].
+pub fn visit_fields_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    fields: &mut Fields,
+    cx: &mut VisitContext<'_>,
+) {
+    match fields {
+        Fields::Unit => {}
+        Fields::Unnamed(fields) => {
+            for (index, field) in fields.fields.iter_mut().enumerate() {
+                cx.with_segment(PathSegment::Index(index), |cx| v.visit_field_mut(field, cx));
+            }
+        }
+        Fields::Named(fields) => {
+            for (name, field) in &mut fields.fields {
+                cx.with_segment(PathSegment::Field(name.clone()), |cx| {
+                    v.visit_field_mut(field, cx)
+                });
+            }
+        }
+    }
+}
+
+/// Recurse into the type of a [`Field`], if it is not skipped.
+pub fn visit_field_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    field: &mut Field,
+    cx: &mut VisitContext<'_>,
+) {
+    if let Some(ty) = &mut field.ty {
+        v.visit_datatype_mut(ty, cx);
+    }
+}
+
+/// Recurse into the elements of a [`Tuple`].
+pub fn visit_tuple_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    tuple: &mut Tuple,
+    cx: &mut VisitContext<'_>,
+) {
+    for (index, dt) in tuple.elements.iter_mut().enumerate() {
+        cx.with_segment(PathSegment::Index(index), |cx| v.visit_datatype_mut(dt, cx));
+    }
+}
+
+/// Recurse into the inner type of a [`DataType::Nullable`].
+pub fn visit_nullable_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    inner: &mut DataType,
+    cx: &mut VisitContext<'_>,
+) {
+    cx.with_segment(PathSegment::Nullable, |cx| v.visit_datatype_mut(inner, cx));
+}
+
+/// Recurse into the members of a [`DataType::Intersection`].
+pub fn visit_intersection_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    types: &mut [DataType],
+    cx: &mut VisitContext<'_>,
+) {
+    for (index, dt) in types.iter_mut().enumerate() {
+        cx.with_segment(PathSegment::Intersection(index), |cx| {
+            v.visit_datatype_mut(dt, cx)
+        });
+    }
+}
+
+/// Recurse into a [`Reference`]. Opaque references have no children.
+pub fn visit_reference_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    reference: &mut Reference,
+    cx: &mut VisitContext<'_>,
+) {
+    if let Reference::Named(reference) = reference {
+        v.visit_named_reference_mut(reference, cx);
+    }
+}
+
+/// Recurse into the generic arguments or inline type of a [`NamedReference`].
+pub fn visit_named_reference_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    reference: &mut NamedReference,
+    cx: &mut VisitContext<'_>,
+) {
+    match &mut reference.inner {
+        NamedReferenceType::Recursive(_) => {}
+        NamedReferenceType::Inline { dt } => v.visit_datatype_mut(dt, cx),
+        NamedReferenceType::Reference { generics } => {
+            for (generic, dt) in generics {
+                cx.with_segment(PathSegment::GenericArgument(generic.name().clone()), |cx| {
+                    v.visit_datatype_mut(dt, cx)
+                });
+            }
+        }
+    }
+}
+
+/// Recurse into the generic defaults and body of a [`NamedDataType`].
+pub fn visit_named_data_type_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    ndt: &mut NamedDataType,
+    cx: &mut VisitContext<'_>,
+) {
+    for generic in ndt.generics.to_mut() {
+        if let Some(default) = &mut generic.default {
+            cx.with_segment(PathSegment::GenericArgument(generic.name.clone()), |cx| {
+                v.visit_datatype_mut(default, cx)
+            });
+        }
+    }
+    if let Some(ty) = &mut ndt.ty {
+        v.visit_datatype_mut(ty, cx);
+    }
+}
+
+/// Rebuild a [`DataType`] tree by value.
+///
+/// Refer to the [module documentation](self) for more information.
+#[allow(unused_variables)]
+pub trait Fold {
+    /// Fold any [`DataType`].
+    fn fold_datatype(&mut self, dt: DataType, cx: &mut VisitContext<'_>) -> DataType {
+        fold_datatype(self, dt, cx)
+    }
+
+    /// Fold a [`Primitive`].
+    fn fold_primitive(&mut self, primitive: Primitive, cx: &mut VisitContext<'_>) -> DataType {
+        DataType::Primitive(primitive)
+    }
+
+    /// Fold a [`List`].
+    fn fold_list(&mut self, list: List, cx: &mut VisitContext<'_>) -> DataType {
+        fold_list(self, list, cx)
+    }
+
+    /// Fold a [`Map`].
+    fn fold_map(&mut self, map: Map, cx: &mut VisitContext<'_>) -> DataType {
+        fold_map(self, map, cx)
+    }
+
+    /// Fold a [`Struct`].
+    fn fold_struct(&mut self, strct: Struct, cx: &mut VisitContext<'_>) -> DataType {
+        fold_struct(self, strct, cx)
+    }
+
+    /// Fold an [`Enum`].
+    fn fold_enum(&mut self, enm: Enum, cx: &mut VisitContext<'_>) -> DataType {
+        fold_enum(self, enm, cx)
+    }
+
+    /// Fold a [`Variant`] of an [`Enum`].
+    fn fold_variant(
+        &mut self,
+        name: Cow<'static, str>,
+        variant: Variant,
+        cx: &mut VisitContext<'_>,
+    ) -> (Cow<'static, str>, Variant) {
+        fold_variant(self, name, variant, cx)
+    }
+
+    /// Fold the [`Fields`] of a [`Struct`] or [`Variant`].
+    fn fold_fields(&mut self, fields: Fields, cx: &mut VisitContext<'_>) -> Fields {
+        fold_fields(self, fields, cx)
+    }
+
+    /// Fold a [`Field`]. The field's name or index is the last segment of the path.
+    fn fold_field(&mut self, field: Field, cx: &mut VisitContext<'_>) -> Field {
+        fold_field(self, field, cx)
+    }
+
+    /// Fold a [`Tuple`].
+    fn fold_tuple(&mut self, tuple: Tuple, cx: &mut VisitContext<'_>) -> DataType {
+        fold_tuple(self, tuple, cx)
+    }
+
+    /// Fold a [`DataType::Nullable`], given its inner type.
+    fn fold_nullable(&mut self, inner: DataType, cx: &mut VisitContext<'_>) -> DataType {
+        fold_nullable(self, inner, cx)
+    }
+
+    /// Fold a [`DataType::Intersection`], given its members.
+    fn fold_intersection(&mut self, types: Vec<DataType>, cx: &mut VisitContext<'_>) -> DataType {
+        fold_intersection(self, types, cx)
+    }
+
+    /// Fold a [`Generic`] placeholder.
+    fn fold_generic(&mut self, generic: Generic, cx: &mut VisitContext<'_>) -> DataType {
+        DataType::Generic(generic)
+    }
+
+    /// Fold a [`Reference`].
+    fn fold_reference(&mut self, reference: Reference, cx: &mut VisitContext<'_>) -> DataType {
+        fold_reference(self, reference, cx)
+    }
+
+    /// Fold a [`NamedReference`].
+    fn fold_named_reference(
+        &mut self,
+        reference: NamedReference,
+        cx: &mut VisitContext<'_>,
+    ) -> DataType {
+        fold_named_reference(self, reference, cx)
+    }
+
+    /// Fold a [`NamedDataType`].
+    ///
+    /// This is never called while folding a [`DataType`], but can be used to fold each entry of
+    /// [`Types`] with [`Types::map`].
+    fn fold_named_data_type(
+        &mut self,
+        ndt: NamedDataType,
+        cx: &mut VisitContext<'_>,
+    ) -> NamedDataType {
+        fold_named_data_type(self, ndt, cx)
+    }
+}
+
+/// Placeholder used while moving a child out of a node which is rebuilt immediately afterwards.
+fn take(dt: &mut DataType) -> DataType {
+    mem::replace(dt, DataType::Tuple(Tuple::new(Vec::new())))
+}
+
+/// Fold the children of a [`DataType`].
+pub fn fold_datatype<F: Fold + ?Sized>(
+    f: &mut F,
+    dt: DataType,
+    cx: &mut VisitContext<'_>,
+) -> DataType {
+    match dt {
+        DataType::Primitive(primitive) => f.fold_primitive(primitive, cx),
+        DataType::List(list) => f.fold_list(list, cx),
+        DataType::Map(map) => f.fold_map(map, cx),
+        DataType::Struct(strct) => f.fold_struct(strct, cx),
+        DataType::Enum(enm) => f.fold_enum(enm, cx),
+        DataType::Tuple(tuple) => f.fold_tuple(tuple, cx),
+        DataType::Nullable(inner) => f.fold_nullable(*inner, cx),
+        DataType::Intersection(types) => f.fold_intersection(types, cx),
+        DataType::Generic(generic) => f.fold_generic(generic, cx),
+        DataType::Reference(reference) => f.fold_reference(reference, cx),
+    }
+}
+
+/// Fold the element type of a [`List`].
+pub fn fold_list<F: Fold + ?Sized>(
+    f: &mut F,
+    mut list: List,
+    cx: &mut VisitContext<'_>,
+) -> DataType {
+    let ty = take(&mut list.ty);
+    *list.ty = cx.with_segment(PathSegment::ListItem, |cx| f.fold_datatype(ty, cx));
+    DataType::List(list)
+}
+
+/// Fold the key and value types of a [`Map`].
+pub fn fold_map<F: Fold + ?Sized>(f: &mut F, mut map: Map, cx: &mut VisitContext<'_>) -> DataType {
+    let key = take(map.key_ty_mut());
+    map.set_key_ty(cx.with_segment(PathSegment::MapKey, |cx| f.fold_datatype(key, cx)));
+    let value = take(map.value_ty_mut());
+    map.set_value_ty(cx.with_segment(PathSegment::MapValue, |cx| f.fold_datatype(value, cx)));
+    DataType::Map(map)
+}
+
+/// Fold the fields of a [`Struct`].
+pub fn fold_struct<F: Fold + ?Sized>(
+    f: &mut F,
+    mut strct: Struct,
+    cx: &mut VisitContext<'_>,
+) -> DataType {
+    strct.fields = f.fold_fields(mem::replace(&mut strct.fields, Fields::Unit), cx);
+    DataType::Struct(strct)
+}
+
+/// Fold the variants of an [`Enum`].
+pub fn fold_enum<F: Fold + ?Sized>(
+    f: &mut F,
+    mut enm: Enum,
+    cx: &mut VisitContext<'_>,
+) -> DataType {
+    enm.variants = mem::take(&mut enm.variants)
+        .into_iter()
+        .map(|(name, variant)| {
+            cx.with_segment(PathSegment::Variant(name.clone()), |cx| {
+                f.fold_variant(name, variant, cx)
+            })
+        })
+        .collect();
+    DataType::Enum(enm)
+}
+
+/// Fold the fields of a [`Variant`].
+pub fn fold_variant<F: Fold + ?Sized>(
+    f: &mut F,
+    name: Cow<'static, str>,
+    mut variant: Variant,
+    cx: &mut VisitContext<'_>,
+) -> (Cow<'static, str>, Variant) {
+    variant.fields = f.fold_fields(mem::replace(&mut variant.fields, Fields::Unit), cx);
+    (name, variant)
+}
+
+/// Fold each [`Field`] of [`Fields`].
+pub fn fold_fields<F: Fold + ?Sized>(
+    f: &mut F,
+    mut fields: Fields,
+    cx: &mut VisitContext<'_>,
+) -> Fields {
+    match &mut fields {
+        Fields::Unit => {}
+        Fields::Unnamed(unnamed) => {
+            unnamed.fields = mem::take(&mut unnamed.fields)
+                .into_iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    cx.with_segment(PathSegment::Index(index), |cx| f.fold_field(field, cx))
+                })
+                .collect();
+        }
+        Fields::Named(named) => {
+            named.fields = mem::take(&mut named.fields)
+                .into_iter()
+                .map(|(name, field)| {
+                    let field = cx.with_segment(PathSegment::Field(name.clone()), |cx| {
+                        f.fold_field(field, cx)
+                    });
+                    (name, field)
+                })
+                .collect();
+        }
+    }
+    fields
+}
+
+/// Fold the type of a [`Field`], if it is not skipped.
+pub fn fold_field<F: Fold + ?Sized>(
+    f: &mut F,
+    mut field: Field,
+    cx: &mut VisitContext<'_>,
+) -> Field {
+    field.ty = field.ty.map(|ty| f.fold_datatype(ty, cx));
+    field
+}
+
+/// Fold the elements of a [`Tuple`].
+pub fn fold_tuple<F: Fold + ?Sized>(
+    f: &mut F,
+    mut tuple: Tuple,
+    cx: &mut VisitContext<'_>,
+) -> DataType {
+    tuple.elements = mem::take(&mut tuple.elements)
+        .into_iter()
+        .enumerate()
+        .map(|(index, dt)| cx.with_segment(PathSegment::Index(index), |cx| f.fold_datatype(dt, cx)))
+        .collect();
+    DataType::Tuple(tuple)
+}
+
+/// Fold the inner type of a [`DataType::Nullable`].
+pub fn fold_nullable<F: Fold + ?Sized>(
+    f: &mut F,
+    inner: DataType,
+    cx: &mut VisitContext<'_>,
+) -> DataType {
+    DataType::Nullable(Box::new(
+        cx.with_segment(PathSegment::Nullable, |cx| f.fold_datatype(inner, cx)),
+    ))
+}
+
+/// Fold the members of a [`DataType::Intersection`].
+pub fn fold_intersection<F: Fold + ?Sized>(
+    f: &mut F,
+    types: Vec<DataType>,
+    cx: &mut VisitContext<'_>,
+) -> DataType {
+    DataType::Intersection(
+        types
+            .into_iter()
+            .enumerate()
+            .map(|(index, dt)| {
+                cx.with_segment(PathSegment::Intersection(index), |cx| {
+                    f.fold_datatype(dt, cx)
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Fold a [`Reference`]. Opaque references are returned unchanged.
+pub fn fold_reference<F: Fold + ?Sized>(
+    f: &mut F,
+    reference: Reference,
+    cx: &mut VisitContext<'_>,
+) -> DataType {
+    match reference {
+        Reference::Named(reference) => f.fold_named_reference(reference, cx),
+        reference => DataType::Reference(reference),
+    }
+}
+
+/// Fold the generic arguments or inline type of a [`NamedReference`].
+pub fn fold_named_reference<F: Fold + ?Sized>(
+    f: &mut F,
+    mut reference: NamedReference,
+    cx: &mut VisitContext<'_>,
+) -> DataType {
+    match &mut reference.inner {
+        NamedReferenceType::Recursive(_) => {}
+        NamedReferenceType::Inline { dt } => **dt = f.fold_datatype(take(dt), cx),
+        NamedReferenceType::Reference { generics } => {
+            for (generic, dt) in generics {
+                *dt = cx.with_segment(PathSegment::GenericArgument(generic.name().clone()), |cx| {
+                    f.fold_datatype(take(dt), cx)
+                });
+            }
+        }
+    }
+    DataType::Reference(Reference::Named(reference))
+}
+
+/// Fold the generic defaults and body of a [`NamedDataType`].
+pub fn fold_named_data_type<F: Fold + ?Sized>(
+    f: &mut F,
+    mut ndt: NamedDataType,
+    cx: &mut VisitContext<'_>,
+) -> NamedDataType {
+    for generic in ndt.generics.to_mut() {
+        if let Some(default) = generic.default.take() {
+            generic.default = Some(
+                cx.with_segment(PathSegment::GenericArgument(generic.name.clone()), |cx| {
+                    f.fold_datatype(default, cx)
+                }),
+            );
+        }
+    }
+    ndt.ty = ndt.ty.map(|ty| f.fold_datatype(ty, cx));
+    ndt
+}
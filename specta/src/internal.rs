@@ -39,3 +39,38 @@ mod functions {
 }
 #[cfg(feature = "function")]
 pub use functions::*;
+
+#[cfg(feature = "serde_json")]
+mod json {
+    use std::{borrow::Cow, marker::PhantomData};
+
+    use serde::Serialize;
+
+    pub fn to_json<T: Serialize + ?Sized>(value: &T) -> Option<Cow<'static, str>> {
+        serde_json::to_string(value).ok().map(Cow::Owned)
+    }
+
+    // `#[serde(default)]` doesn't guarantee the field implements `Default` and `Serialize`, so
+    // these use autoref specialization to fall back to `None` when it doesn't.
+    pub struct DefaultProbe<T>(pub PhantomData<T>);
+
+    pub trait SerializeDefault {
+        fn json_value(&self) -> Option<Cow<'static, str>>;
+    }
+
+    impl<T: Default + Serialize> SerializeDefault for DefaultProbe<T> {
+        fn json_value(&self) -> Option<Cow<'static, str>> {
+            to_json(&T::default())
+        }
+    }
+
+    pub trait NoSerializeDefault {
+        fn json_value(&self) -> Option<Cow<'static, str>> {
+            None
+        }
+    }
+
+    impl<T> NoSerializeDefault for &DefaultProbe<T> {}
+}
+#[cfg(feature = "serde_json")]
+pub use json::*;
@@ -26,12 +26,15 @@ pub use format::{Format, FormatError};
 #[doc(inline)]
 pub use r#type::Type;
 #[doc(inline)]
-pub use types::Types;
+pub use types::{
+    Monomorphise, NameCollisionError, NameCollisions, NameResolution, ResolveNames, Selection,
+    SelectionError, TypeGraph, Types,
+};
 
 #[doc(inline)]
 #[cfg(feature = "collect")]
 #[cfg_attr(docsrs, doc(cfg(feature = "collect")))]
-pub use collect::collect;
+pub use collect::{collect, collect_group};
 
 #[doc(inline)]
 #[cfg(feature = "derive")]
@@ -1,5 +1,5 @@
 use std::{
-    collections::{HashMap, hash_map},
+    collections::{HashMap, HashSet, hash_map},
     fmt,
 };
 
@@ -8,6 +8,16 @@ use crate::{
     datatype::{DataType, NamedDataType, NamedId, NamedReference, RecursiveInlineFrame},
 };
 
+mod graph;
+mod monomorphise;
+mod names;
+mod select;
+
+pub use graph::TypeGraph;
+pub use monomorphise::Monomorphise;
+pub use names::{NameCollisionError, NameCollisions, NameResolution, ResolveNames};
+pub use select::{Selection, SelectionError};
+
 /// Collection of named datatypes that can be exported together.
 ///
 /// Resolving a [`Type`] adds every named type it depends on to this collection.
@@ -252,6 +262,43 @@ impl Types {
         }
     }
 
+    /// Builds the reference graph between the named datatypes in the collection.
+    ///
+    /// This can be used to order types by their dependencies, find recursive types or look up
+    /// which types use a given type.
+    pub fn graph(&self) -> TypeGraph<'_> {
+        TypeGraph::new(self)
+    }
+
+    /// Removes every named datatype which is not reachable from a type matching `is_root`.
+    ///
+    /// Types matching `is_root` are kept along with everything they transitively depend on.
+    /// Explicitly registered [roots](Types::roots) are kept only if every named type they
+    /// refer to is kept.
+    pub fn retain_reachable<F>(mut self, is_root: F) -> Self
+    where
+        F: FnMut(&NamedDataType) -> bool,
+    {
+        let reachable = self.graph().reachable_ids(is_root);
+        self.retain_ids(&reachable);
+        self
+    }
+
+    /// Selects a subset of the collection along with everything it transitively depends on.
+    ///
+    /// Refer to [`Selection`] for how types are selected. Explicitly registered
+    /// [roots](Types::roots) are kept only if every named type they refer to is kept.
+    pub fn select(&self, selection: &Selection) -> Result<Self, SelectionError> {
+        select::select(self, selection)
+    }
+
+    /// Renames types so every type has a unique export name.
+    ///
+    /// Refer to [`NameResolution`] for how names are resolved.
+    pub fn resolve_names(&self, resolution: &NameResolution) -> Result<Self, NameCollisionError> {
+        resolution.resolve(self)
+    }
+
     /// Transforms each [`NamedDataType`] in the collection.
     pub fn map<F>(mut self, mut f: F) -> Self
     where
@@ -264,6 +311,17 @@ impl Types {
         }
         self
     }
+
+    fn retain_ids(&mut self, ids: &HashSet<NamedId>) {
+        self.types
+            .retain(|id, ndt| ndt.is_some() && ids.contains(id));
+        self.len = self.types.len();
+        self.roots.retain(|root| {
+            graph::referenced_ids(root)
+                .iter()
+                .all(|id| self.types.contains_key(id))
+        });
+    }
 }
 
 struct UnsortedIter<'a> {
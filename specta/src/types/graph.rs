@@ -0,0 +1,312 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    Types,
+    datatype::{
+        DataType, NamedDataType, NamedId, NamedReference, NamedReferenceType, Visit, VisitContext,
+        visit::{visit_datatype, visit_named_reference},
+    },
+};
+
+/// Reference graph between the [`NamedDataType`]s of a [`Types`] collection.
+///
+/// An edge from `A` to `B` means the definition of `A` refers to `B`, including through generic
+/// arguments, inlined references and recursive inline references.
+///
+/// Built with [`Types::graph`]. All methods return types in a deterministic order, matching
+/// [`Types::into_sorted_iter`] where no other order applies.
+#[derive(Debug, Clone)]
+pub struct TypeGraph<'a> {
+    nodes: Vec<&'a NamedDataType>,
+    index: HashMap<NamedId, usize>,
+    dependencies: Vec<Vec<usize>>,
+    dependents: Vec<Vec<usize>>,
+    /// Strongly connected components, in dependency order.
+    components: Vec<Vec<usize>>,
+    /// Index into `components` of the component containing each node.
+    component: Vec<usize>,
+}
+
+impl<'a> TypeGraph<'a> {
+    pub(crate) fn new(types: &'a Types) -> Self {
+        let nodes = types.into_sorted_iter().collect::<Vec<_>>();
+        let index = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, ndt)| (ndt.id.clone(), i))
+            .collect::<HashMap<_, _>>();
+
+        let mut dependencies = Vec::with_capacity(nodes.len());
+        let mut dependents = vec![Vec::new(); nodes.len()];
+        for (i, ndt) in nodes.iter().enumerate() {
+            let mut collector = Edges {
+                index: &index,
+                out: Vec::new(),
+            };
+            collector.visit_named_data_type(ndt, &mut VisitContext::new());
+
+            let mut edges = collector.out;
+            edges.sort_unstable();
+            edges.dedup();
+            for &dependency in &edges {
+                dependents[dependency].push(i);
+            }
+            dependencies.push(edges);
+        }
+
+        let components = strongly_connected_components(&dependencies);
+        let mut component = vec![0; nodes.len()];
+        for (c, members) in components.iter().enumerate() {
+            for &i in members {
+                component[i] = c;
+            }
+        }
+
+        Self {
+            nodes,
+            index,
+            dependencies,
+            dependents,
+            components,
+            component,
+        }
+    }
+
+    /// Returns every type in the graph.
+    pub fn types(&self) -> impl ExactSizeIterator<Item = &'a NamedDataType> + '_ {
+        self.nodes.iter().copied()
+    }
+
+    /// Returns the types directly referenced by `ndt`.
+    ///
+    /// Returns nothing if `ndt` is not part of the graph.
+    pub fn dependencies(
+        &self,
+        ndt: &NamedDataType,
+    ) -> impl Iterator<Item = &'a NamedDataType> + '_ {
+        self.edges(&self.dependencies, ndt)
+    }
+
+    /// Returns the types which directly reference `ndt`.
+    ///
+    /// Returns nothing if `ndt` is not part of the graph.
+    pub fn dependents(&self, ndt: &NamedDataType) -> impl Iterator<Item = &'a NamedDataType> + '_ {
+        self.edges(&self.dependents, ndt)
+    }
+
+    /// Returns `true` if `ndt` refers to itself, either directly or through other types.
+    pub fn is_recursive(&self, ndt: &NamedDataType) -> bool {
+        let Some(&i) = self.index.get(&ndt.id) else {
+            return false;
+        };
+
+        self.dependencies[i].contains(&i) || self.components[self.component[i]].len() > 1
+    }
+
+    /// Groups the types into strongly connected components.
+    ///
+    /// Every component is a set of types which all (transitively) refer to each other, or a single
+    /// type. Components are returned in dependency order, so a component only refers to itself or
+    /// to components earlier in the list.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&'a NamedDataType>> {
+        self.components
+            .iter()
+            .map(|component| component.iter().map(|&i| self.nodes[i]).collect())
+            .collect()
+    }
+
+    /// Returns the components of [`TypeGraph::strongly_connected_components`] which contain a
+    /// cycle, being mutually recursive types or a single type which refers to itself.
+    pub fn cycles(&self) -> Vec<Vec<&'a NamedDataType>> {
+        self.components
+            .iter()
+            .filter(|component| match component.as_slice() {
+                [i] => self.dependencies[*i].contains(i),
+                _ => true,
+            })
+            .map(|component| component.iter().map(|&i| self.nodes[i]).collect())
+            .collect()
+    }
+
+    /// Returns every type with its dependencies ordered before it.
+    ///
+    /// Types within a cycle cannot be ordered and are kept next to each other.
+    pub fn topological_order(&self) -> Vec<&'a NamedDataType> {
+        self.components
+            .iter()
+            .flatten()
+            .map(|&i| self.nodes[i])
+            .collect()
+    }
+
+    /// Returns the `roots` and every type they transitively depend on.
+    ///
+    /// Roots which are not part of the graph are ignored.
+    pub fn reachable<'b>(
+        &self,
+        roots: impl IntoIterator<Item = &'b NamedDataType>,
+    ) -> Vec<&'a NamedDataType> {
+        self.reachable_indices(
+            roots
+                .into_iter()
+                .filter_map(|ndt| self.index.get(&ndt.id).copied()),
+        )
+        .into_iter()
+        .enumerate()
+        .filter(|(_, reachable)| *reachable)
+        .map(|(i, _)| self.nodes[i])
+        .collect()
+    }
+
+    pub(crate) fn reachable_ids(
+        &self,
+        mut is_root: impl FnMut(&NamedDataType) -> bool,
+    ) -> HashSet<NamedId> {
+        self.reachable_indices((0..self.nodes.len()).filter(|&i| is_root(self.nodes[i])))
+            .into_iter()
+            .enumerate()
+            .filter(|(_, reachable)| *reachable)
+            .map(|(i, _)| self.nodes[i].id.clone())
+            .collect()
+    }
+
+    fn edges<'b>(
+        &'b self,
+        edges: &'b [Vec<usize>],
+        ndt: &NamedDataType,
+    ) -> impl Iterator<Item = &'a NamedDataType> + 'b {
+        self.index
+            .get(&ndt.id)
+            .map(|&i| edges[i].as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|&i| self.nodes[i])
+    }
+
+    fn reachable_indices(&self, roots: impl IntoIterator<Item = usize>) -> Vec<bool> {
+        let mut reachable = vec![false; self.nodes.len()];
+        let mut stack = roots.into_iter().collect::<Vec<_>>();
+        while let Some(i) = stack.pop() {
+            if !reachable[i] {
+                reachable[i] = true;
+                stack.extend(&self.dependencies[i]);
+            }
+        }
+        reachable
+    }
+}
+
+/// Tarjan's algorithm, without recursion so deep dependency chains can't overflow the stack.
+fn strongly_connected_components(dependencies: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let len = dependencies.len();
+    let mut next_index = 0;
+    let mut indices = vec![None; len];
+    let mut lowlinks = vec![0; len];
+    let mut on_stack = vec![false; len];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+
+    for start in 0..len {
+        if indices[start].is_some() {
+            continue;
+        }
+
+        let mut calls = vec![(start, 0)];
+        indices[start] = Some(next_index);
+        lowlinks[start] = next_index;
+        next_index += 1;
+        stack.push(start);
+        on_stack[start] = true;
+
+        while let Some((node, edge)) = calls.last_mut() {
+            let node = *node;
+            if let Some(&dependency) = dependencies[node].get(*edge) {
+                *edge += 1;
+                match indices[dependency] {
+                    None => {
+                        indices[dependency] = Some(next_index);
+                        lowlinks[dependency] = next_index;
+                        next_index += 1;
+                        stack.push(dependency);
+                        on_stack[dependency] = true;
+                        calls.push((dependency, 0));
+                    }
+                    Some(index) if on_stack[dependency] => {
+                        lowlinks[node] = lowlinks[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                lowlinks[parent] = lowlinks[parent].min(lowlinks[node]);
+            }
+
+            if Some(lowlinks[node]) == indices[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+/// Collects the named types referenced by a definition.
+struct Edges<'b> {
+    index: &'b HashMap<NamedId, usize>,
+    out: Vec<usize>,
+}
+
+impl<'a> Visit<'a> for Edges<'_> {
+    fn visit_named_reference(&mut self, reference: &'a NamedReference, cx: &mut VisitContext<'a>) {
+        match &reference.inner {
+            NamedReferenceType::Inline { dt } => {
+                self.out.extend(self.index.get(&reference.id));
+                visit_datatype(self, dt, cx);
+            }
+            NamedReferenceType::Reference { generics } => {
+                self.out.extend(self.index.get(&reference.id));
+                for (_, dt) in generics {
+                    self.visit_datatype(dt, cx);
+                }
+            }
+            NamedReferenceType::Recursive(recursive) => {
+                self.out.extend(self.index.get(&reference.id));
+                for (_, dt) in recursive.generics() {
+                    self.visit_datatype(dt, cx);
+                }
+            }
+        }
+    }
+}
+
+/// Collects the identities of every named type referenced by a [`DataType`].
+pub(crate) fn referenced_ids(dt: &DataType) -> Vec<NamedId> {
+    struct Ids(Vec<NamedId>);
+
+    impl<'a> Visit<'a> for Ids {
+        fn visit_named_reference(
+            &mut self,
+            reference: &'a NamedReference,
+            cx: &mut VisitContext<'a>,
+        ) {
+            self.0.push(reference.id.clone());
+            visit_named_reference(self, reference, cx);
+        }
+    }
+
+    let mut ids = Ids(Vec::new());
+    ids.visit_datatype(dt, &mut VisitContext::new());
+    ids.0
+}
@@ -0,0 +1,374 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt,
+    sync::Arc,
+};
+
+use super::names::{collisions, path};
+use crate::{
+    Format, FormatError, NameCollisionError, Types,
+    datatype::{
+        DataType, Generic, NamedDataType, NamedId, NamedReference, NamedReferenceType, Reference,
+        VisitContext, VisitMut,
+        visit::{visit_datatype_mut, visit_named_reference_mut},
+    },
+};
+
+type Naming = dyn Fn(&NamedDataType, &[String]) -> String + Send + Sync;
+
+/// [`Format`] wrapper which instantiates every generic named type with the concrete arguments it is used with.
+///
+/// This is for languages and formats without generics. Each use of `Foo<String>` is rewritten to
+/// reference a new non-generic `FooString` type and the generic `Foo<T>` definition is removed, so
+/// `Foo<String>` and `Foo<u32>` are exported as two separate types.
+///
+/// Returns a [`NameCollisionError`] if an instantiation is named the same as another type, like
+/// `Foo<Struct>` for two different anonymous structs.
+///
+/// The wrapped format is applied first, then the collection is monomorphised.
+///
+/// By default the name of an instantiation is the name of the generic type followed by the names of its
+/// arguments, like `FooString` or `PageListUser`. Use [`Monomorphise::naming`] to customise this.
+///
+/// # Example
+///
+/// ```rust
+/// use specta::{Format, Monomorphise, Type, Types};
+/// # struct Identity;
+/// # impl Format for Identity {
+/// #     fn map_types(&self, types: &Types) -> Result<std::borrow::Cow<'_, Types>, specta::FormatError> {
+/// #         Ok(std::borrow::Cow::Owned(types.clone()))
+/// #     }
+/// #     fn map_type(&self, _: &Types, dt: &specta::datatype::DataType) -> Result<std::borrow::Cow<'_, specta::datatype::DataType>, specta::FormatError> {
+/// #         Ok(std::borrow::Cow::Owned(dt.clone()))
+/// #     }
+/// # }
+///
+/// #[derive(Type)]
+/// #[specta(collect = false)]
+/// struct Page<T> {
+///     items: Vec<T>,
+/// }
+///
+/// #[derive(Type)]
+/// #[specta(collect = false)]
+/// struct Response {
+///     users: Page<String>,
+///     ids: Page<u32>,
+/// }
+///
+/// let types = Types::default().register::<Response>();
+/// let types = Monomorphise::new(Identity).map_types(&types).unwrap().into_owned();
+///
+/// let mut names = types.into_sorted_iter().map(|ndt| ndt.name.as_ref()).collect::<Vec<_>>();
+/// names.retain(|name| name.starts_with("Page"));
+/// assert_eq!(names, ["PageString", "PageU32"]);
+/// ```
+#[derive(Clone)]
+pub struct Monomorphise<F> {
+    format: F,
+    naming: Option<Arc<Naming>>,
+}
+
+impl<F: fmt::Debug> fmt::Debug for Monomorphise<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Monomorphise")
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> Monomorphise<F> {
+    /// Wraps a format so its output is monomorphised.
+    pub fn new(format: F) -> Self {
+        Self {
+            format,
+            naming: None,
+        }
+    }
+
+    /// Configure how instantiated types are named.
+    ///
+    /// The callback receives the generic type and the default name of each of its arguments, being
+    /// `String` for `String`, `ListU32` for `Vec<u32>`, or the name of a named type. Names should be
+    /// deterministic and unique, as an instantiation with the same name as another type is a
+    /// [`NameCollisionError`].
+    ///
+    /// ```rust
+    /// # use specta::Monomorphise;
+    /// # struct Identity;
+    /// let format = Monomorphise::new(Identity)
+    ///     .naming(|ndt, arguments| format!("{}_{}", ndt.name, arguments.join("_")));
+    /// ```
+    pub fn naming(
+        mut self,
+        naming: impl Fn(&NamedDataType, &[String]) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.naming = Some(Arc::new(naming));
+        self
+    }
+
+    fn name(&self, ndt: &NamedDataType, arguments: &[String]) -> String {
+        match &self.naming {
+            Some(naming) => naming(ndt, arguments),
+            None => format!("{}{}", ndt.name, arguments.concat()),
+        }
+    }
+}
+
+impl<F: Format> Format for Monomorphise<F> {
+    fn map_types(&'_ self, types: &Types) -> Result<Cow<'_, Types>, FormatError> {
+        let types = self.format.map_types(types)?;
+        Ok(Cow::Owned(monomorphise(types.as_ref(), self)?))
+    }
+
+    fn map_type(&'_ self, types: &Types, dt: &DataType) -> Result<Cow<'_, DataType>, FormatError> {
+        let mut dt = self.format.map_type(types, dt)?.into_owned();
+        Rewrite(types).visit_datatype_mut(&mut dt, &mut VisitContext::new());
+        Ok(Cow::Owned(dt))
+    }
+}
+
+fn monomorphise<F>(types: &Types, config: &Monomorphise<F>) -> Result<Types, NameCollisionError> {
+    let mut types = types.clone();
+    let names = types
+        .types
+        .iter()
+        .filter_map(|(id, ndt)| Some((id.clone(), ndt.as_ref()?.name.to_string())))
+        .collect();
+
+    let mut definitions = HashMap::new();
+    types.types.retain(|id, ndt| match ndt {
+        Some(ndt) if !ndt.generics.is_empty() => {
+            definitions.insert(id.clone(), ndt.clone());
+            false
+        }
+        _ => true,
+    });
+    types.len = types.types.values().flatten().count();
+
+    let mut instantiate = Instantiate {
+        names,
+        definitions: &definitions,
+        created: HashSet::new(),
+        pending: Vec::new(),
+        naming: &|ndt, arguments| config.name(ndt, arguments),
+    };
+    let cx = &mut VisitContext::new();
+    for ndt in types.types.values_mut().flatten() {
+        instantiate.visit_named_data_type_mut(ndt, cx);
+    }
+    for root in &mut types.roots {
+        instantiate.visit_datatype_mut(root, cx);
+    }
+
+    // Instantiations can refer to further generic types, which are instantiated in turn.
+    while let Some(mut ndt) = instantiate.pending.pop() {
+        instantiate.visit_named_data_type_mut(&mut ndt, cx);
+        types.types.insert(ndt.id.clone(), Some(ndt));
+        types.len += 1;
+    }
+
+    // Arguments are named by their shape, so different instantiations can end up with the same name.
+    let names = types
+        .into_unsorted_iter()
+        .map(|ndt| (ndt.id.clone(), ndt.name.clone()))
+        .collect();
+    if let Some(group) = collisions(&types, &names).into_iter().find(|group| {
+        group
+            .iter()
+            .any(|ndt| matches!(ndt.id, NamedId::Instance(_)))
+    }) {
+        return Err(NameCollisionError {
+            name: group[0].name.to_string(),
+            paths: group.iter().map(|ndt| path(ndt)).collect(),
+        });
+    }
+
+    Ok(types)
+}
+
+fn instance_id(id: &NamedId, generics: &[(Generic, DataType)]) -> NamedId {
+    NamedId::Instance(Arc::new((
+        id.clone(),
+        generics.iter().map(|(_, dt)| dt.clone()).collect(),
+    )))
+}
+
+/// Rewrites references to generic types into references to their instantiations, creating the
+/// instantiations as they are encountered.
+struct Instantiate<'a> {
+    /// Name of every named type, for naming the arguments of instantiations.
+    names: HashMap<NamedId, String>,
+    definitions: &'a HashMap<NamedId, NamedDataType>,
+    created: HashSet<NamedId>,
+    /// Instantiations which have not had their own references rewritten yet.
+    pending: Vec<NamedDataType>,
+    naming: &'a dyn Fn(&NamedDataType, &[String]) -> String,
+}
+
+impl VisitMut for Instantiate<'_> {
+    fn visit_named_reference_mut(
+        &mut self,
+        reference: &mut NamedReference,
+        cx: &mut VisitContext<'_>,
+    ) {
+        visit_named_reference_mut(self, reference, cx);
+
+        let arguments = match &reference.inner {
+            NamedReferenceType::Reference { generics } => generics.clone(),
+            // A recursive reference is to a type which is being inlined, so it can only be
+            // replaced if the type has a definition to refer to.
+            NamedReferenceType::Recursive(recursive)
+                if self
+                    .definitions
+                    .get(&reference.id)
+                    .is_some_and(|ndt| ndt.ty.is_some()) =>
+            {
+                let mut generics = recursive.generics().to_vec();
+                for (_, dt) in &mut generics {
+                    self.visit_datatype_mut(dt, cx);
+                }
+                generics
+            }
+            _ => return,
+        };
+        let Some(definition) = self.definitions.get(&reference.id) else {
+            return;
+        };
+
+        let id = instance_id(&reference.id, &arguments);
+        if self.created.insert(id.clone()) {
+            let substitutions = definition
+                .generics
+                .iter()
+                .filter_map(|generic| {
+                    let dt = arguments
+                        .iter()
+                        .find(|(argument, _)| *argument.name() == generic.name)
+                        .map(|(_, dt)| dt.clone())
+                        .or_else(|| generic.default.clone())?;
+                    Some((generic.reference(), dt))
+                })
+                .collect::<Vec<_>>();
+            let argument_names = arguments
+                .iter()
+                .map(|(_, dt)| argument_name(dt, &self.names))
+                .collect::<Vec<_>>();
+
+            let mut ndt = definition.clone();
+            ndt.id = id.clone();
+            ndt.name = (self.naming)(definition, &argument_names).into();
+            ndt.generics = Cow::Borrowed(&[]);
+            if let Some(ty) = &mut ndt.ty {
+                Substitute(&substitutions).visit_datatype_mut(ty, &mut VisitContext::new());
+            }
+
+            self.names.insert(id.clone(), ndt.name.to_string());
+            self.pending.push(ndt);
+        }
+
+        reference.id = id;
+        reference.inner = NamedReferenceType::Reference {
+            generics: Vec::new(),
+        };
+    }
+}
+
+/// Rewrites references into references to instantiations which already exist in the collection.
+struct Rewrite<'a>(&'a Types);
+
+impl VisitMut for Rewrite<'_> {
+    fn visit_named_reference_mut(
+        &mut self,
+        reference: &mut NamedReference,
+        cx: &mut VisitContext<'_>,
+    ) {
+        visit_named_reference_mut(self, reference, cx);
+
+        if self.0.get(reference).is_none()
+            && let NamedReferenceType::Reference { generics } = &mut reference.inner
+        {
+            let id = instance_id(&reference.id, generics);
+            if self.0.types.contains_key(&id) {
+                reference.id = id;
+                generics.clear();
+            }
+        }
+    }
+}
+
+/// Replaces generic placeholders with their arguments.
+struct Substitute<'a>(&'a [(Generic, DataType)]);
+
+impl VisitMut for Substitute<'_> {
+    fn visit_datatype_mut(&mut self, dt: &mut DataType, cx: &mut VisitContext<'_>) {
+        if let DataType::Generic(generic) = dt {
+            if let Some((_, argument)) = self.0.iter().find(|(g, _)| g == generic) {
+                *dt = argument.clone();
+            }
+            return;
+        }
+
+        visit_datatype_mut(self, dt, cx);
+    }
+
+    fn visit_named_reference_mut(
+        &mut self,
+        reference: &mut NamedReference,
+        cx: &mut VisitContext<'_>,
+    ) {
+        if let NamedReferenceType::Recursive(recursive) = &mut reference.inner {
+            let mut generics = recursive.generics().to_vec();
+            for (_, dt) in &mut generics {
+                self.visit_datatype_mut(dt, cx);
+            }
+            *recursive = recursive.with_generics(generics);
+        }
+
+        visit_named_reference_mut(self, reference, cx);
+    }
+}
+
+/// The default name of a generic argument, used to name instantiations.
+fn argument_name(dt: &DataType, names: &HashMap<NamedId, String>) -> String {
+    let name = |dt| argument_name(dt, names);
+    match dt {
+        DataType::Primitive(primitive) => {
+            let primitive = format!("{primitive:?}");
+            if primitive == "str" {
+                "String".into()
+            } else {
+                let mut chars = primitive.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            }
+        }
+        DataType::List(list) => format!("List{}", name(&list.ty)),
+        DataType::Map(map) => format!("Map{}{}", name(map.key_ty()), name(map.value_ty())),
+        DataType::Nullable(inner) => format!("Option{}", name(inner)),
+        DataType::Tuple(tuple) if tuple.elements.is_empty() => "Unit".into(),
+        DataType::Tuple(tuple) => {
+            format!(
+                "Tuple{}",
+                tuple.elements.iter().map(name).collect::<String>()
+            )
+        }
+        DataType::Struct(_) => "Struct".into(),
+        DataType::Enum(_) => "Enum".into(),
+        DataType::Intersection(_) => "Intersection".into(),
+        DataType::Generic(generic) => generic.name().to_string(),
+        DataType::Reference(Reference::Named(reference)) => match &reference.inner {
+            // Inlined wrappers, like `Vec<T>`, are named by what they expand to.
+            NamedReferenceType::Inline { dt } => name(dt),
+            _ => names
+                .get(&reference.id)
+                .cloned()
+                .unwrap_or_else(|| "Unknown".into()),
+        },
+        DataType::Reference(Reference::Opaque(_)) => "Opaque".into(),
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    error, fmt,
+};
+
+use crate::{
+    Format, FormatError, Types,
+    datatype::{DataType, NamedDataType, NamedId},
+};
+
+/// How [`NameResolution`] handles multiple types with the same export name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NameCollisions {
+    /// Return a [`NameCollisionError`].
+    #[default]
+    Error,
+    /// Prefix only the colliding names with as many trailing segments of their module path as
+    /// needed to tell them apart, so `billing::Error` and `auth::Error` become `BillingError` and
+    /// `AuthError`.
+    ///
+    /// Types which can't be told apart by their module path are still an error.
+    ModulePrefix,
+}
+
+/// Resolves the names types are exported with, so multiple types with the same name can be exported together.
+///
+/// Exporters with a flat layout require every exported name to be unique, but Rust only requires
+/// it within a module. This renames types before they're exported, which unlike
+/// `#[serde(rename = "...")]` doesn't affect the serialized format, including the tag names of
+/// internally tagged types.
+///
+/// Explicit renames are applied first, then any remaining collisions are handled according to
+/// [`NameResolution::collisions`].
+///
+/// Use [`Types::resolve_names`] to apply it to a collection, or [`ResolveNames`] to apply it
+/// after a [`Format`].
+#[derive(Debug, Clone, Default)]
+pub struct NameResolution {
+    renames: HashMap<Cow<'static, str>, Cow<'static, str>>,
+    collisions: NameCollisions,
+}
+
+impl NameResolution {
+    /// Constructs a new name resolution which errors on any collision.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Export the type at `path`, like `my_crate::billing::Error`, as `name`.
+    pub fn rename(
+        mut self,
+        path: impl Into<Cow<'static, str>>,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.renames.insert(path.into(), name.into());
+        self
+    }
+
+    /// Configure how collisions are handled.
+    pub fn collisions(mut self, collisions: NameCollisions) -> Self {
+        self.collisions = collisions;
+        self
+    }
+
+    pub(crate) fn resolve(&self, types: &Types) -> Result<Types, NameCollisionError> {
+        let mut names = types
+            .into_unsorted_iter()
+            .map(|ndt| {
+                let name = self
+                    .renames
+                    .get(path(ndt).as_str())
+                    .unwrap_or(&ndt.name)
+                    .clone();
+                (ndt.id.clone(), name)
+            })
+            .collect::<HashMap<_, _>>();
+
+        for group in collisions(types, &names) {
+            let resolved = match self.collisions {
+                NameCollisions::Error => None,
+                NameCollisions::ModulePrefix => module_prefixed(&group, &names),
+            };
+            let Some(resolved) = resolved else {
+                return Err(NameCollisionError {
+                    name: names[&group[0].id].to_string(),
+                    paths: group.iter().map(|ndt| path(ndt)).collect(),
+                });
+            };
+
+            for (ndt, name) in group.iter().zip(resolved) {
+                names.insert(ndt.id.clone(), name.into());
+            }
+        }
+
+        let mut types = types.clone();
+        types.iter_mut(|ndt| {
+            if let Some(name) = names.remove(&ndt.id) {
+                ndt.name = name;
+            }
+        });
+        Ok(types)
+    }
+}
+
+/// Error returned when multiple types would be exported with the same name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NameCollisionError {
+    /// The export name which collides.
+    pub name: String,
+    /// Paths of every type with the name, like `my_crate::billing::Error`.
+    pub paths: Vec<String>,
+}
+
+impl fmt::Display for NameCollisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "multiple types are exported with the name `{}`: {}",
+            self.name,
+            self.paths.join(", ")
+        )
+    }
+}
+
+impl error::Error for NameCollisionError {}
+
+/// [`Format`] wrapper which applies a [`NameResolution`] after the wrapped format.
+///
+/// As the wrapped format runs first, anything it derives from type names, like the tags of
+/// internally tagged enums, is unaffected by the renames.
+#[derive(Debug, Clone)]
+pub struct ResolveNames<F> {
+    format: F,
+    resolution: NameResolution,
+}
+
+impl<F> ResolveNames<F> {
+    /// Wraps a format so the names of its output are resolved with `resolution`.
+    pub fn new(format: F, resolution: NameResolution) -> Self {
+        Self { format, resolution }
+    }
+}
+
+impl<F: Format> Format for ResolveNames<F> {
+    fn map_types(&'_ self, types: &Types) -> Result<Cow<'_, Types>, FormatError> {
+        let types = self.format.map_types(types)?;
+        Ok(Cow::Owned(self.resolution.resolve(types.as_ref())?))
+    }
+
+    fn map_type(&'_ self, types: &Types, dt: &DataType) -> Result<Cow<'_, DataType>, FormatError> {
+        // References are by identity, so renaming never affects individual datatypes.
+        self.format.map_type(types, dt)
+    }
+}
+
+pub(super) fn path(ndt: &NamedDataType) -> String {
+    if ndt.module_path.is_empty() {
+        ndt.name.to_string()
+    } else {
+        format!("{}::{}", ndt.module_path, ndt.name)
+    }
+}
+
+/// Groups the types which share a name, in a deterministic order.
+pub(super) fn collisions<'a>(
+    types: &'a Types,
+    names: &HashMap<NamedId, Cow<'static, str>>,
+) -> Vec<Vec<&'a NamedDataType>> {
+    let mut groups = BTreeMap::<&str, Vec<&NamedDataType>>::new();
+    for ndt in types.into_sorted_iter() {
+        groups.entry(&names[&ndt.id]).or_default().push(ndt);
+    }
+    groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect()
+}
+
+/// Prefixes each name with the fewest trailing module path segments which make them unique,
+/// without colliding with any other name.
+fn module_prefixed(
+    group: &[&NamedDataType],
+    names: &HashMap<NamedId, Cow<'static, str>>,
+) -> Option<Vec<String>> {
+    let depth = group
+        .iter()
+        .map(|ndt| ndt.module_path.split("::").count())
+        .max()?;
+
+    (1..=depth).find_map(|depth| {
+        let prefixed = group
+            .iter()
+            .map(|ndt| {
+                let segments = ndt.module_path.split("::").collect::<Vec<_>>();
+                let prefix = segments[segments.len().saturating_sub(depth)..]
+                    .iter()
+                    .map(|segment| pascal_case(segment))
+                    .collect::<String>();
+                format!("{prefix}{}", names[&ndt.id])
+            })
+            .collect::<Vec<_>>();
+
+        let unique = prefixed
+            .iter()
+            .enumerate()
+            .all(|(i, name)| !prefixed[..i].contains(name));
+        let taken = prefixed
+            .iter()
+            .any(|name| names.values().any(|other| other == name));
+        (unique && !taken).then_some(prefixed)
+    })
+}
+
+fn pascal_case(segment: &str) -> String {
+    segment
+        .split('_')
+        .filter_map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next()?;
+            Some(first.to_ascii_uppercase().to_string() + chars.as_str())
+        })
+        .collect()
+}
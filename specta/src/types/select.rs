@@ -0,0 +1,238 @@
+use std::{borrow::Cow, collections::HashSet, error, fmt, sync::Arc};
+
+use crate::{Types, datatype::NamedDataType};
+
+/// Selects a subset of a [`Types`] collection, such as the types for a single bindings package.
+///
+/// A type is selected when it matches any of the included filters and none of the excluded
+/// filters. When no included filters are configured every type which isn't excluded is selected.
+///
+/// [`Types::select`] returns the selected types along with everything they transitively depend
+/// on, so the result can always be exported on its own. It is an error for a selected type to
+/// depend on an excluded type.
+///
+/// ```rust
+/// use specta::{Selection, Type, Types};
+///
+/// #[derive(Type)]
+/// #[specta(selection_tag = "public", collect = false)]
+/// struct User {
+///     id: u32,
+/// }
+///
+/// #[derive(Type)]
+/// #[specta(collect = false)]
+/// struct AuditLog {
+///     user: User,
+/// }
+///
+/// let types = Types::default().register::<AuditLog>();
+/// let public = types.select(&Selection::new().tag("public")).unwrap();
+/// assert_eq!(public.len(), 1);
+/// ```
+#[derive(Default, Clone)]
+pub struct Selection {
+    include: Vec<Filter>,
+    exclude: Vec<Filter>,
+}
+
+#[derive(Clone)]
+enum Filter {
+    Module(Cow<'static, str>),
+    Name(Cow<'static, str>),
+    Tag(Cow<'static, str>),
+    Predicate(Arc<dyn Fn(&NamedDataType) -> bool + Send + Sync>),
+}
+
+impl Filter {
+    fn matches(&self, ndt: &NamedDataType) -> bool {
+        match self {
+            Self::Module(prefix) => ndt
+                .module_path
+                .strip_prefix(prefix.as_ref())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::")),
+            Self::Name(pattern) => glob(pattern, &ndt.name),
+            Self::Tag(tag) => ndt.tags.iter().any(|t| t == tag),
+            Self::Predicate(predicate) => predicate(ndt),
+        }
+    }
+}
+
+impl fmt::Debug for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Module(prefix) => f.debug_tuple("Module").field(prefix).finish(),
+            Self::Name(pattern) => f.debug_tuple("Name").field(pattern).finish(),
+            Self::Tag(tag) => f.debug_tuple("Tag").field(tag).finish(),
+            Self::Predicate(_) => f.write_str("Predicate"),
+        }
+    }
+}
+
+impl fmt::Debug for Selection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Selection")
+            .field("include", &self.include)
+            .field("exclude", &self.exclude)
+            .finish()
+    }
+}
+
+impl Selection {
+    /// Constructs a new selection which selects every type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select types defined in the module `prefix` or any of its submodules, like `my_crate::api`.
+    pub fn module(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.include.push(Filter::Module(prefix.into()));
+        self
+    }
+
+    /// Select types with a name matching `pattern`, where `*` matches any sequence of characters.
+    pub fn name(mut self, pattern: impl Into<Cow<'static, str>>) -> Self {
+        self.include.push(Filter::Name(pattern.into()));
+        self
+    }
+
+    /// Select types tagged with `#[specta(selection_tag = "...")]`.
+    pub fn tag(mut self, tag: impl Into<Cow<'static, str>>) -> Self {
+        self.include.push(Filter::Tag(tag.into()));
+        self
+    }
+
+    /// Select types matching a custom predicate.
+    pub fn filter(
+        mut self,
+        predicate: impl Fn(&NamedDataType) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.include.push(Filter::Predicate(Arc::new(predicate)));
+        self
+    }
+
+    /// Exclude types defined in the module `prefix` or any of its submodules.
+    pub fn exclude_module(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.exclude.push(Filter::Module(prefix.into()));
+        self
+    }
+
+    /// Exclude types with a name matching `pattern`, where `*` matches any sequence of characters.
+    pub fn exclude_name(mut self, pattern: impl Into<Cow<'static, str>>) -> Self {
+        self.exclude.push(Filter::Name(pattern.into()));
+        self
+    }
+
+    /// Exclude types tagged with `#[specta(selection_tag = "...")]`.
+    pub fn exclude_tag(mut self, tag: impl Into<Cow<'static, str>>) -> Self {
+        self.exclude.push(Filter::Tag(tag.into()));
+        self
+    }
+
+    /// Exclude types matching a custom predicate.
+    pub fn exclude_filter(
+        mut self,
+        predicate: impl Fn(&NamedDataType) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.exclude.push(Filter::Predicate(Arc::new(predicate)));
+        self
+    }
+
+    fn is_excluded(&self, ndt: &NamedDataType) -> bool {
+        self.exclude.iter().any(|filter| filter.matches(ndt))
+    }
+
+    fn is_selected(&self, ndt: &NamedDataType) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|filter| filter.matches(ndt)))
+            && !self.is_excluded(ndt)
+    }
+}
+
+/// Error returned by [`Types::select`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SelectionError {
+    /// A selected type depends on a type which was explicitly excluded.
+    DependsOnExcluded {
+        /// Path of the selected type, like `my_crate::api::User`.
+        selected: String,
+        /// Path of the excluded type it depends on.
+        excluded: String,
+    },
+}
+
+impl fmt::Display for SelectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DependsOnExcluded { selected, excluded } => write!(
+                f,
+                "selected type `{selected}` depends on excluded type `{excluded}`"
+            ),
+        }
+    }
+}
+
+impl error::Error for SelectionError {}
+
+pub(crate) fn select(types: &Types, selection: &Selection) -> Result<Types, SelectionError> {
+    let graph = types.graph();
+
+    // Each type to visit along with the selected type it was reached from.
+    let mut reached = HashSet::new();
+    let mut stack = graph
+        .types()
+        .filter(|ndt| selection.is_selected(ndt))
+        .map(|ndt| (ndt, ndt))
+        .collect::<Vec<_>>();
+    stack.reverse();
+
+    while let Some((ndt, root)) = stack.pop() {
+        if reached.contains(&ndt.id) {
+            continue;
+        }
+        if selection.is_excluded(ndt) {
+            return Err(SelectionError::DependsOnExcluded {
+                selected: path(root),
+                excluded: path(ndt),
+            });
+        }
+
+        reached.insert(ndt.id.clone());
+        stack.extend(graph.dependencies(ndt).map(|dependency| (dependency, root)));
+    }
+
+    let mut types = types.clone();
+    types.retain_ids(&reached);
+    Ok(types)
+}
+
+fn path(ndt: &NamedDataType) -> String {
+    if ndt.module_path.is_empty() {
+        ndt.name.to_string()
+    } else {
+        format!("{}::{}", ndt.module_path, ndt.name)
+    }
+}
+
+/// Matches `name` against `pattern`, where `*` matches any sequence of characters.
+fn glob(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+
+    let mut parts = parts.collect::<Vec<_>>();
+    let Some(last) = parts.pop() else {
+        // No wildcard, so the whole name must match.
+        return rest.is_empty();
+    };
+
+    for part in parts {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
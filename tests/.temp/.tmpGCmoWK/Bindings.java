@@ -0,0 +1,48 @@
+// This file has been generated by Specta. Do not edit this file manually.
+// @generated by specta-java
+
+public final class Bindings {
+    private Bindings() {}
+
+    public sealed interface TestJavaEvent<T> permits TestJavaEvent.Started, TestJavaEvent.Progress, TestJavaEvent.Finished {
+        record Started<T>() implements TestJavaEvent<T> {}
+
+        record Progress<T>(
+            java.lang.Short field0
+        ) implements TestJavaEvent<T> {}
+
+        record Finished<T>(
+            T result
+        ) implements TestJavaEvent<T> {}
+    }
+
+    public record TestJavaModel(
+        TestJavaResponse<java.lang.String> response,
+        TestJavaStatus status,
+        TestJavaEvent<java.lang.String> event,
+        java.util.Map<java.lang.String, java.lang.Long> lookup,
+        Tuple tuple
+    ) {
+        public record Tuple(
+            java.lang.String field0,
+            java.lang.Boolean field1
+        ) {}
+    }
+
+    /**
+     * A documented generic response.
+     */
+    public record TestJavaResponse<T>(
+        /**
+         * Returned payload.
+         */
+        T value,
+        java.util.Optional<java.lang.Long> optional_count,
+        java.util.List<java.lang.String> labels
+    ) {}
+
+    public enum TestJavaStatus {
+        Ready,
+        PendingReview
+    }
+}
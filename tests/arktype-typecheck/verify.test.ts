@@ -0,0 +1,113 @@
+import { expect, test } from "bun:test";
+import { type } from "arktype";
+import {
+  AdjacentEnumSchema,
+  ExternalEnumSchema,
+  FlattenedSchema,
+  GenericSchema,
+  InternalEnumSchema,
+  LinkedNodeSchema,
+  MutualASchema,
+  RecursiveSchema,
+  ReferencesSchema,
+  UntaggedReferencesSchema,
+  WireTypesSchema,
+  type LinkedNode,
+  type References,
+} from "./generated/bindings";
+import * as files from "./generated/files/arktype_typecheck";
+import * as prefixed from "./generated/module-prefixed";
+import { arktype_typecheck as namespaced } from "./generated/namespaces";
+
+const check = (schema: (value: unknown) => unknown, value: unknown) =>
+  !(schema(value) instanceof type.errors);
+
+const linked: typeof LinkedNodeSchema.infer = { value: 1, next: { value: 2, next: null } };
+const linkedType: LinkedNode = linked;
+void linkedType;
+// @ts-expect-error References resolve to the exported TypeScript type.
+const invalidLinked: typeof LinkedNodeSchema.infer = { value: 1 };
+void invalidLinked;
+
+const NumberGenericSchema = GenericSchema(type("number"));
+const numberGeneric: typeof NumberGenericSchema.infer = { value: 1, items: [2] };
+void numberGeneric;
+// @ts-expect-error Generic schemas infer from their schema arguments.
+const invalidNumberGeneric: typeof NumberGenericSchema.infer = { value: "1", items: [] };
+void invalidNumberGeneric;
+
+const validReferences: References = {
+  recursive: { children: [{ children: [] }] },
+  optional: null,
+  list: [linked],
+  generic: { value: { children: [] }, items: [] },
+  nested: { value: "inner" },
+};
+
+test("lazy references resolve recursively", () => {
+  expect(check(RecursiveSchema, { children: [{ children: [{ children: [] }] }] })).toBe(true);
+  expect(check(RecursiveSchema, { children: [{ children: [{}] }] })).toBe(false);
+  expect(check(LinkedNodeSchema, linked)).toBe(true);
+  expect(check(LinkedNodeSchema, { value: 1, next: { value: 256, next: null } })).toBe(false);
+  expect(check(MutualASchema, { b: { a: [{ b: null }] } })).toBe(true);
+  expect(check(MutualASchema, { b: { a: [{ b: { a: [{}] } }] } })).toBe(false);
+  expect(check(ReferencesSchema, validReferences)).toBe(true);
+  expect(check(ReferencesSchema, { ...validReferences, optional: { children: [] } })).toBe(true);
+  expect(check(ReferencesSchema, { ...validReferences, generic: { value: 1, items: [] } })).toBe(
+    false,
+  );
+  expect(check(ReferencesSchema, { ...validReferences, nested: {} })).toBe(false);
+});
+
+test("lazy references can be combined in unions", () => {
+  expect(check(UntaggedReferencesSchema, { children: [] })).toBe(true);
+  expect(check(UntaggedReferencesSchema, { value: 1, next: null })).toBe(true);
+  expect(check(UntaggedReferencesSchema, { value: 1 })).toBe(false);
+  expect(check(AdjacentEnumSchema, { t: "Node", c: linked })).toBe(true);
+  expect(check(AdjacentEnumSchema, { t: "Node", c: 1 })).toBe(false);
+});
+
+test("lazy references keep the validated value and report nested errors", () => {
+  expect(ReferencesSchema(validReferences)).toEqual(validReferences);
+  const out = LinkedNodeSchema({ value: 1, next: { value: "2", next: null } });
+  expect(out).toBeInstanceOf(type.errors);
+  expect((out as InstanceType<typeof type.errors>).summary).toContain("value");
+});
+
+test("generated schemas validate representative wire values", () => {
+  expect(check(ExternalEnumSchema, "Unit")).toBe(true);
+  expect(check(ExternalEnumSchema, { Tuple: [1, true] })).toBe(true);
+  expect(check(ExternalEnumSchema, { Named: { radius: null } })).toBe(true);
+  expect(check(ExternalEnumSchema, { Newtype: "value", Tuple: [1, true] })).toBe(false);
+  expect(check(InternalEnumSchema, { type: "A", value: 1 })).toBe(true);
+  expect(check(InternalEnumSchema, { type: "A" })).toBe(false);
+  expect(check(FlattenedSchema, { id: "id", Newtype: "value" })).toBe(true);
+  expect(check(FlattenedSchema, { id: "id", Newtype: "value", extra: true })).toBe(false);
+  expect(
+    check(WireTypesSchema, {
+      character: "😀",
+      floating: null,
+      fixed_array: [1, 2],
+      tuple: ["value", true],
+      integer_keys: { "-2": "value" },
+      enum_keys: { First: "value" },
+    }),
+  ).toBe(true);
+  expect(
+    check(WireTypesSchema, {
+      character: "ab",
+      floating: 1,
+      fixed_array: [1, 2],
+      tuple: ["value", true],
+      integer_keys: {},
+      enum_keys: {},
+    }),
+  ).toBe(false);
+});
+
+test("other layouts resolve lazy references", () => {
+  expect(check(namespaced.ReferencesSchema, validReferences)).toBe(true);
+  expect(check(prefixed.arktype_typecheck_ReferencesSchema, validReferences)).toBe(true);
+  expect(check(files.ReferencesSchema, validReferences)).toBe(true);
+  expect(check(files.ReferencesSchema, { ...validReferences, nested: {} })).toBe(false);
+});
@@ -0,0 +1,137 @@
+use std::{collections::HashMap, path::Path};
+
+use serde::{Deserialize, Serialize};
+use specta::{Type, Types};
+use specta_typebox::{Layout, TypeBox};
+
+#[derive(Type, Serialize, Deserialize)]
+struct Recursive {
+    children: Vec<Recursive>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+struct LinkedNode {
+    value: u8,
+    next: Option<Box<LinkedNode>>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+struct MutualA {
+    b: Option<Box<MutualB>>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+struct MutualB {
+    a: Vec<MutualA>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+struct Generic<T = String> {
+    value: T,
+    items: Vec<T>,
+}
+
+#[derive(Type, Serialize, Deserialize, Eq, Hash, PartialEq)]
+enum FiniteKey {
+    First,
+    Second,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+struct WireTypes {
+    character: char,
+    floating: f64,
+    fixed_array: [u8; 2],
+    tuple: (String, bool),
+    integer_keys: HashMap<i32, String>,
+    enum_keys: HashMap<FiniteKey, String>,
+    #[specta(optional)]
+    maybe: Option<Vec<u16>>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+enum ExternalEnum {
+    Unit,
+    Newtype(String),
+    Tuple(i32, bool),
+    Named { radius: f64 },
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum InternalEnum {
+    A { value: u8 },
+    B,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[serde(tag = "t", content = "c")]
+enum AdjacentEnum {
+    Leaf(u8),
+    Node(Box<LinkedNode>),
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[serde(untagged)]
+enum UntaggedReferences {
+    Recursive(Recursive),
+    Linked(LinkedNode),
+}
+
+#[derive(Type, Serialize, Deserialize)]
+struct Flattened {
+    id: String,
+    #[serde(flatten)]
+    shape: ExternalEnum,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+struct References {
+    recursive: Recursive,
+    optional: Option<Recursive>,
+    list: Vec<LinkedNode>,
+    generic: Generic<Recursive>,
+    nested: nested::Inner,
+}
+
+mod nested {
+    use super::*;
+
+    #[derive(Type, Serialize, Deserialize)]
+    pub struct Inner {
+        pub value: String,
+    }
+}
+
+fn main() {
+    let types = Types::default()
+        .register::<Recursive>()
+        .register::<LinkedNode>()
+        .register::<MutualA>()
+        .register::<Generic>()
+        .register::<WireTypes>()
+        .register::<InternalEnum>()
+        .register::<AdjacentEnum>()
+        .register::<UntaggedReferences>()
+        .register::<Flattened>()
+        .register::<References>();
+
+    let out = Path::new(env!("CARGO_MANIFEST_DIR")).join("typebox-typecheck/generated");
+    std::fs::create_dir_all(&out).unwrap();
+
+    TypeBox::default()
+        .export_to(out.join("bindings.ts"), &types, specta_serde::Format)
+        .unwrap();
+    TypeBox::default()
+        .layout(Layout::Namespaces)
+        .export_to(out.join("namespaces.ts"), &types, specta_serde::Format)
+        .unwrap();
+    TypeBox::default()
+        .layout(Layout::ModulePrefixedName)
+        .export_to(out.join("module-prefixed.ts"), &types, specta_serde::Format)
+        .unwrap();
+    TypeBox::default()
+        .layout(Layout::Files)
+        .export_to(out.join("files"), &types, specta_serde::Format)
+        .unwrap();
+}
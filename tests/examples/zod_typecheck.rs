@@ -7,7 +7,7 @@ use specta::{
 };
 use specta_typescript::Typescript;
 use specta_util::Remapper;
-use specta_zod::{Layout, Zod};
+use specta_zod::{Layout, Target, Zod};
 
 #[derive(Type, Serialize, Deserialize)]
 struct Recursive {
@@ -508,6 +508,17 @@ fn main() {
         .layout(Layout::Namespaces)
         .export_to(out.join("namespaces.ts"), &types, specta_serde::Format)
         .unwrap();
+    Zod::default()
+        .target(Target::Zod3)
+        .framework_prelude(
+            "import { z } from \"zod/v3\";\n// This file has been generated by Specta. Do not edit this file manually.",
+        )
+        .export_to(out.join("bindings-zod3.ts"), &types, specta_serde::Format)
+        .unwrap();
+    Zod::default()
+        .target(Target::Mini)
+        .export_to(out.join("bindings-mini.ts"), &types, specta_serde::Format)
+        .unwrap();
     let mut module_prefixed_types = Types::default();
     let module_prefixed_root =
         NamedDataType::new("RootType", &mut module_prefixed_types, |_, ndt| {
@@ -0,0 +1,236 @@
+use std::{collections::HashMap, path::Path};
+
+use serde::{Deserialize, Serialize};
+use specta::{Type, Types};
+use specta_arktype::{Any, ArkType, Layout, Never, Unknown, define, primitives};
+use tempfile::TempDir;
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Recursive {
+    children: Vec<Recursive>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+enum Shape {
+    Unit,
+    Newtype(String),
+    Tuple(i32, bool),
+    Named { radius: f64 },
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+#[serde(tag = "type")]
+enum Tagged {
+    A { value: u8 },
+    B,
+}
+
+#[derive(Type, Eq, Hash, PartialEq)]
+#[specta(collect = false)]
+enum Key {
+    First,
+    Second,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Generic<T = String> {
+    value: T,
+    keys: HashMap<Key, T>,
+    numbers: HashMap<i32, char>,
+    recursive: Recursive,
+    #[specta(optional)]
+    maybe: Option<Vec<u16>>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct Flattened {
+    id: String,
+    #[serde(flatten)]
+    shape: Shape,
+}
+
+mod nested {
+    use super::*;
+
+    #[derive(Type)]
+    pub struct Inner {
+        pub value: String,
+    }
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Outer {
+    inner: nested::Inner,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct TupleDefault(u8, #[serde(default)] u8);
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct SyntaxKeys {
+    #[serde(rename = "done?")]
+    done: bool,
+    #[serde(rename = "[index]")]
+    index: u8,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct ReservedKey {
+    #[serde(rename = "+")]
+    plus: bool,
+}
+
+fn export<T: Type>() -> Result<String, specta_arktype::Error> {
+    ArkType::default().export(&Types::default().register::<T>(), specta_serde::Format)
+}
+
+#[test]
+fn arktype_export_smoke() {
+    let out = export::<Recursive>().unwrap();
+    assert!(out.starts_with("import { type, type Type } from \"arktype\";"));
+    assert!(out.contains("const $spectaLazy = <const T extends Type>(schema: () => T): T => {"));
+    assert!(out.contains("export type Recursive = {"));
+    assert!(out.contains(
+        "export const RecursiveSchema: Type<Recursive> = type({\n\tchildren: $spectaLazy(() => RecursiveSchema).array(),\n});"
+    ));
+}
+
+#[test]
+fn arktype_datatypes() {
+    let types = Types::default()
+        .register::<Generic>()
+        .register::<Tagged>()
+        .register::<Flattened>();
+    let out = ArkType::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    insta::assert_snapshot!("arktype-datatypes", out);
+
+    assert!(out.contains(
+        "export function GenericSchema<T extends Type>(T: T): Type<Generic<T[\"infer\"]>>;"
+    ));
+    assert!(out.contains("export function GenericSchema(T: Type = type(\"string\")): Type {"));
+    assert!(out.contains(
+        r#"keys: type.Record(type.or(type.unit("First"), type.unit("Second")), T).partial(),"#
+    ));
+    assert!(out.contains(r#"numbers: type.Record(type(/^-?\d+$/), "#));
+    assert!(
+        out.contains(r#""maybe?": type.or(type("0 <= number.integer <= 65535").array(), "null"),"#)
+    );
+    assert!(out.contains("\t\"+\": \"reject\","));
+    assert!(out.contains("\ttype: type.unit(\"A\"),"));
+}
+
+#[test]
+fn arktype_recursive_generics_and_optional_elements() {
+    let out = export::<Tree<String>>().unwrap();
+    assert!(out.contains("children: $spectaLazy(() => TreeSchema(T)).array(),"));
+
+    let out = ArkType::default()
+        .export(
+            &Types::default().register::<TupleDefault>(),
+            specta_serde::PhasesFormat,
+        )
+        .unwrap();
+    assert!(out.contains(
+        r#"type([type("0 <= number.integer <= 255"), [type("0 <= number.integer <= 255"), "?"]])"#
+    ));
+}
+
+#[test]
+fn arktype_escapes_object_keys() {
+    let out = export::<SyntaxKeys>().unwrap();
+    assert!(out.contains(r#""done\\?": type("boolean"),"#));
+    assert!(out.contains(r#""\\[index]": type("0 <= number.integer <= 255"),"#));
+
+    let err = export::<ReservedKey>().unwrap_err().to_string();
+    assert!(err.contains("ArkType has no way to express the object keys `+` and `...`"));
+
+    let mut types = Types::default();
+    let dt = u64::definition(&mut types);
+    let err = primitives::inline(&ArkType::default(), &types, &dt)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("specta_arktype::define"));
+}
+
+#[test]
+fn arktype_layouts_qualify_references() {
+    let types = Types::default().register::<Outer>();
+
+    let namespaces = ArkType::default()
+        .layout(Layout::Namespaces)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(namespaces.contains("inner: $spectaLazy(() => $s$.test.arktype.nested.InnerSchema),"));
+
+    let prefixed = ArkType::default()
+        .layout(Layout::ModulePrefixedName)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(prefixed.contains("inner: $spectaLazy(() => test_arktype_nested_InnerSchema),"));
+
+    let dir = TempDir::new_in(temp_root()).unwrap();
+    ArkType::default()
+        .layout(Layout::Files)
+        .export_to(dir.path(), &types, specta_serde::Format)
+        .unwrap();
+    let outer = std::fs::read_to_string(dir.path().join("test/arktype.ts")).unwrap();
+    assert!(outer.contains(r#"import * as test$arktype$nested from "./arktype/nested";"#));
+    assert!(outer.contains("$spectaLazy(() => test$arktype$nested.InnerSchema)"));
+    let inner = std::fs::read_to_string(dir.path().join("test/arktype/nested.ts")).unwrap();
+    assert!(inner.contains("const $spectaLazy ="));
+}
+
+#[test]
+fn arktype_define_raw_and_runtime() {
+    #[derive(Type)]
+    #[specta(collect = false)]
+    struct Wrappers {
+        any: Any,
+        unknown: Unknown,
+        never: Never,
+    }
+
+    let types = Types::default().register::<Wrappers>();
+    let out = ArkType::default()
+        .header("// header")
+        .with_raw("export const raw = true;")
+        .framework_runtime(|mut exporter| {
+            let types = exporter.render_types()?;
+            let custom = exporter.inline(&define("type(\"string.uuid\")").into())?;
+            Ok(format!("export const Id = {custom};\n{types}").into())
+        })
+        .export(&types, specta_serde::Format)
+        .unwrap();
+
+    assert!(out.starts_with("// header\n"));
+    assert!(out.contains("export const Id = type(\"string.uuid\");"));
+    assert!(out.contains("export const raw = true;"));
+    assert!(out.contains(
+        "\tany: type(\"unknown.any\"),\n\tunknown: type(\"unknown\"),\n\tnever: type(\"never\"),"
+    ));
+    assert_eq!(out.matches("export const WrappersSchema").count(), 1);
+}
+
+fn temp_root() -> std::path::PathBuf {
+    let temp_root = Path::new(env!("CARGO_MANIFEST_DIR")).join(".temp");
+    std::fs::create_dir_all(&temp_root).unwrap();
+    temp_root
+}
@@ -0,0 +1,54 @@
+use specta::{Type, Types};
+
+#[derive(Type)]
+#[specta(collect = "collect_billing")]
+struct Invoice {
+    customer: Customer,
+}
+
+// Not collected itself, but `Invoice` depends on it.
+#[derive(Type)]
+#[specta(collect = false)]
+struct Customer {
+    id: String,
+}
+
+#[derive(Type)]
+#[specta(collect = "collect_accounts")]
+struct Account {
+    id: String,
+}
+
+#[derive(Type)]
+struct CollectUngrouped {
+    id: String,
+}
+
+fn names(types: &Types) -> Vec<&str> {
+    types
+        .into_sorted_iter()
+        .map(|ndt| ndt.name.as_ref())
+        .collect()
+}
+
+#[test]
+fn collect_group_contains_its_types_and_their_dependencies() {
+    assert_eq!(
+        names(&specta::collect_group("collect_billing")),
+        ["Customer", "Invoice", "String"]
+    );
+    assert_eq!(
+        names(&specta::collect_group("collect_accounts")),
+        ["Account", "String"]
+    );
+    assert!(names(&specta::collect_group("collect_missing")).is_empty());
+}
+
+#[test]
+fn collect_contains_every_group() {
+    let types = specta::collect();
+    let names = names(&types);
+    for name in ["Account", "CollectUngrouped", "Customer", "Invoice"] {
+        assert!(names.contains(&name), "{name} should be collected");
+    }
+}
@@ -6,7 +6,7 @@ use specta::{
     function::{self, fn_datatype},
     specta,
 };
-use specta_typescript::{Typescript, primitives};
+use specta_typescript::{FunctionStyle, JSDoc, Typescript, primitives};
 
 fn render_datatype(ts: &Typescript, types: &Types, dt: &DataType) -> String {
     // This is handled by Specta Typescript for you.
@@ -110,6 +110,13 @@ fn rename_all_fn(myArg: i32, anotherValue: String) {}
 #[specta(rename = "totally_custom")]
 fn renamed_fn(myArg: i32) {}
 
+/// Loads a user.
+#[deprecated = "use `d` instead"]
+#[specta]
+async fn load_user(r#type: i32, user: Demo) -> Result<Demo, String> {
+    Ok(user)
+}
+
 // TODO: Finish fixing these
 
 #[test]
@@ -355,3 +362,86 @@ fn test_function_exporting() {
         insta::assert_snapshot!(def.args[0].0, @"myArg");
     }
 }
+
+#[test]
+#[allow(deprecated)]
+fn test_function_export() {
+    let mut types = Types::default();
+    let functions = function::collect_functions![a, d, load_user](&mut types);
+
+    let output = Typescript::default()
+        .export_functions(
+            &functions,
+            FunctionStyle::Declarations,
+            &types,
+            specta_serde::Format,
+        )
+        .unwrap();
+    assert!(output.contains("export type Demo = {"), "{output}");
+    assert!(
+        output.contains(
+            "/**\n *  Multiline\n *  Docs\n */\nexport declare function a(): void;\n\nexport declare function d(demo: string): number;\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "/**\n *  Loads a user.\n * @throws {string}\n * @deprecated use `d` instead\n */\nexport declare function load_user(type_: number, user: Demo): Promise<Demo>;\n"
+        ),
+        "{output}"
+    );
+
+    let output = Typescript::default()
+        .export_functions(
+            &functions,
+            FunctionStyle::Client,
+            &types,
+            specta_serde::Format,
+        )
+        .unwrap();
+    assert!(
+        output.contains(
+            "\ta: { args: Record<string, never>; result: void; error: never };\n\td: { args: { demo: string }; result: number; error: never };\n\t/**  Loads a user. */\n\tload_user: { args: { type: number; user: Demo }; result: Demo; error: string };\n};\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains("\t\ta: (): Promise<void> => invoke(\"a\", {}),\n"),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "\t\tload_user: (type_: number, user: Demo): Promise<Demo> => invoke(\"load_user\", { type: type_, user }),\n"
+        ),
+        "{output}"
+    );
+
+    let output = JSDoc::default()
+        .export_functions(
+            &functions,
+            FunctionStyle::Client,
+            &types,
+            specta_serde::Format,
+        )
+        .unwrap();
+    assert!(
+        output.contains(
+            "\t\t/**\n\t\t * @param {string} demo\n\t\t * @returns {Promise<number>}\n\t\t */\n\t\td: (demo) => invoke(\"d\", { demo }),\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(" * @returns {Promise<void>}\n\t\t */\n\t\ta: () => invoke(\"a\", {}),\n"),
+        "{output}"
+    );
+
+    let output = JSDoc::default()
+        .export_functions(
+            &functions,
+            FunctionStyle::Declarations,
+            &types,
+            specta_serde::Format,
+        )
+        .unwrap();
+    assert!(output.contains(" * @typedef {() => void} a\n"), "{output}");
+}
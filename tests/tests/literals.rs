@@ -0,0 +1,304 @@
+use serde::Serialize;
+use specta::{
+    Type, Types,
+    datatype::{DataType, Literal, LiteralValue, Primitive, Reference},
+};
+use specta_arktype::ArkType;
+use specta_jsonschema::JsonSchema;
+use specta_kotlin::{Kotlin, Serialization};
+use specta_swift::Swift;
+use specta_typebox::TypeBox;
+use specta_typescript::Typescript;
+use specta_valibot::Valibot;
+use specta_zod::Zod;
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct NotFound {
+    #[specta(literal = "v1")]
+    version: String,
+    #[specta(literal = 404u16)]
+    status: u16,
+    #[specta(literal = true)]
+    error: bool,
+    message: String,
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct Offset {
+    #[specta(literal = -1.5)]
+    value: f64,
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct Ratio {
+    #[specta(literal = 1.1f32)]
+    value: f32,
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct Invalid {
+    #[specta(literal = f64::NAN)]
+    value: f64,
+}
+
+fn not_found() -> Types {
+    Types::default().register::<NotFound>()
+}
+
+fn inline(ty: DataType) -> String {
+    specta_typescript::primitives::inline(&Typescript::default(), &Types::default(), &ty).unwrap()
+}
+
+#[test]
+fn literal_values() {
+    let DataType::Reference(Reference::Opaque(reference)) = Literal::new("v1") else {
+        panic!("literals should be opaque references");
+    };
+    let literal = reference.downcast_ref::<Literal>().unwrap();
+    assert_eq!(literal.primitive(), Primitive::str);
+    assert_eq!(literal.value(), LiteralValue::Str("v1"));
+
+    assert_eq!(Literal::new(404u16), Literal::new(404u16));
+    assert_ne!(Literal::new(404u16), Literal::new(404u32));
+    assert_eq!(Literal::new(f64::NAN), Literal::new(f64::NAN));
+}
+
+#[test]
+fn f32_literals_keep_their_precision() {
+    let DataType::Reference(Reference::Opaque(reference)) = Literal::new(1.1f32) else {
+        panic!("literals should be opaque references");
+    };
+    let literal = reference.downcast_ref::<Literal>().unwrap();
+    assert_eq!(literal.primitive(), Primitive::f32);
+    assert_eq!(literal.value(), LiteralValue::Float(1.1));
+    assert_eq!(serde_json::to_string(&1.1f32).unwrap(), "1.1");
+
+    let types = Types::default().register::<Ratio>();
+    let output = Typescript::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(output.contains("value: 1.1,"), "{output}");
+    let output = Zod::default().export(&types, specta_serde::Format).unwrap();
+    assert!(output.contains("z.literal(1.1)"), "{output}");
+    let output = TypeBox::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(output.contains("Type.Literal(1.1)"), "{output}");
+    let output = Swift::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(output.contains("= 1.1"), "{output}");
+    assert!(!output.contains("1.10000002"), "{output}");
+    let output = Kotlin::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(!output.contains("1.10000002"), "{output}");
+
+    let value = JsonSchema::default()
+        .export_value(&types, specta_serde::Format)
+        .unwrap();
+    let defs = value
+        .get("$defs")
+        .or_else(|| value.get("definitions"))
+        .unwrap();
+    assert_eq!(
+        defs["Ratio"]["properties"]["value"],
+        serde_json::json!({ "const": 1.1 })
+    );
+}
+
+#[test]
+fn typescript_literals() {
+    assert_eq!(inline(Literal::new("v1")), r#""v1""#);
+    assert_eq!(inline(Literal::new("say \"hi\"")), r#""say \"hi\"""#);
+    assert_eq!(inline(Literal::new(404u16)), "404");
+    assert_eq!(inline(Literal::new(-1i8)), "-1");
+    assert_eq!(inline(Literal::new(1.5f64)), "1.5");
+    assert_eq!(inline(Literal::new(false)), "false");
+    assert_eq!(inline(Literal::new('x')), r#""x""#);
+
+    let output = Typescript::default()
+        .export(&not_found(), specta_serde::Format)
+        .unwrap();
+    assert!(output.contains(r#"version: "v1""#), "{output}");
+    assert!(output.contains("status: 404"), "{output}");
+    assert!(output.contains("error: true"), "{output}");
+    assert!(output.contains("message: string"), "{output}");
+
+    let output = Typescript::default()
+        .export(&Types::default().register::<Offset>(), specta_serde::Format)
+        .unwrap();
+    assert!(output.contains("value: -1.5"), "{output}");
+}
+
+#[test]
+fn typescript_rejects_unrepresentable_literals() {
+    for ty in [
+        Literal::new(f64::NAN),
+        Literal::new(f32::INFINITY),
+        Literal::new(f64::NEG_INFINITY),
+        Literal::new(u64::MAX),
+    ] {
+        let err =
+            specta_typescript::primitives::inline(&Typescript::default(), &Types::default(), &ty)
+                .unwrap_err();
+        assert!(err.to_string().contains("literal"), "{err}");
+    }
+
+    let err = Typescript::default()
+        .export(
+            &Types::default().register::<Invalid>(),
+            specta_serde::Format,
+        )
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("NaN"), "{err}");
+}
+
+#[test]
+fn schema_literals() {
+    let output = Zod::default()
+        .export(&not_found(), specta_serde::Format)
+        .unwrap();
+    assert!(output.contains(r#"version: z.literal("v1")"#), "{output}");
+    assert!(output.contains("status: z.literal(404)"), "{output}");
+
+    let output = Valibot::default()
+        .export(&not_found(), specta_serde::Format)
+        .unwrap();
+    assert!(output.contains(r#"version: v.literal("v1")"#), "{output}");
+    assert!(output.contains("status: v.literal(404)"), "{output}");
+
+    let output = TypeBox::default()
+        .export(&not_found(), specta_serde::Format)
+        .unwrap();
+    assert!(output.contains(r#"Type.Literal("v1")"#), "{output}");
+
+    let output = ArkType::default()
+        .export(&not_found(), specta_serde::Format)
+        .unwrap();
+    assert!(output.contains(r#"type.unit("v1")"#), "{output}");
+
+    let err = Zod::default()
+        .export(
+            &Types::default().register::<Invalid>(),
+            specta_serde::Format,
+        )
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("NaN"), "{err}");
+}
+
+#[test]
+fn jsonschema_literals() {
+    let value = JsonSchema::default()
+        .export_value(&not_found(), specta_serde::Format)
+        .unwrap();
+    let defs = value
+        .get("$defs")
+        .or_else(|| value.get("definitions"))
+        .unwrap();
+    let properties = &defs["NotFound"]["properties"];
+    assert_eq!(properties["version"], serde_json::json!({ "const": "v1" }));
+    assert_eq!(properties["status"], serde_json::json!({ "const": 404 }));
+    assert_eq!(properties["error"], serde_json::json!({ "const": true }));
+
+    let err = JsonSchema::default()
+        .export_value(
+            &Types::default().register::<Invalid>(),
+            specta_serde::Format,
+        )
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("NaN"), "{err}");
+}
+
+#[test]
+fn swift_and_kotlin_literals() {
+    let output = Swift::default()
+        .export(&not_found(), specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains(r#"public let version: String = "v1""#),
+        "{output}"
+    );
+    assert!(
+        output.contains("public let status: UInt16 = 404"),
+        "{output}"
+    );
+
+    let output = Kotlin::default()
+        .serialization(Serialization::Kotlinx)
+        .export(&not_found(), specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains(
+            "@kotlinx.serialization.EncodeDefault\n    public val version: kotlin.String = \"v1\""
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains("public val status: kotlin.UShort = 404u"),
+        "{output}"
+    );
+
+    assert!(
+        Swift::default()
+            .export(
+                &Types::default().register::<Invalid>(),
+                specta_serde::Format
+            )
+            .is_err()
+    );
+    assert!(
+        Kotlin::default()
+            .export(
+                &Types::default().register::<Invalid>(),
+                specta_serde::Format
+            )
+            .is_err()
+    );
+}
+
+#[test]
+fn exported_constants() {
+    const API_VERSION: &str = "v1";
+    const MAX_UPLOAD_BYTES: u32 = 1024;
+
+    let output = Typescript::default()
+        .constant("API_VERSION", API_VERSION)
+        .constant("MAX_UPLOAD_BYTES", MAX_UPLOAD_BYTES)
+        .export(&Types::default(), specta_serde::Format)
+        .unwrap();
+    assert!(
+        output
+            .contains("export const API_VERSION = \"v1\";\nexport const MAX_UPLOAD_BYTES = 1024;"),
+        "{output}"
+    );
+
+    let output = specta_typescript::JSDoc::default()
+        .constant("API_VERSION", API_VERSION)
+        .export(&Types::default(), specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains("export const API_VERSION = \"v1\";"),
+        "{output}"
+    );
+
+    assert!(
+        Typescript::default()
+            .constant("not valid", 1u8)
+            .export(&Types::default(), specta_serde::Format)
+            .is_err()
+    );
+    assert!(
+        Typescript::default()
+            .constant("RATIO", f64::NAN)
+            .export(&Types::default(), specta_serde::Format)
+            .is_err()
+    );
+}
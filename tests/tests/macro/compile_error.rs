@@ -105,24 +105,24 @@ struct InvalidAttrs4 {
     a: String,
 }
 
-// Legacy `#[specta(...)]` migration errors
 #[derive(Type)]
 #[specta(collect = false)]
-#[specta(rename = "Renamed")]
-struct LegacyContainerRename;
+#[specta(rename_all = "camelCase123")]
+struct InvalidRenameAll {
+    a: String,
+}
 
 #[derive(Type)]
 #[specta(collect = false)]
-struct LegacyFieldRename {
-    #[specta(rename = "renamed")]
-    a: String,
-}
+struct UnnamedFieldRename(#[specta(rename = "renamed")] String);
 
 #[derive(Type)]
 #[specta(collect = false)]
-enum LegacyVariantRename {
-    #[specta(rename = "renamed")]
-    A,
+enum LegacyVariantFlatten {
+    A {
+        #[specta(flatten)]
+        a: String,
+    },
 }
 
 const INTERNAL_RENAME_KEY: &str = "renamed";
@@ -169,6 +169,41 @@ struct InvalidExplicitBool {
     value: String,
 }
 
+#[derive(Type)]
+#[specta(collect = false)]
+struct InvalidExamples {
+    #[specta(examples = "a")]
+    value: String,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+enum VariantDefaultWithoutValue {
+    #[specta(default)]
+    A,
+}
+
+#[derive(Type)]
+#[specta(collect = false, serde_repr)]
+enum SerdeReprWithoutRepr {
+    A,
+}
+
+#[derive(Type)]
+#[specta(collect = "true")]
+struct QuotedCollectBool;
+
+#[derive(Type)]
+#[specta(collect = "billing", collect = "accounts")]
+struct RepeatedCollect;
+
+// Specta v1's tag, which is now `#[serde(tag = "...")]`
+#[derive(Type)]
+#[specta(collect = false, tag = "type")]
+enum LegacyTag {
+    A { value: String },
+}
+
 #[specta]
 pub fn invalid_destructure((): ()) {}
 
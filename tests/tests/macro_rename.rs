@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use specta::{
+    Type, Types,
+    datatype::{DataType, Fields},
+};
+use specta_csharp::CSharp;
+use specta_go::Go;
+use specta_kotlin::Kotlin;
+use specta_swift::Swift;
+use specta_typescript::Typescript;
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(
+    collect = false,
+    rename = "CreateUserRequest",
+    rename_all = "camelCase"
+)]
+#[serde(tag = "kind")]
+struct CreateUserRequestV2 {
+    user_id: u32,
+    #[serde(rename = "displayName")]
+    #[specta(rename = "name")]
+    display_name: String,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false, rename_all = "SCREAMING_SNAKE_CASE")]
+enum Status {
+    Active,
+    #[specta(rename = "archived")]
+    Archived,
+    #[specta(rename_all = "PascalCase")]
+    Suspended {
+        until_ms: u32,
+    },
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct Account {
+    request: CreateUserRequestV2,
+    status: Status,
+}
+
+fn types() -> Types {
+    Types::default().register::<Account>()
+}
+
+#[test]
+fn renames_are_stored_in_attributes() {
+    let types = types();
+    let ndt = types
+        .into_sorted_iter()
+        .find(|ndt| ndt.name == "CreateUserRequest")
+        .unwrap();
+    let Some(DataType::Struct(strct)) = &ndt.ty else {
+        panic!("expected a struct");
+    };
+    assert_eq!(strct.attributes.rename(), Some("CreateUserRequest"));
+    let Fields::Named(fields) = &strct.fields else {
+        panic!("expected named fields");
+    };
+    let renames = fields
+        .fields
+        .iter()
+        .map(|(name, field)| (name.as_ref(), field.attributes.rename()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        renames,
+        [("user_id", Some("userId")), ("display_name", Some("name"))]
+    );
+
+    let ndt = types
+        .into_sorted_iter()
+        .find(|ndt| ndt.name == "Status")
+        .unwrap();
+    let Some(DataType::Enum(enm)) = &ndt.ty else {
+        panic!("expected an enum");
+    };
+    assert_eq!(enm.attributes.rename(), None);
+    let renames = enm
+        .variants
+        .iter()
+        .map(|(name, variant)| (name.as_ref(), variant.attributes.rename()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        renames,
+        [
+            ("Active", Some("ACTIVE")),
+            ("Archived", Some("archived")),
+            ("Suspended", Some("SUSPENDED")),
+        ]
+    );
+    let Fields::Named(fields) = &enm.variants[2].1.fields else {
+        panic!("expected named fields");
+    };
+    assert_eq!(fields.fields[0].1.attributes.rename(), Some("UntilMs"));
+}
+
+#[test]
+fn serialized_names_are_unchanged() {
+    let output = Typescript::default()
+        .export(&types(), specta_serde::Format)
+        .unwrap();
+
+    assert!(
+        output.contains("export type CreateUserRequest = {"),
+        "{output}"
+    );
+    assert!(output.contains("request: CreateUserRequest"), "{output}");
+    // The internal tag and the keys are still serde's.
+    assert!(
+        output.contains(r#"kind: "CreateUserRequestV2""#),
+        "{output}"
+    );
+    assert!(output.contains("user_id: number"), "{output}");
+    assert!(output.contains("displayName: string"), "{output}");
+    assert!(output.contains(r#""Active""#), "{output}");
+}
+
+#[test]
+fn exporters_use_renamed_identifiers() {
+    let types = types();
+
+    let swift = Swift::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        swift.contains("public struct CreateUserRequest: Codable"),
+        "{swift}"
+    );
+    assert!(swift.contains(r#"case name = "displayName""#), "{swift}");
+    assert!(swift.contains(r#"case userId = "user_id""#), "{swift}");
+
+    let kotlin = Kotlin::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(kotlin.contains("class CreateUserRequest("), "{kotlin}");
+    assert!(
+        kotlin.contains("public val name: kotlin.String"),
+        "{kotlin}"
+    );
+    assert!(
+        kotlin.contains("public data object ACTIVE : Status"),
+        "{kotlin}"
+    );
+    assert!(
+        kotlin.contains("public data class SUSPENDED(\n        public val UntilMs"),
+        "{kotlin}"
+    );
+
+    let csharp = CSharp::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(csharp.contains("record CreateUserRequest"), "{csharp}");
+    assert!(
+        csharp.contains("JsonPropertyName(\"displayName\")]\n    public required string name"),
+        "{csharp}"
+    );
+
+    let go = Go::default().export(&types, specta_serde::Format).unwrap();
+    assert!(go.contains("type CreateUserRequest struct"), "{go}");
+    assert!(go.contains("UserId"), "{go}");
+    assert!(go.contains(r#"`json:"displayName"`"#), "{go}");
+}
@@ -0,0 +1,239 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use specta::{
+    Type, Types,
+    datatype::{DataType, Fields},
+};
+use specta_jsonschema::JsonSchema;
+use specta_openapi::{OasVersion, OpenApi, SchemaMode};
+use specta_typescript::Typescript;
+
+fn default_port() -> u16 {
+    8080
+}
+
+fn default_retries() -> u8 {
+    3
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct Config {
+    /// The name of the service.
+    #[specta(example = "api", example = "worker")]
+    name: String,
+    #[serde(default = "default_port")]
+    #[specta(default = default_port(), examples = [80, 443])]
+    port: u16,
+    #[serde(default = "default_retries")]
+    retries: u8,
+    #[serde(default)]
+    verbose: bool,
+    #[specta(default = vec!["a".to_string()])]
+    tags: Vec<String>,
+    #[specta(example = Some(Point { x: 1, y: 2 }))]
+    origin: Option<Point>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct Generic<T> {
+    #[serde(default)]
+    value: Vec<T>,
+    #[specta(default = 1)]
+    explicit: u8,
+}
+
+#[derive(Type, Deserialize)]
+#[specta(collect = false)]
+struct NotSerialize {
+    #[serde(default)]
+    #[specta(type = ())]
+    value: NotSerializeDefault,
+}
+
+#[derive(Default, Deserialize)]
+struct NotSerializeDefault;
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+enum Shape {
+    #[specta(example = Shape::Circle { radius: 1.5 })]
+    Circle { radius: f64 },
+    #[specta(default = "Square")]
+    Square,
+}
+
+fn named_fields(ty: &Option<DataType>) -> &Fields {
+    let Some(DataType::Struct(strct)) = ty else {
+        panic!("expected a struct");
+    };
+    &strct.fields
+}
+
+#[test]
+fn values_are_serialized_at_registration() {
+    let types = Types::default()
+        .register::<Config>()
+        .register::<Generic<u8>>()
+        .register::<NotSerialize>();
+    let field = |ty: &str, name: &str| {
+        let ndt = types.into_sorted_iter().find(|ndt| ndt.name == ty).unwrap();
+        let Fields::Named(fields) = named_fields(&ndt.ty) else {
+            panic!("expected named fields");
+        };
+        fields
+            .fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .unwrap()
+            .1
+            .clone()
+    };
+
+    let name = field("Config", "name");
+    assert_eq!(name.attributes.examples(), [r#""api""#, r#""worker""#]);
+    assert_eq!(name.attributes.default_value(), None);
+    assert!(!name.optional);
+
+    let port = field("Config", "port");
+    assert_eq!(port.attributes.examples(), ["80", "443"]);
+    assert_eq!(port.attributes.default_value(), Some("8080"));
+
+    // The function of `#[serde(default = "...")]` is never called.
+    assert_eq!(field("Config", "retries").attributes.default_value(), None);
+
+    assert_eq!(
+        field("Config", "verbose").attributes.default_value(),
+        Some("false")
+    );
+
+    let tags = field("Config", "tags");
+    assert_eq!(tags.attributes.default_value(), Some(r#"["a"]"#));
+    assert!(tags.optional);
+
+    assert_eq!(
+        field("Config", "origin").attributes.examples(),
+        [r#"{"x":1,"y":2}"#]
+    );
+
+    // Inferred defaults can't be evaluated for generic types, but explicit ones can.
+    assert_eq!(field("Generic", "value").attributes.default_value(), None);
+    assert_eq!(
+        field("Generic", "explicit").attributes.default_value(),
+        Some("1")
+    );
+
+    // Types which don't implement `Serialize` are skipped.
+    assert_eq!(
+        field("NotSerialize", "value").attributes.default_value(),
+        None
+    );
+}
+
+#[test]
+fn jsonschema_exports_examples_and_default() {
+    let types = Types::default().register::<Config>().register::<Shape>();
+    let schema = JsonSchema::default()
+        .export_value(&types, specta_serde::Format)
+        .unwrap();
+    let config = &schema["$defs"]["Config"]["properties"];
+
+    assert_eq!(config["name"]["examples"], json!(["api", "worker"]));
+    assert_eq!(config["port"]["examples"], json!([80, 443]));
+    assert_eq!(config["port"]["default"], json!(8080));
+    assert_eq!(config["verbose"]["default"], json!(false));
+    assert_eq!(config["tags"]["default"], json!(["a"]));
+    assert_eq!(config["origin"]["examples"], json!([{ "x": 1, "y": 2 }]));
+
+    let shape = schema["$defs"]["Shape"].to_string();
+    assert!(
+        shape.contains(r#""examples":[{"Circle":{"radius":1.5}}]"#),
+        "{shape}"
+    );
+    assert!(shape.contains(r#""default":"Square""#), "{shape}");
+}
+
+#[test]
+fn openapi_exports_examples_and_default() {
+    let types = Types::default().register::<Config>();
+
+    let document = OpenApi::default()
+        .export_document(&types, specta_serde::Format)
+        .unwrap();
+    let port = &document["components"]["schemas"]["Config"]["properties"]["port"];
+    assert_eq!(port["examples"], json!([80, 443]));
+    assert_eq!(port["default"], json!(8080));
+
+    // OpenAPI 3.0 schemas only have a single `example`.
+    let document = OpenApi::default()
+        .oas_version(OasVersion::V3_0)
+        .schema_mode(SchemaMode::Compatible)
+        .export_document(&types, specta_serde::Format)
+        .unwrap();
+    let port = &document["components"]["schemas"]["Config"]["properties"]["port"];
+    assert_eq!(port["example"], json!(80));
+    assert!(port.get("examples").is_none());
+    assert_eq!(port["default"], json!(8080));
+}
+
+#[test]
+fn typescript_exports_value_tags() {
+    let output = Typescript::default()
+        .export(&Types::default().register::<Config>(), specta_serde::Format)
+        .unwrap();
+
+    assert!(
+        output.contains(
+            "\t/**\n\t *  The name of the service.\n\t * @example \"api\"\n\t * @example \"worker\"\n\t */\n\tname: string,"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains("\t/**\n\t * @default 8080\n\t * @example 80\n\t * @example 443\n\t */\n\tport?: number,"),
+        "{output}"
+    );
+    assert!(
+        output.contains("\t/** @default false */\n\tverbose?: boolean,"),
+        "{output}"
+    );
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct Comment {
+    /// The comment's text.
+    #[specta(example = "a */ b")]
+    text: String,
+}
+
+#[test]
+fn jsdoc_exports_value_tags() {
+    let output = specta_typescript::JSDoc::default()
+        .export(
+            &Types::default().register::<Config>().register::<Comment>(),
+            specta_serde::Format,
+        )
+        .unwrap();
+
+    assert!(
+        output.contains("\t* @property {number} [port] - @default 8080 @example 80 @example 443\n"),
+        "{output}"
+    );
+    assert!(
+        output.contains("\t* @property {boolean} [verbose] - @default false\n"),
+        "{output}"
+    );
+    assert!(
+        output
+            .contains("\t* @property {string} text - The comment's text. @example \"a *\\/ b\"\n"),
+        "{output}"
+    );
+}
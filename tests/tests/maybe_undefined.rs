@@ -5,7 +5,7 @@
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use specta::{Type, Types, datatype::DataType};
-use specta_typescript::Typescript;
+use specta_typescript::{JSDoc, OptionalStyle, Typescript};
 
 /// Represents a field that may be undefined, null, or have a value.
 ///
@@ -102,3 +102,69 @@ fn maybe_undefined_single_phase() {
         .expect("unified typescript export should widen conditional omission");
     insta::assert_snapshot!("maybe-undefined-single-phase", ts);
 }
+
+#[test]
+fn maybe_undefined_optional_styles() {
+    let types = Types::default().register::<UpdateUser>();
+    let export = |style| {
+        let ts = Typescript::default()
+            .optional_style(style)
+            .export(&types, specta_serde::Format)
+            .unwrap();
+        let zod = specta_zod::Zod::default()
+            .optional_style(style)
+            .export(&types, specta_serde::Format)
+            .unwrap();
+        let valibot = specta_valibot::Valibot::default()
+            .optional_style(style)
+            .export(&types, specta_serde::Format)
+            .unwrap();
+        (ts, zod, valibot)
+    };
+
+    let (ts, zod, valibot) = export(OptionalStyle::Optional);
+    assert!(ts.contains("\tname?: string | null,\n"), "{ts}");
+    assert!(
+        zod.contains("name: z.string().nullable().optional(),"),
+        "{zod}"
+    );
+    assert!(
+        valibot.contains("name: v.optional(v.nullable("),
+        "{valibot}"
+    );
+
+    let (ts, zod, valibot) = export(OptionalStyle::OptionalUndefined);
+    assert!(ts.contains("\tname?: string | null | undefined,\n"), "{ts}");
+    assert!(
+        zod.contains("\tname?: string | null | undefined,\n"),
+        "{zod}"
+    );
+    assert!(
+        zod.contains("name: z.string().nullable().optional(),"),
+        "{zod}"
+    );
+    assert!(
+        valibot.contains("name: v.optional(v.nullable("),
+        "{valibot}"
+    );
+
+    let (ts, zod, valibot) = export(OptionalStyle::ExactOptional);
+    assert!(ts.contains("\tname?: string | null,\n"), "{ts}");
+    assert!(
+        zod.contains("name: z.string().nullable().exactOptional(),"),
+        "{zod}"
+    );
+    assert!(
+        valibot.contains("name: v.exactOptional(v.nullable("),
+        "{valibot}"
+    );
+
+    let jsdoc = JSDoc::default()
+        .optional_style(OptionalStyle::OptionalUndefined)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        jsdoc.contains("* @property {string | null | undefined} [name]"),
+        "{jsdoc}"
+    );
+}
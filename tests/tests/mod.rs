@@ -12,7 +12,9 @@ macro_rules! register {
     }};
 }
 
+mod arktype;
 mod bound;
+mod collect;
 mod core_type_impls;
 mod csharp;
 mod errors;
@@ -24,16 +26,22 @@ mod jsonschema;
 mod kotlin;
 mod layouts;
 mod legacy_impls;
+mod literals;
 mod macro_doc_attrs;
 mod macro_generic_variant_override;
+mod macro_rename;
 mod macro_repr_transparent_enum;
 mod macro_structured_deprecated;
 mod macro_thiserror_attrs;
+mod macro_values;
 mod maybe_undefined;
+mod monomorphise;
+mod name_resolution;
 mod openapi;
 mod python;
 mod references;
 mod rust;
+mod selection;
 mod semantic;
 mod serde_container_rename;
 mod serde_conversions;
@@ -44,16 +52,20 @@ mod serde_flatten_option;
 mod serde_identifiers;
 mod serde_internal_tag_payloads;
 mod serde_other;
+mod serde_repr;
 mod serde_unified_asymmetry;
 mod serde_unknown_attrs;
 mod serde_untagged_unit;
 mod serde_validate_coverage;
 mod serde_validate_recursion;
 mod swift;
+mod type_graph;
+mod typebox;
 mod types;
 mod typescript;
 mod utils;
 mod valibot;
+mod visit;
 mod zod;
 
 pub use types::{types, types_phased};
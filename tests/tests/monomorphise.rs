@@ -0,0 +1,160 @@
+use serde::Serialize;
+use specta::{Format, Monomorphise, Type, Types, datatype::DataType};
+use specta_jsonschema::JsonSchema;
+use specta_typescript::Typescript;
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct User {
+    id: u32,
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct Page<T> {
+    items: Vec<T>,
+    next: Option<Cursor<T>>,
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct Cursor<T> {
+    after: T,
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct Response {
+    users: Page<User>,
+    ids: Page<u32>,
+    nested: Page<Vec<String>>,
+    tree: Tree<bool>,
+}
+
+fn monomorphised() -> Types {
+    let types = Types::default().register::<Response>();
+    Monomorphise::new(specta_serde::Format)
+        .map_types(&types)
+        .unwrap()
+        .into_owned()
+}
+
+fn names(types: &Types) -> Vec<&str> {
+    types
+        .into_sorted_iter()
+        .map(|ndt| ndt.name.as_ref())
+        .filter(|name| !["String", "Vec", "Option"].contains(name))
+        .collect()
+}
+
+#[test]
+fn instantiates_every_used_generic() {
+    let types = monomorphised();
+
+    assert_eq!(
+        names(&types),
+        [
+            "CursorListString",
+            "CursorU32",
+            "CursorUser",
+            "PageListString",
+            "PageU32",
+            "PageUser",
+            "Response",
+            "TreeBool",
+            "User",
+        ]
+    );
+    assert!(types.into_sorted_iter().all(|ndt| ndt.generics.is_empty()));
+
+    let output = Typescript::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(output.contains("users: PageUser"), "{output}");
+    assert!(output.contains("ids: PageU32"), "{output}");
+    assert!(output.contains("nested: PageListString"), "{output}");
+    assert!(output.contains("next: CursorU32 | null"), "{output}");
+    assert!(output.contains("children: TreeBool[]"), "{output}");
+    assert!(output.contains("after: string[]"), "{output}");
+    assert!(!output.contains('<'), "{output}");
+}
+
+#[test]
+fn rewrites_datatypes_and_roots() {
+    let types = Types::default().register::<Page<User>>();
+    let format = Monomorphise::new(specta_serde::Format);
+    let mapped = format.map_types(&types).unwrap().into_owned();
+
+    let root = mapped.roots().next().unwrap();
+    let DataType::Reference(specta::datatype::Reference::Named(reference)) = root else {
+        panic!("expected a named reference");
+    };
+    assert_eq!(mapped.get(reference).unwrap().name, "PageUser");
+
+    let original = types.roots().next().unwrap();
+    let rewritten = format.map_type(&mapped, original).unwrap();
+    assert_eq!(rewritten.as_ref(), root);
+
+    let value = JsonSchema::default().export_value(&types, format).unwrap();
+    let defs = value
+        .get("$defs")
+        .or_else(|| value.get("definitions"))
+        .unwrap();
+    assert!(defs.get("PageUser").is_some(), "{value:#}");
+    assert!(defs.get("CursorUser").is_some(), "{value:#}");
+    assert!(defs.get("Page").is_none(), "{value:#}");
+}
+
+#[test]
+fn custom_naming() {
+    let types = Types::default().register::<Response>();
+    let types = Monomorphise::new(specta_serde::Format)
+        .naming(|ndt, arguments| format!("{}Of{}", ndt.name, arguments.join("And")))
+        .map_types(&types)
+        .unwrap()
+        .into_owned();
+
+    let names = names(&types);
+    assert!(names.contains(&"PageOfUser"), "{names:?}");
+    assert!(names.contains(&"PageOfListString"), "{names:?}");
+    assert!(names.contains(&"TreeOfBool"), "{names:?}");
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct PageU32 {
+    total: u32,
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct Clashing {
+    ids: Page<u32>,
+    summary: PageU32,
+}
+
+#[test]
+fn colliding_instantiations_are_an_error() {
+    let types = Types::default().register::<Clashing>();
+    let err = Monomorphise::new(specta_serde::Format)
+        .map_types(&types)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("`PageU32`"), "{err}");
+    assert!(err.contains("monomorphise::PageU32"), "{err}");
+
+    let types = Types::default().register::<Response>();
+    let err = Monomorphise::new(specta_serde::Format)
+        .naming(|ndt, _| ndt.name.to_string())
+        .map_types(&types)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("`Cursor`"), "{err}");
+}
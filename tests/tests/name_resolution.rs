@@ -0,0 +1,148 @@
+use serde::Serialize;
+use specta::{Format, NameCollisions, NameResolution, ResolveNames, Type, Types};
+use specta_typescript::Typescript;
+
+mod billing {
+    use serde::Serialize;
+    use specta::Type;
+
+    #[derive(Type, Serialize)]
+    #[specta(collect = false)]
+    #[serde(tag = "type")]
+    pub struct Error {
+        pub amount: u32,
+    }
+}
+
+mod auth {
+    use serde::Serialize;
+    use specta::Type;
+
+    #[derive(Type, Serialize)]
+    #[specta(collect = false)]
+    pub struct Error {
+        pub reason: String,
+    }
+
+    pub mod billing {
+        use serde::Serialize;
+        use specta::Type;
+
+        #[derive(Type, Serialize)]
+        #[specta(collect = false)]
+        pub struct Error {
+            pub code: u32,
+        }
+    }
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct Errors {
+    billing: billing::Error,
+    auth: auth::Error,
+}
+
+#[derive(Type, Serialize)]
+#[specta(collect = false)]
+struct NestedErrors {
+    billing: billing::Error,
+    auth_billing: auth::billing::Error,
+}
+
+fn names(types: &Types) -> Vec<&str> {
+    types
+        .into_sorted_iter()
+        .map(|ndt| ndt.name.as_ref())
+        .filter(|name| *name != "String")
+        .collect()
+}
+
+#[test]
+fn collisions_error_by_default() {
+    let types = Types::default().register::<Errors>();
+    let err = types.resolve_names(&NameResolution::new()).unwrap_err();
+    assert_eq!(err.name, "Error");
+    assert_eq!(
+        err.paths,
+        [
+            concat!(module_path!(), "::auth::Error"),
+            concat!(module_path!(), "::billing::Error"),
+        ]
+    );
+    assert!(
+        err.to_string()
+            .starts_with("multiple types are exported with the name `Error`"),
+        "{err}"
+    );
+}
+
+#[test]
+fn module_prefix_only_renames_collisions() {
+    let resolution = NameResolution::new().collisions(NameCollisions::ModulePrefix);
+
+    let types = Types::default()
+        .register::<Errors>()
+        .resolve_names(&resolution)
+        .unwrap();
+    assert_eq!(names(&types), ["AuthError", "BillingError", "Errors"]);
+
+    // `billing` is ambiguous so more of the module path is used.
+    let types = Types::default()
+        .register::<NestedErrors>()
+        .resolve_names(&resolution)
+        .unwrap();
+    assert_eq!(
+        names(&types),
+        [
+            "AuthBillingError",
+            "NameResolutionBillingError",
+            "NestedErrors"
+        ]
+    );
+}
+
+#[test]
+fn explicit_renames() {
+    let types = Types::default()
+        .register::<Errors>()
+        .resolve_names(
+            &NameResolution::new().rename(concat!(module_path!(), "::auth::Error"), "AuthFailure"),
+        )
+        .unwrap();
+    assert_eq!(names(&types), ["AuthFailure", "Error", "Errors"]);
+
+    // Renaming into another type's name is also a collision.
+    let err = Types::default()
+        .register::<Errors>()
+        .resolve_names(
+            &NameResolution::new().rename(concat!(module_path!(), "::auth::Error"), "Errors"),
+        )
+        .unwrap_err();
+    assert_eq!(err.name, "Errors");
+}
+
+#[test]
+fn format_wrapper_keeps_wire_names() {
+    let types = Types::default().register::<Errors>();
+    let format = ResolveNames::new(
+        specta_serde::Format,
+        NameResolution::new().collisions(NameCollisions::ModulePrefix),
+    );
+
+    let mapped = format.map_types(&types).unwrap();
+    assert_eq!(names(&mapped), ["AuthError", "BillingError", "Errors"]);
+
+    let output = Typescript::default().export(&types, format).unwrap();
+    assert!(output.contains("export type BillingError = {"), "{output}");
+    assert!(output.contains("export type AuthError = {"), "{output}");
+    assert!(output.contains("billing: BillingError"), "{output}");
+    // The internal tag is still the Rust type's name.
+    assert!(output.contains(r#"type: "Error""#), "{output}");
+
+    assert!(
+        Typescript::default()
+            .export(&types, specta_serde::Format)
+            .is_err()
+    );
+}
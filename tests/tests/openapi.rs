@@ -655,6 +655,87 @@ fn openapi_supports_yaml_export_to_and_document_merging() {
     );
 }
 
+/// A split document is one file per component and per path, linked by
+/// relative `$ref`s, and bundling it reproduces the single-file document.
+#[test]
+fn openapi_split_export_round_trips_through_bundle() {
+    let types = Types::default()
+        .register::<UsesGenerics>()
+        .register::<ReferencedField>()
+        .register::<Recipe>()
+        .register::<ApiError>();
+    let operations = || {
+        [
+            Operation::get("/recipes/{slug}")
+                .path_param::<String>("slug")
+                .response::<Recipe>(200, "The recipe")
+                .response::<ApiError>(404, "No such recipe"),
+            Operation::get("/").response::<ReferencedField>(200, "Index"),
+        ]
+    };
+
+    for format in [OutputFormat::Yaml, OutputFormat::Json] {
+        let exporter = OpenApi::default()
+            .output_format(format)
+            .operations(operations());
+        let expected = exporter
+            .export_document(&types, specta_serde::Format)
+            .expect("single-file export should succeed");
+
+        let dir = tempfile::tempdir().unwrap();
+        exporter
+            .export_split(dir.path(), &types, specta_serde::Format)
+            .expect("split export should succeed");
+
+        let extension = match format {
+            OutputFormat::Json => "json",
+            OutputFormat::Yaml => "yaml",
+        };
+        let root = dir.path().join(format!("openapi.{extension}"));
+        let source = std::fs::read_to_string(&root).unwrap();
+        assert!(source.contains(&format!("paths/recipes_slug.{extension}")));
+        assert!(source.contains(&format!("components/schemas/Recipe.{extension}")));
+        assert!(dir.path().join(format!("paths/root.{extension}")).is_file());
+        let path_item =
+            std::fs::read_to_string(dir.path().join(format!("paths/recipes_slug.{extension}")))
+                .unwrap();
+        assert!(path_item.contains(&format!("../components/schemas/ApiError.{extension}")));
+        let schema = std::fs::read_to_string(
+            dir.path()
+                .join(format!("components/schemas/ReferencedField.{extension}")),
+        )
+        .unwrap();
+        assert!(schema.contains(&format!("ApiEvent.{extension}")));
+        assert!(!schema.contains("#/components/schemas"));
+
+        let bundled = specta_openapi::bundle(&root).expect("bundling should succeed");
+        assert_eq!(bundled, expected);
+    }
+}
+
+#[test]
+fn openapi_split_export_suffixes_colliding_file_names() {
+    let dir = tempfile::tempdir().unwrap();
+    let api = OpenApi::default()
+        .operation(Operation::get("/a/b").response::<Recipe>(200, "ok"))
+        .operation(Operation::get("/a_b").response::<Recipe>(200, "ok"))
+        .operation(Operation::get("/A_B").response::<Recipe>(200, "ok"));
+    let types = Types::default().register::<Recipe>();
+    api.export_split(dir.path(), &types, specta_serde::Format)
+        .expect("paths sharing a file name should be suffixed");
+
+    let root: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.path().join("openapi.json")).unwrap())
+            .unwrap();
+    assert_eq!(root["paths"]["/A_B"]["$ref"], "paths/A_B.json");
+    assert_eq!(root["paths"]["/a/b"]["$ref"], "paths/a_b_2.json");
+    assert_eq!(root["paths"]["/a_b"]["$ref"], "paths/a_b_3.json");
+
+    let expected = api.export_document(&types, specta_serde::Format).unwrap();
+    let bundled = specta_openapi::bundle(dir.path().join("openapi.json")).unwrap();
+    assert_eq!(bundled["paths"], expected["paths"]);
+}
+
 #[derive(Type)]
 #[specta(collect = false)]
 struct Recipe {
@@ -1112,6 +1193,135 @@ fn request_body_examples_are_typed_values() {
     );
 }
 
+/// Named examples attach to request bodies, responses and parameters, and each
+/// is checked against the schema of what it exemplifies at export.
+#[test]
+fn named_examples_are_emitted_and_validated() {
+    use specta_openapi::{Error, Example, Param};
+
+    #[derive(Type, Serialize)]
+    #[specta(collect = false)]
+    struct Pet {
+        name: String,
+        age: Option<u8>,
+    }
+
+    let operation = || {
+        Operation::post("/pets")
+            .parameter(
+                Param::query::<u32>("limit")
+                    .named_example("small", Example::new(5).summary("A small page")),
+            )
+            .request_body::<Pet>()
+            .request_body_example(
+                "minimal",
+                Example::new(Pet {
+                    name: "Rex".into(),
+                    age: None,
+                })
+                .description("Only the required fields"),
+            )
+            .response::<Pet>(201, "Created")
+            .response_example(
+                201,
+                "created",
+                Example::new(serde_json::json!({ "name": "Rex", "age": 3 })),
+            )
+    };
+    let types = Types::default().register::<Pet>();
+
+    for version in [OasVersion::V3_1, OasVersion::V3_0] {
+        let document = OpenApi::default()
+            .oas_version(version)
+            .schema_mode(SchemaMode::Compatible)
+            .operation(operation())
+            .export_document(&types, specta_serde::Format)
+            .expect("matching examples should export");
+        let post = &document["paths"]["/pets"]["post"];
+        assert_eq!(
+            post["parameters"][0]["examples"]["small"],
+            serde_json::json!({ "summary": "A small page", "value": 5 })
+        );
+        let request = &post["requestBody"]["content"]["application/json"];
+        assert_eq!(request["examples"]["minimal"]["value"]["name"], "Rex");
+        assert_eq!(
+            request["examples"]["minimal"]["description"],
+            "Only the required fields"
+        );
+        assert!(request.get("example").is_none());
+        assert_eq!(
+            post["responses"]["201"]["content"]["application/json"]["examples"]["created"]["value"]
+                ["age"],
+            3
+        );
+    }
+
+    let error = OpenApi::default()
+        .operation(operation().response_example(
+            201,
+            "stale",
+            Example::new(serde_json::json!({ "name": 7, "age": null })),
+        ))
+        .export_document(&types, specta_serde::Format)
+        .expect_err("a mismatched example should fail the export");
+    match &error {
+        Error::InvalidExample {
+            operation,
+            example,
+            reason,
+        } => {
+            assert_eq!(operation, "POST /pets");
+            assert_eq!(example, "stale");
+            assert!(reason.contains("/name"), "{reason}");
+        }
+        error => panic!("unexpected error: {error}"),
+    }
+
+    // Unnamed examples are held to the same standard.
+    let error = OpenApi::default()
+        .operation(
+            Operation::get("/pets")
+                .parameter(Param::query::<u32>("limit").example(serde_json::json!("ten")))
+                .response::<Pet>(200, "ok"),
+        )
+        .export_document(&types, specta_serde::Format)
+        .expect_err("a mismatched parameter example should fail the export");
+    assert!(
+        matches!(&error, Error::InvalidExample { example, .. } if example == "example"),
+        "{error}"
+    );
+
+    // The unnamed example joins named ones as `example`, so it can't share that name.
+    let error = OpenApi::default()
+        .operation(
+            Operation::get("/pets")
+                .parameter(
+                    Param::query::<u32>("limit")
+                        .example(serde_json::json!(5))
+                        .named_example("example", Example::new(10)),
+                )
+                .response::<Pet>(200, "ok"),
+        )
+        .export_document(&types, specta_serde::Format)
+        .expect_err("colliding example names should fail the export");
+    assert!(
+        matches!(&error, Error::DuplicateExample { operation } if operation == "GET /pets"),
+        "{error}"
+    );
+
+    // An example for a status with no body has nothing to exemplify.
+    let error = OpenApi::default()
+        .operation(
+            Operation::delete("/pets/{id}")
+                .path_param::<u32>("id")
+                .empty_response(204, "Deleted")
+                .response_example(204, "gone", Example::new(serde_json::json!(null))),
+        )
+        .export_document(&types, specta_serde::Format)
+        .expect_err("an example without a response body should fail the export");
+    assert!(matches!(error, Error::InvalidExample { .. }), "{error}");
+}
+
 /// Error responses can be served as `application/problem+json`, security
 /// schemes register on the document, and operations state their security
 /// alternatives, the anonymous option included.
@@ -1490,3 +1700,390 @@ fn plain_option_fields_split_per_phase() {
         serde_json::json!(["id"])
     );
 }
+
+mod axum_routes {
+    use axum::{
+        Json,
+        extract::{Path, Query, State},
+        http::StatusCode,
+    };
+    use axum_extra::{
+        TypedHeader,
+        headers::{Authorization, ContentLength, authorization::Bearer},
+    };
+    use serde::{Deserialize, Serialize};
+    use specta::{Type, Types};
+    use specta_openapi::{
+        OpenApi, Operation,
+        axum::{ApiRouter, OperationOutput, delete, get},
+    };
+
+    #[derive(Type, Serialize, Deserialize)]
+    #[specta(collect = false)]
+    pub struct Recipe {
+        slug: String,
+    }
+
+    #[derive(Type, Deserialize)]
+    #[specta(collect = false)]
+    pub struct NewRecipe {
+        slug: String,
+    }
+
+    #[derive(Type, Deserialize)]
+    #[specta(collect = false)]
+    pub struct Search {
+        term: String,
+        limit: Option<u32>,
+    }
+
+    #[derive(Type, Serialize)]
+    #[specta(collect = false)]
+    pub struct ApiError {
+        message: String,
+    }
+
+    impl axum::response::IntoResponse for ApiError {
+        fn into_response(self) -> axum::response::Response {
+            (axum::http::StatusCode::NOT_FOUND, Json(self)).into_response()
+        }
+    }
+
+    impl OperationOutput for ApiError {
+        fn describe(operation: Operation) -> Operation {
+            operation.response::<ApiError>(404, "No such recipe")
+        }
+    }
+
+    async fn recipe(Path(slug): Path<String>) -> Result<Json<Recipe>, ApiError> {
+        Ok(Json(Recipe { slug }))
+    }
+
+    async fn step(Path((slug, index)): Path<(String, u32)>) -> String {
+        format!("{slug} {index}")
+    }
+
+    async fn search(State(_): State<()>, Query(_): Query<Search>) -> Json<Vec<Recipe>> {
+        Json(Vec::new())
+    }
+
+    async fn create(
+        TypedHeader(_): TypedHeader<Authorization<Bearer>>,
+        Json(recipe): Json<NewRecipe>,
+    ) -> Json<Recipe> {
+        Json(Recipe { slug: recipe.slug })
+    }
+
+    fn router() -> ApiRouter {
+        ApiRouter::new()
+            .route(
+                "/recipes",
+                get(search).post(create).describe(|op| op.tag("recipes")),
+            )
+            .route("/recipes/{slug}", get(recipe))
+            .nest(
+                "/v2",
+                ApiRouter::new().route("/recipes/{slug}/steps/{index}", get(step)),
+            )
+    }
+
+    fn types() -> Types {
+        Types::default()
+            .register::<Recipe>()
+            .register::<NewRecipe>()
+            .register::<ApiError>()
+    }
+
+    #[test]
+    fn openapi_describes_axum_routes_from_handler_signatures() {
+        let (_router, operations): (axum::Router, _) = router().into_parts();
+        let document = OpenApi::default()
+            .operations(operations)
+            .export_document(&types(), specta_serde::Format)
+            .expect("axum operations should export");
+
+        let get = &document["paths"]["/recipes/{slug}"]["get"];
+        assert_eq!(get["operationId"], "recipe");
+        assert_eq!(get["parameters"][0]["name"], "slug");
+        assert_eq!(get["parameters"][0]["in"], "path");
+        assert_eq!(get["parameters"][0]["schema"]["type"], "string");
+        assert_eq!(
+            get["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/Recipe"
+        );
+        assert_eq!(
+            get["responses"]["404"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/ApiError"
+        );
+
+        // Query structs expand to one parameter per field; state contributes nothing.
+        let search = &document["paths"]["/recipes"]["get"];
+        assert_eq!(search["tags"], serde_json::Value::Null);
+        assert_eq!(search["parameters"][0]["name"], "term");
+        assert_eq!(search["parameters"][0]["in"], "query");
+        assert_eq!(search["parameters"][0]["required"], true);
+        assert_eq!(search["parameters"][1]["name"], "limit");
+        assert!(search["parameters"][1].get("required").is_none());
+        assert_eq!(
+            search["responses"]["200"]["content"]["application/json"]["schema"]["type"],
+            "array"
+        );
+
+        // `describe` amends only the handler chained before it.
+        let create = &document["paths"]["/recipes"]["post"];
+        assert_eq!(create["tags"][0], "recipes");
+        assert_eq!(create["parameters"][0]["name"], "authorization");
+        assert_eq!(create["parameters"][0]["in"], "header");
+        assert_eq!(create["parameters"][0]["required"], true);
+        assert_eq!(
+            create["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/NewRecipe"
+        );
+
+        // Tuple paths bind in template order, under the nesting prefix.
+        let step = &document["paths"]["/v2/recipes/{slug}/steps/{index}"]["get"];
+        assert_eq!(step["parameters"][0]["name"], "slug");
+        assert_eq!(step["parameters"][1]["name"], "index");
+        assert_eq!(step["parameters"][1]["schema"]["type"], "integer");
+        assert!(
+            step["responses"]["200"]["content"]
+                .get("text/plain; charset=utf-8")
+                .is_some()
+        );
+    }
+
+    #[derive(Type, Deserialize)]
+    #[specta(collect = false)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Page {
+        page_size: u32,
+        #[serde(rename = "q")]
+        query: Option<String>,
+        #[serde(skip)]
+        #[allow(dead_code)]
+        cursor: String,
+    }
+
+    async fn list(
+        TypedHeader(_): TypedHeader<ContentLength>,
+        Query(_): Query<Page>,
+    ) -> (StatusCode, Json<Recipe>) {
+        (
+            StatusCode::OK,
+            Json(Recipe {
+                slug: String::new(),
+            }),
+        )
+    }
+
+    async fn remove(Path(_): Path<String>) -> StatusCode {
+        StatusCode::NO_CONTENT
+    }
+
+    #[test]
+    fn openapi_axum_uses_serde_names_header_types_and_runtime_statuses() {
+        let operations = ApiRouter::<()>::new()
+            .route("/recipes", get(list))
+            .route("/recipes/{slug}", delete(remove))
+            .operations()
+            .to_vec();
+        let document = OpenApi::default()
+            .operations(operations)
+            .export_document(&types(), specta_serde::Format)
+            .expect("axum operations should export");
+
+        let list = &document["paths"]["/recipes"]["get"];
+        assert_eq!(list["parameters"][0]["name"], "content-length");
+        assert_eq!(list["parameters"][0]["schema"]["type"], "integer");
+        assert_eq!(list["parameters"][1]["name"], "pageSize");
+        assert_eq!(list["parameters"][2]["name"], "q");
+        assert!(list["parameters"].get(3).is_none());
+        assert!(list["responses"].get("200").is_none());
+        assert_eq!(
+            list["responses"]["default"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/Recipe"
+        );
+
+        let remove = &document["paths"]["/recipes/{slug}"]["delete"];
+        assert!(remove["responses"]["default"].get("content").is_none());
+    }
+
+    #[test]
+    fn openapi_axum_operations_require_registered_types() {
+        let error = OpenApi::default()
+            .operations(router().operations().iter().cloned())
+            .export_document(&Types::default(), specta_serde::Format)
+            .expect_err("unregistered handler types should be rejected");
+        assert!(matches!(
+            error,
+            specta_openapi::Error::UnregisteredOperationType { .. }
+        ));
+    }
+}
+
+mod typescript_client {
+    use serde::{Deserialize, Serialize};
+    use specta::{Type, Types};
+    use specta_openapi::{OpenApi, Operation, Param, typescript::TypescriptClient};
+    use specta_typescript::{Exporter, Typescript};
+
+    #[derive(Type, Serialize, Deserialize)]
+    #[specta(collect = false)]
+    struct Recipe {
+        slug: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+    }
+
+    #[derive(Type, Serialize)]
+    #[specta(collect = false)]
+    struct ApiError {
+        message: String,
+    }
+
+    fn render(
+        api: &OpenApi,
+        format: impl specta::Format,
+    ) -> Result<String, specta_typescript::Error> {
+        let client = TypescriptClient::new(api);
+        Exporter::from(Typescript::default())
+            .framework_runtime(move |exporter| client.render(&exporter))
+            .export(
+                &Types::default().register::<Recipe>().register::<ApiError>(),
+                format,
+            )
+    }
+
+    #[test]
+    fn renders_one_function_per_operation_sharing_named_types() {
+        let api = OpenApi::default()
+            .operation(
+                Operation::get("/recipes/{slug}")
+                    .operation_id("get_recipe")
+                    .summary("Fetch one recipe")
+                    .path_param::<String>("slug")
+                    .query_param::<String>("lang")
+                    .parameter(Param::header::<String>("x-request-id").required())
+                    .response::<Recipe>(200, "The recipe")
+                    .response::<ApiError>(404, "No such recipe"),
+            )
+            .operation(
+                Operation::put("/recipes/{slug}")
+                    .path_param::<String>("slug")
+                    .request_body::<Recipe>()
+                    .empty_response(204, "Stored"),
+            )
+            .operation(Operation::get("/health").response_as::<String>(
+                200,
+                "Liveness",
+                "text/plain",
+            ));
+        let output = render(&api, specta_serde::Format).unwrap();
+
+        assert_eq!(
+            output.matches("export type Recipe = ").count(),
+            1,
+            "{output}"
+        );
+        assert!(output.contains("export type ClientOptions = {"), "{output}");
+        assert!(
+            output.contains(
+                "/**\n * Fetch one recipe\n */\nexport async function getRecipe(\n\tinput: {\n\t\tpath: { slug: string };\n\t\tquery?: { lang?: string };\n\t\theaders: { \"x-request-id\": string };\n\t},\n\toptions: ClientOptions = {},\n): Promise<\n\t| { status: 200; data: Recipe }\n\t| { status: 404; data: ApiError }\n> {"
+            ),
+            "{output}"
+        );
+        assert!(
+            output.contains(
+                "await request(options, \"GET\", `/recipes/${encodeURIComponent(String(input.path.slug))}`, { query: input.query, headers: input.headers });"
+            ),
+            "{output}"
+        );
+        assert!(
+            output.contains("export async function putRecipesSlug("),
+            "{output}"
+        );
+        assert!(output.contains("\t\tbody: Recipe;\n"), "{output}");
+        assert!(
+            output.contains("case 204:\n\t\t\treturn { status: 204, data: undefined };"),
+            "{output}"
+        );
+        assert!(
+            output.contains("export async function getHealth(\n\toptions: ClientOptions = {},\n)"),
+            "{output}"
+        );
+        assert!(
+            output
+                .contains("case 200:\n\t\t\treturn { status: 200, data: await response.text() };"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn default_responses_return_the_runtime_status() {
+        let api = OpenApi::default().operation(
+            Operation::delete("/recipes/{slug}")
+                .operation_id("delete_recipe")
+                .path_param::<String>("slug")
+                .empty_response(204, "Deleted")
+                .default_response::<ApiError>("Failure"),
+        );
+        let output = render(&api, specta_serde::Format).unwrap();
+
+        assert!(
+            output.contains(
+                "\t| { status: 204; data: undefined }\n\t| { status: number; data: ApiError }"
+            ),
+            "{output}"
+        );
+        assert!(
+            output.contains(
+                "default:\n\t\t\treturn { status: response.status, data: await response.json() };"
+            ),
+            "{output}"
+        );
+        assert!(!output.contains("unexpected status"), "{output}");
+    }
+
+    #[test]
+    fn request_and_response_types_resolve_per_phase() {
+        let api = OpenApi::default().operation(
+            Operation::post("/recipes")
+                .request_body::<Recipe>()
+                .response::<Recipe>(201, "Created"),
+        );
+        let output = render(&api, specta_serde::PhasesFormat).unwrap();
+
+        assert!(
+            output.contains("\t\tbody: Recipe_Deserialize;\n"),
+            "{output}"
+        );
+        assert!(
+            output.contains("{ status: 201; data: Recipe_Serialize }"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn colliding_function_names_are_rejected() {
+        let api = OpenApi::default()
+            .operation(
+                Operation::get("/a")
+                    .operation_id("list_items")
+                    .empty_response(204, "Done"),
+            )
+            .operation(
+                Operation::get("/b")
+                    .operation_id("listItems")
+                    .empty_response(204, "Done"),
+            );
+        let error = render(&api, specta_serde::Format).unwrap_err().to_string();
+        assert!(
+            error.contains("failed to render the TypeScript client"),
+            "{error}"
+        );
+
+        let api =
+            OpenApi::default().operation(Operation::get("/items/{id}").empty_response(204, "Done"));
+        assert!(render(&api, specta_serde::Format).is_err());
+    }
+}
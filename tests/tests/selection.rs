@@ -0,0 +1,180 @@
+use specta::{Selection, SelectionError, Types};
+
+mod public {
+    use specta::Type;
+
+    #[derive(Type)]
+    #[specta(selection_tag = "public", collect = false)]
+    pub struct User {
+        pub id: super::shared::UserId,
+        pub name: String,
+    }
+
+    #[derive(Type)]
+    #[specta(selection_tag = "public", selection_tag = "admin", collect = false)]
+    pub struct UserSummary {
+        pub id: super::shared::UserId,
+    }
+}
+
+mod admin {
+    use specta::Type;
+
+    #[derive(Type)]
+    #[specta(selection_tag = "admin", collect = false)]
+    pub struct Ban {
+        pub user: super::public::User,
+        pub reason: super::internal::Secret,
+    }
+
+    #[derive(Type)]
+    #[specta(selection_tag = "admin", collect = false)]
+    pub struct BanRequest {
+        pub user: super::public::User,
+    }
+}
+
+mod shared {
+    use specta::Type;
+
+    #[derive(Type)]
+    #[specta(collect = false)]
+    pub struct UserId(pub u64);
+}
+
+mod internal {
+    use specta::Type;
+
+    #[derive(Type)]
+    #[specta(collect = false)]
+    pub struct Secret(pub String);
+}
+
+fn types() -> Types {
+    Types::default()
+        .register::<public::UserSummary>()
+        .register::<admin::Ban>()
+        .register::<admin::BanRequest>()
+}
+
+fn names(types: &Types) -> Vec<&str> {
+    types
+        .into_sorted_iter()
+        .map(|ndt| ndt.name.as_ref())
+        .filter(|name| *name != "String")
+        .collect()
+}
+
+#[test]
+fn tags_are_recorded() {
+    let types = types();
+    let summary = types
+        .into_unsorted_iter()
+        .find(|ndt| ndt.name == "UserSummary")
+        .unwrap();
+    assert_eq!(summary.tags.as_ref(), ["public", "admin"]);
+}
+
+#[test]
+fn select_by_tag_includes_dependencies() {
+    let types = types();
+
+    let public = types.select(&Selection::new().tag("public")).unwrap();
+    assert_eq!(names(&public), ["User", "UserId", "UserSummary"]);
+    // Only the `UserSummary` registration is kept, the others refer to unselected types.
+    assert_eq!(public.roots().len(), 1);
+
+    let admin = types.select(&Selection::new().tag("admin")).unwrap();
+    assert_eq!(
+        names(&admin),
+        [
+            "Ban",
+            "BanRequest",
+            "Secret",
+            "User",
+            "UserId",
+            "UserSummary"
+        ]
+    );
+}
+
+#[test]
+fn select_by_module_and_name() {
+    let types = types();
+
+    let shared = types
+        .select(&Selection::new().module(concat!(module_path!(), "::shared")))
+        .unwrap();
+    assert_eq!(names(&shared), ["UserId"]);
+
+    // Module paths only match whole segments.
+    let none = types
+        .select(&Selection::new().module(concat!(module_path!(), "::share")))
+        .unwrap();
+    assert!(none.is_empty());
+
+    let users = types.select(&Selection::new().name("User*")).unwrap();
+    assert_eq!(names(&users), ["User", "UserId", "UserSummary"]);
+
+    let bans = types.select(&Selection::new().name("*Ban*")).unwrap();
+    assert_eq!(
+        names(&bans),
+        ["Ban", "BanRequest", "Secret", "User", "UserId"]
+    );
+
+    let exact = types.select(&Selection::new().name("User")).unwrap();
+    assert_eq!(names(&exact), ["User", "UserId"]);
+
+    let custom = types
+        .select(&Selection::new().filter(|ndt| ndt.name.len() == 3))
+        .unwrap();
+    assert_eq!(names(&custom), ["Ban", "Secret", "User", "UserId"]);
+}
+
+#[test]
+fn excluded_dependencies_are_an_error() {
+    let types = types();
+
+    let err = types
+        .select(
+            &Selection::new()
+                .tag("admin")
+                .exclude_module(concat!(module_path!(), "::internal")),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        SelectionError::DependsOnExcluded {
+            selected: concat!(module_path!(), "::admin::Ban").into(),
+            excluded: concat!(module_path!(), "::internal::Secret").into(),
+        }
+    );
+    assert!(
+        err.to_string().contains("depends on excluded type"),
+        "{err}"
+    );
+
+    // Excluding a type nothing selected depends on is fine.
+    let admin = types
+        .select(&Selection::new().tag("admin").exclude_name("Ban"))
+        .unwrap();
+    assert_eq!(
+        names(&admin),
+        ["BanRequest", "User", "UserId", "UserSummary"]
+    );
+
+    let requests = types
+        .select(
+            &Selection::new()
+                .tag("admin")
+                .exclude_name("Ban")
+                .exclude_tag("public"),
+        )
+        .unwrap_err();
+    assert!(matches!(requests, SelectionError::DependsOnExcluded { .. }));
+
+    let requests = types
+        .select(&Selection::new().name("BanRequest").exclude_name("Ban"))
+        .unwrap();
+    assert_eq!(names(&requests), ["BanRequest", "User", "UserId"]);
+}
@@ -557,7 +557,7 @@ fn option_children_split_for_deserialize() {
         .expect("PhasesFormat should split Option-bearing child types");
 
     assert!(rendered.contains(
-        "OptionParent_Deserialize = {\n\tflag?: boolean,\n\tchild: OptionChild_Deserialize,"
+        "OptionParent_Deserialize = {\n\t/** @default false */\n\tflag?: boolean,\n\tchild: OptionChild_Deserialize,"
     ));
     assert!(rendered.contains("OptionChild_Deserialize = {\n\tvalue?: number | null,"));
 }
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use specta::{
+    Type, Types,
+    datatype::{DataType, LiteralValue, Primitive},
+};
+use specta_csharp::CSharp;
+use specta_go::Go;
+use specta_kotlin::Kotlin;
+use specta_swift::Swift;
+use specta_typescript::Typescript;
+
+#[derive(Type, Serialize_repr, Deserialize_repr)]
+#[specta(collect = false, serde_repr)]
+#[repr(u8)]
+enum Priority {
+    Low = 1,
+    Medium,
+    High = 10,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+#[repr(i16)]
+enum Named {
+    Negative = -1,
+    Zero,
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Type)]
+#[specta(collect = false, serde_repr)]
+#[repr(u8)]
+enum DeriveLast {
+    A = 3,
+    B = 7,
+}
+
+// Derives listed after `Type` are visible to it, so `serde_repr` is detected without opting in.
+#[derive(Type)]
+#[specta(collect = false)]
+#[derive(Serialize_repr)]
+#[repr(u8)]
+enum SerializeOnly {
+    A,
+    B,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct Task {
+    priority: Priority,
+    named: Named,
+}
+
+fn types() -> Types {
+    Types::default().register::<Task>()
+}
+
+#[test]
+fn discriminants_are_recorded() {
+    let types = types();
+    for (name, expected, primitive) in [
+        (
+            "Priority",
+            [
+                LiteralValue::UInt(1),
+                LiteralValue::UInt(2),
+                LiteralValue::UInt(10),
+            ]
+            .as_slice(),
+            Primitive::u8,
+        ),
+        (
+            "Named",
+            [LiteralValue::Int(-1), LiteralValue::Int(0)].as_slice(),
+            Primitive::i16,
+        ),
+    ] {
+        let ndt = types
+            .into_sorted_iter()
+            .find(|ndt| ndt.name == name)
+            .unwrap();
+        let Some(DataType::Enum(enm)) = &ndt.ty else {
+            panic!("expected an enum");
+        };
+        let discriminants = enm
+            .variants
+            .iter()
+            .map(|(_, variant)| variant.attributes.discriminant().unwrap())
+            .collect::<Vec<_>>();
+        assert!(discriminants.iter().all(|d| d.primitive() == primitive));
+        assert_eq!(
+            discriminants.iter().map(|d| d.value()).collect::<Vec<_>>(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn serde_repr_exports_numeric_union() {
+    let output = Typescript::default()
+        .export(&types(), specta_serde::Format)
+        .unwrap();
+
+    assert!(
+        output.contains("export type Priority = 1 | 2 | 10;"),
+        "{output}"
+    );
+    // Without `serde_repr` the variant names are still serialized.
+    assert!(
+        output.contains(r#"export type Named = "Negative" | "Zero";"#),
+        "{output}"
+    );
+}
+
+#[test]
+fn serde_repr_opt_in_works_in_any_derive_order() {
+    let output = Typescript::default()
+        .export(
+            &Types::default().register::<DeriveLast>(),
+            specta_serde::Format,
+        )
+        .unwrap();
+
+    assert!(
+        output.contains("export type DeriveLast = 3 | 7;"),
+        "{output}"
+    );
+}
+
+#[test]
+fn exporters_render_numeric_enums() {
+    let types = types();
+
+    let swift = Swift::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        swift.contains("public enum Priority: UInt8, Codable {\n    case low = 1\n    case medium = 2\n    case high = 10\n}"),
+        "{swift}"
+    );
+
+    let kotlin = Kotlin::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        kotlin.contains("public enum class Priority(public val value: kotlin.UByte) {\n    Low(1u),\n    Medium(2u),\n    High(10u)\n}"),
+        "{kotlin}"
+    );
+
+    let csharp = CSharp::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        csharp.contains(
+            "public enum Priority : byte\n{\n    Low = 1,\n    Medium = 2,\n    High = 10,\n}"
+        ),
+        "{csharp}"
+    );
+
+    let go = Go::default().export(&types, specta_serde::Format).unwrap();
+    assert!(go.contains("type Priority uint8"), "{go}");
+    assert!(go.contains("PriorityHigh   Priority = 10"), "{go}");
+}
+
+#[test]
+fn serde_repr_in_one_phase_is_split() {
+    let types = Types::default().register::<SerializeOnly>();
+
+    assert!(
+        Typescript::default()
+            .export(&types, specta_serde::Format)
+            .is_err()
+    );
+
+    let output = Typescript::default()
+        .export(&types, specta_serde::PhasesFormat)
+        .unwrap();
+    assert!(
+        output.contains("export type SerializeOnly_Serialize = 0 | 1;"),
+        "{output}"
+    );
+    assert!(
+        output.contains(r#"export type SerializeOnly_Deserialize = "A" | "B";"#),
+        "{output}"
+    );
+}
@@ -0,0 +1,169 @@
+use specta::{Type, Types, datatype::NamedDataType};
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct UserId(u64);
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct User {
+    id: UserId,
+    friends: Vec<User>,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Post {
+    author: UserId,
+    comments: Vec<Comment>,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Comment {
+    post: Option<Box<Post>>,
+    body: String,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Page<T> {
+    items: Vec<T>,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Feed {
+    posts: Page<Post>,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Standalone {
+    value: String,
+}
+
+fn types() -> Types {
+    Types::default()
+        .register::<User>()
+        .register::<Feed>()
+        .register::<Standalone>()
+}
+
+fn find<'a>(types: &'a Types, name: &str) -> &'a NamedDataType {
+    types
+        .into_unsorted_iter()
+        .find(|ndt| ndt.name == name)
+        .unwrap()
+}
+
+fn names<'a>(types: impl IntoIterator<Item = &'a NamedDataType>) -> Vec<&'a str> {
+    types.into_iter().map(|ndt| ndt.name.as_ref()).collect()
+}
+
+#[test]
+fn dependencies_and_dependents() {
+    let types = types();
+    let graph = types.graph();
+
+    // `Vec`, `Box` and `String` are inlined, but are still part of the graph.
+    assert_eq!(
+        names(graph.types()),
+        [
+            "Box",
+            "Comment",
+            "Feed",
+            "Page",
+            "Post",
+            "Standalone",
+            "String",
+            "User",
+            "UserId",
+            "Vec"
+        ]
+    );
+    assert_eq!(
+        names(graph.dependencies(find(&types, "User"))),
+        ["User", "UserId", "Vec"]
+    );
+    assert_eq!(
+        names(graph.dependencies(find(&types, "Feed"))),
+        ["Page", "Post"]
+    );
+    assert_eq!(
+        names(graph.dependents(find(&types, "UserId"))),
+        ["Post", "User"]
+    );
+    assert!(
+        graph
+            .dependents(find(&types, "Standalone"))
+            .next()
+            .is_none()
+    );
+}
+
+#[test]
+fn topological_order_puts_dependencies_first() {
+    let types = types();
+    let order = names(types.graph().topological_order());
+    let position = |name| order.iter().position(|n| *n == name).unwrap();
+
+    assert_eq!(order.len(), 10);
+    assert!(position("Vec") < position("User"));
+    assert!(position("UserId") < position("User"));
+    assert!(position("UserId") < position("Post"));
+    assert!(position("Post") < position("Feed"));
+    assert!(position("Page") < position("Feed"));
+}
+
+#[test]
+fn strongly_connected_components_and_cycles() {
+    let types = types();
+    let graph = types.graph();
+
+    let components = graph.strongly_connected_components();
+    assert_eq!(components.len(), 9);
+    assert!(
+        components
+            .iter()
+            .any(|c| names(c.clone()) == ["Comment", "Post"])
+    );
+
+    assert_eq!(
+        graph.cycles().into_iter().map(names).collect::<Vec<_>>(),
+        [vec!["Comment", "Post"], vec!["User"]]
+    );
+
+    assert!(graph.is_recursive(find(&types, "User")));
+    assert!(graph.is_recursive(find(&types, "Comment")));
+    assert!(!graph.is_recursive(find(&types, "UserId")));
+    assert!(!graph.is_recursive(find(&types, "Feed")));
+}
+
+#[test]
+fn reachability_and_pruning() {
+    let types = types();
+    let graph = types.graph();
+
+    assert_eq!(
+        names(graph.reachable([find(&types, "Comment")])),
+        ["Comment", "Post", "String", "UserId", "Vec"]
+    );
+    assert_eq!(
+        names(graph.reachable([find(&types, "Standalone")])),
+        ["Standalone", "String"]
+    );
+
+    let pruned = types.clone().retain_reachable(|ndt| ndt.name == "Feed");
+    assert_eq!(
+        names(pruned.into_sorted_iter()),
+        ["Comment", "Feed", "Page", "Post", "String", "UserId", "Vec"]
+    );
+    assert_eq!(pruned.len(), 7);
+    // Only the `Feed` registration survives, `User` and `Standalone` were removed.
+    assert_eq!(pruned.roots().len(), 1);
+
+    let pruned = types.retain_reachable(|_| false);
+    assert!(pruned.is_empty());
+    assert_eq!(pruned.roots().len(), 0);
+}
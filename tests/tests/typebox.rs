@@ -0,0 +1,207 @@
+use std::{collections::HashMap, path::Path};
+
+use serde::{Deserialize, Serialize};
+use specta::{Type, Types};
+use specta_typebox::{Any, Layout, Never, TypeBox, Unknown, define, primitives};
+use tempfile::TempDir;
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Recursive {
+    children: Vec<Recursive>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+enum Shape {
+    Unit,
+    Newtype(String),
+    Tuple(i32, bool),
+    Named { radius: f64 },
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+#[serde(tag = "type")]
+enum Tagged {
+    A { value: u8 },
+    B,
+}
+
+#[derive(Type, Eq, Hash, PartialEq)]
+#[specta(collect = false)]
+enum Key {
+    First,
+    Second,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Generic<T = String> {
+    value: T,
+    keys: HashMap<Key, T>,
+    numbers: HashMap<i32, char>,
+    recursive: Recursive,
+    #[specta(optional)]
+    maybe: Option<Vec<u16>>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct Flattened {
+    id: String,
+    #[serde(flatten)]
+    shape: Shape,
+}
+
+mod nested {
+    use super::*;
+
+    #[derive(Type)]
+    pub struct Inner {
+        pub value: String,
+    }
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Outer {
+    inner: nested::Inner,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+#[derive(Type, Serialize, Deserialize)]
+#[specta(collect = false)]
+struct TupleDefault(u8, #[serde(default)] u8);
+
+fn export<T: Type>() -> Result<String, specta_typebox::Error> {
+    TypeBox::default().export(&Types::default().register::<T>(), specta_serde::Format)
+}
+
+#[test]
+fn typebox_export_smoke() {
+    let out = export::<Recursive>().unwrap();
+    assert!(out.starts_with(
+        "import { Type, type Static, type TSchema, type TUnsafe } from \"@sinclair/typebox\";"
+    ));
+    assert!(out.contains("export type Recursive = {"));
+    assert!(out.contains(
+        "export const RecursiveSchema: TUnsafe<Recursive> = { ...Type.Object({\n\tchildren: Type.Array(Type.Unsafe<Recursive>(Type.Ref(\"Recursive\"))),\n}), $id: \"Recursive\" };"
+    ));
+}
+
+#[test]
+fn typebox_datatypes() {
+    let types = Types::default()
+        .register::<Generic>()
+        .register::<Tagged>()
+        .register::<Flattened>();
+    let out = TypeBox::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    insta::assert_snapshot!("typebox-datatypes", out);
+
+    assert!(out.contains(
+        "export function GenericSchema<T extends TSchema>(T: T): TUnsafe<Generic<Static<T>>>;"
+    ));
+    assert!(out.contains("export function GenericSchema(T: TSchema = Type.String()): TSchema {"));
+    assert!(out.contains(r#"keys: Type.Partial(Type.Record(Type.Union([Type.Literal("First"), Type.Literal("Second")]), T)),"#));
+    assert!(out.contains(r#"numbers: Type.Record(Type.String({ pattern: "^-?\\d+$" }), "#));
+    assert!(out.contains("maybe: Type.Optional(Type.Union([Type.Array(Type.Integer({ minimum: 0, maximum: 65535 })), Type.Null()])),"));
+    assert!(out.contains("}, { additionalProperties: false })"));
+    assert!(out.contains("\ttype: Type.Literal(\"A\"),"));
+}
+
+#[test]
+fn typebox_layouts_qualify_references() {
+    let types = Types::default().register::<Outer>();
+
+    let namespaces = TypeBox::default()
+        .layout(Layout::Namespaces)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(namespaces.contains(r#"inner: Type.Unsafe<$s$.test.typebox.nested.Inner>(Type.Ref("test.typebox.nested.Inner")),"#));
+    assert!(namespaces.contains(r#"$id: "test.typebox.nested.Inner" };"#));
+
+    let prefixed = TypeBox::default()
+        .layout(Layout::ModulePrefixedName)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(prefixed.contains(
+        r#"inner: Type.Unsafe<test_typebox_nested_Inner>(Type.Ref("test_typebox_nested_Inner")),"#
+    ));
+
+    let dir = TempDir::new_in(temp_root()).unwrap();
+    TypeBox::default()
+        .layout(Layout::Files)
+        .export_to(dir.path(), &types, specta_serde::Format)
+        .unwrap();
+    let outer = std::fs::read_to_string(dir.path().join("test/typebox.ts")).unwrap();
+    assert!(outer.contains(r#"import * as test$typebox$nested from "./typebox/nested";"#));
+    assert!(outer.contains(
+        r#"Type.Unsafe<test$typebox$nested.Inner>(Type.Ref("test.typebox.nested.Inner"))"#
+    ));
+}
+
+#[test]
+fn typebox_unsupported_constructs() {
+    let err = export::<Tree<String>>().unwrap_err().to_string();
+    assert!(err.contains("TypeBox has no way to express recursive generic types"));
+
+    let err = TypeBox::default()
+        .export(
+            &Types::default().register::<TupleDefault>(),
+            specta_serde::PhasesFormat,
+        )
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("TypeBox has no way to express optional tuple elements"));
+
+    let mut types = Types::default();
+    let dt = u64::definition(&mut types);
+    let err = primitives::inline(&TypeBox::default(), &types, &dt)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("specta_typebox::define"));
+}
+
+#[test]
+fn typebox_define_raw_and_runtime() {
+    #[derive(Type)]
+    #[specta(collect = false)]
+    struct Wrappers {
+        any: Any,
+        unknown: Unknown,
+        never: Never,
+    }
+
+    let types = Types::default().register::<Wrappers>();
+    let out = TypeBox::default()
+        .header("// header")
+        .with_raw("export const raw = true;")
+        .framework_runtime(|mut exporter| {
+            let types = exporter.render_types()?;
+            let custom = exporter.inline(&define("Type.String({ format: \"uuid\" })").into())?;
+            Ok(format!("export const Id = {custom};\n{types}").into())
+        })
+        .export(&types, specta_serde::Format)
+        .unwrap();
+
+    assert!(out.starts_with("// header\n"));
+    assert!(out.contains("export const Id = Type.String({ format: \"uuid\" });"));
+    assert!(out.contains("export const raw = true;"));
+    assert!(out.contains("\tany: Type.Any(),\n\tunknown: Type.Unknown(),\n\tnever: Type.Never(),"));
+    assert_eq!(out.matches("export const WrappersSchema").count(), 1);
+}
+
+fn temp_root() -> std::path::PathBuf {
+    let temp_root = Path::new(env!("CARGO_MANIFEST_DIR")).join(".temp");
+    std::fs::create_dir_all(&temp_root).unwrap();
+    temp_root
+}
@@ -8,9 +8,11 @@ use std::{
 
 use specta::{
     Format, Type, Types,
-    datatype::{DataType, NamedDataType, Primitive, Reference},
+    datatype::{DataType, Field, NamedDataType, Primitive, Reference, Struct},
+};
+use specta_typescript::{
+    ErrorTraceFrame, Exporter, ImportExtension, Layout, Typescript, UnitEnumStyle, primitives,
 };
-use specta_typescript::{ErrorTraceFrame, Exporter, Layout, Typescript, primitives};
 use tempfile::TempDir;
 
 use crate::fs_to_string;
@@ -268,6 +270,104 @@ fn typescript_with_raw_exports_to_files_layout() {
     assert!(exported.contains("export const queryClient = {};"));
 }
 
+fn files_layout_types(legacy: bool) -> Types {
+    let mut types = Types::default();
+    let leaf = NamedDataType::new("Leaf", &mut types, |_, ndt| {
+        ndt.module_path = "api::models".into();
+        ndt.ty = Some(Primitive::str.into());
+    });
+    NamedDataType::new("Model", &mut types, |_, ndt| {
+        ndt.module_path = "api".into();
+        ndt.ty = Some(
+            Struct::named()
+                .field("leaf", Field::new(leaf.reference(Vec::new()).into()))
+                .build(),
+        );
+    });
+    NamedDataType::new("Root", &mut types, |_, ndt| {
+        ndt.module_path = "".into();
+        ndt.ty = Some(Primitive::bool.into());
+    });
+    if legacy {
+        NamedDataType::new("Old", &mut types, |_, ndt| {
+            ndt.module_path = "legacy".into();
+            ndt.ty = Some(Primitive::bool.into());
+        });
+    }
+    types
+}
+
+#[test]
+fn typescript_files_layout_import_extensions_and_barrels() {
+    let temp = Path::new(env!("CARGO_MANIFEST_DIR")).join(".temp");
+    std::fs::create_dir_all(&temp).unwrap();
+    let temp = TempDir::new_in(temp).unwrap();
+    let read = |path: &str| std::fs::read_to_string(temp.path().join(path)).unwrap();
+
+    Typescript::default()
+        .layout(Layout::Files)
+        .import_extension(ImportExtension::Js)
+        .barrels(true)
+        .export_to(temp.path(), &files_layout_types(true), IdentityFormat)
+        .unwrap();
+
+    assert!(
+        read("api.ts").contains("import type * as api$models from \"./api/models.js\";"),
+        "{}",
+        read("api.ts")
+    );
+    assert_eq!(
+        read("api/index.ts"),
+        "// This file has been generated by Specta. Do not edit this file manually.\nexport type * from \"../api.js\";\nexport type * as models from \"./models.js\";\n"
+    );
+    let index = read("index.ts");
+    assert!(index.contains("export type Root = boolean;"), "{index}");
+    assert!(
+        index.ends_with(
+            "export type * as api from \"./api/index.js\";\nexport type * as legacy from \"./legacy.js\";"
+        ),
+        "{index}"
+    );
+
+    // Dropping a module and the barrels removes their generated files.
+    Typescript::default()
+        .layout(Layout::Files)
+        .type_only_imports(false)
+        .export_to(temp.path(), &files_layout_types(false), IdentityFormat)
+        .unwrap();
+
+    assert!(
+        read("api.ts").contains("import * as api$models from \"./api/models\";"),
+        "{}",
+        read("api.ts")
+    );
+    assert!(!temp.path().join("legacy.ts").exists());
+    assert!(!temp.path().join("api/index.ts").exists());
+    assert!(!read("index.ts").contains("export type * as"));
+}
+
+#[test]
+fn typescript_files_layout_rejects_barrels_over_index_modules() {
+    let mut types = files_layout_types(false);
+    NamedDataType::new("Shadowed", &mut types, |_, ndt| {
+        ndt.module_path = "api::index".into();
+        ndt.ty = Some(Primitive::bool.into());
+    });
+    let temp = Path::new(env!("CARGO_MANIFEST_DIR")).join(".temp");
+    std::fs::create_dir_all(&temp).unwrap();
+    let temp = TempDir::new_in(temp).unwrap();
+
+    let error = Typescript::default()
+        .layout(Layout::Files)
+        .barrels(true)
+        .export_to(temp.path(), &types, IdentityFormat)
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("module named `index`"),
+        "{error}"
+    );
+}
+
 #[test]
 fn jsdoc_with_raw_exports_runtime() {
     let exported = specta_typescript::JSDoc::default()
@@ -278,6 +378,393 @@ fn jsdoc_with_raw_exports_runtime() {
     assert!(exported.contains("export const queryClient = {};"));
 }
 
+/// A unit-only enum to render in each [`UnitEnumStyle`].
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false)]
+#[serde(rename_all = "kebab-case")]
+enum Color {
+    /// The colour of the sky.
+    SkyBlue,
+    #[deprecated = "use `SkyBlue`"]
+    Red,
+}
+
+fn unit_enum_types() -> Types {
+    let mut types = Types::default();
+    let _ = Color::definition(&mut types);
+    types
+}
+
+#[test]
+fn typescript_unit_enum_styles() {
+    let export = |style| {
+        Typescript::default()
+            .unit_enum_style(style)
+            .export(&unit_enum_types(), specta_serde::Format)
+            .unwrap()
+    };
+
+    let output = export(UnitEnumStyle::Union);
+    assert!(output.contains("export type Color = \n"), "{output}");
+    assert!(!output.contains("export const Color"), "{output}");
+
+    let output = export(UnitEnumStyle::AsConst);
+    assert!(
+        output.contains(
+            "/**  A unit-only enum to render in each [`UnitEnumStyle`]. */\nexport const Color = Object.freeze({\n\t/**  The colour of the sky. */\n\t\"sky-blue\": \"sky-blue\",\n\t/**\n\t * @deprecated use `SkyBlue`\n\t */\n\tred: \"red\",\n} as const);\n\n/**  A unit-only enum to render in each [`UnitEnumStyle`]. */\nexport type Color = (typeof Color)[keyof typeof Color];\n"
+        ),
+        "{output}"
+    );
+
+    let output = export(UnitEnumStyle::Enum);
+    assert!(output.contains("export enum Color {\n"), "{output}");
+    assert!(
+        output.contains("\t\"sky-blue\" = \"sky-blue\",\n"),
+        "{output}"
+    );
+    assert!(output.contains("\tred = \"red\",\n}\n"), "{output}");
+    assert!(
+        export(UnitEnumStyle::ConstEnum).contains("export const enum Color {\n"),
+        "{}",
+        export(UnitEnumStyle::ConstEnum)
+    );
+}
+
+#[test]
+fn typescript_unit_enum_styles_across_layouts() {
+    let output = Typescript::default()
+        .layout(Layout::Namespaces)
+        .unit_enum_style(UnitEnumStyle::Enum)
+        .export(&unit_enum_types(), specta_serde::Format)
+        .unwrap();
+    assert!(output.contains("\t\texport enum Color {\n"), "{output}");
+
+    let temp = Path::new(env!("CARGO_MANIFEST_DIR")).join(".temp");
+    std::fs::create_dir_all(&temp).unwrap();
+    let temp = TempDir::new_in(temp).unwrap();
+    Typescript::default()
+        .layout(Layout::Files)
+        .unit_enum_style(UnitEnumStyle::AsConst)
+        .barrels(true)
+        .export_to(temp.path(), &unit_enum_types(), specta_serde::Format)
+        .unwrap();
+    let output = fs_to_string(temp.path()).unwrap();
+    assert!(
+        output.contains("export const Color = Object.freeze({"),
+        "{output}"
+    );
+    // Barrels must re-export the value as well as the type.
+    assert!(!output.contains("export type *"), "{output}");
+
+    let output = specta_typescript::JSDoc::default()
+        .unit_enum_style(UnitEnumStyle::Enum)
+        .export(&unit_enum_types(), specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains("* @typedef {(typeof Color)[keyof typeof Color]} Color\n"),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "export const Color = Object.freeze(/** @type {const} */ ({\n\t/**  The colour of the sky. */\n\t\"sky-blue\": \"sky-blue\",\n"
+        ),
+        "{output}"
+    );
+}
+
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+enum Shape<T> {
+    Circle { radius: T },
+    BigSquare(Square),
+    Empty,
+}
+
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false)]
+struct Square {
+    side: f64,
+}
+
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false)]
+#[serde(tag = "event-type", content = "data")]
+enum Event {
+    Opened,
+    Closed(String),
+}
+
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false)]
+enum OnlyA {
+    A,
+}
+
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false)]
+enum OnlyB {
+    B,
+}
+
+// Shaped like an internally tagged enum, but serde doesn't tag it.
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false)]
+#[serde(untagged)]
+enum LooksTagged {
+    A {
+        #[specta(inline)]
+        kind: OnlyA,
+    },
+    B {
+        #[specta(inline)]
+        kind: OnlyB,
+    },
+}
+
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false)]
+#[serde(tag = "kind")]
+enum WithFallback {
+    Known {
+        value: u8,
+    },
+    #[serde(untagged)]
+    Unknown {
+        kind: String,
+    },
+}
+
+#[test]
+fn typescript_tagged_union_helpers() {
+    let mut types = Types::default();
+    let _ = Shape::<f64>::definition(&mut types);
+    let _ = Event::definition(&mut types);
+    let _ = Color::definition(&mut types);
+    let _ = LooksTagged::definition(&mut types);
+    let _ = WithFallback::definition(&mut types);
+
+    let output = Typescript::default()
+        .tagged_union_helpers(true)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains(
+            "export function isShape_big_square<T>(value: Shape<T>): value is Extract<Shape<T>, { kind: \"big-square\" }> {\n\treturn value.kind === \"big-square\";\n}\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "export function matchShape<T, R>(value: Shape<T>, cases: {\n\tcircle: (value: Extract<Shape<T>, { kind: \"circle\" }>) => R;\n\t\"big-square\": (value: Extract<Shape<T>, { kind: \"big-square\" }>) => R;\n\tempty: (value: Extract<Shape<T>, { kind: \"empty\" }>) => R;\n}): R {\n\treturn (cases[value.kind] as (value: Shape<T>) => R)(value);\n}\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains("\treturn value[\"event-type\"] === \"Closed\";\n"),
+        "{output}"
+    );
+    // Externally tagged enums have no tag field to switch on.
+    assert!(!output.contains("matchColor"), "{output}");
+    // Helpers follow the representation serde tags the enum with, not its shape.
+    assert!(!output.contains("matchLooksTagged"), "{output}");
+    assert!(!output.contains("matchWithFallback"), "{output}");
+
+    let output = Typescript::default()
+        .tagged_union_helpers(true)
+        .export(&types, IdentityFormat)
+        .unwrap();
+    assert!(!output.contains("export function"), "{output}");
+
+    let output = specta_typescript::JSDoc::default()
+        .tagged_union_helpers(true)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains(
+            "/**\n * @template T\n * @param {Shape<T>} value\n * @returns {value is Extract<Shape<T>, { kind: \"circle\" }>}\n */\nexport function isShape_circle(value) {\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "export function matchEvent(value, cases) {\n\treturn /** @type {(value: Event) => R} */ (cases[value[\"event-type\"]])(value);\n}\n"
+        ),
+        "{output}"
+    );
+}
+
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false)]
+struct Snapshot {
+    tags: Vec<String>,
+    counts: HashMap<String, i32>,
+    range: (i32, i32),
+    draft: Draft,
+    events: Vec<Event>,
+}
+
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false)]
+struct Draft {
+    lines: Vec<String>,
+    #[serde(rename = "pinned")]
+    pinned_lines: Vec<String>,
+}
+
+#[test]
+fn typescript_readonly() {
+    let mut types = Types::default();
+    let _ = Snapshot::definition(&mut types);
+    types.iter_mut(|ndt| {
+        if let Some(DataType::Struct(draft)) = &mut ndt.ty
+            && ndt.name == "Draft"
+        {
+            draft.attributes.set_readonly(false);
+            if let specta::datatype::Fields::Named(fields) = &mut draft.fields {
+                fields.fields[1].1.attributes.set_readonly(true);
+            }
+        }
+    });
+
+    let output = Typescript::default()
+        .readonly(true)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains(
+            "export type Snapshot = {\n\treadonly tags: readonly string[],\n\treadonly counts: { readonly [key in string]: number },\n\treadonly range: readonly [number, number],\n\treadonly draft: Draft,\n\treadonly events: readonly Event[],\n};"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "export type Draft = {\n\tlines: string[],\n\treadonly pinned: readonly string[],\n};"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains("{ readonly \"event-type\": \"Closed\"; readonly data: string }"),
+        "{output}"
+    );
+
+    let output = Typescript::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains("\ttags: string[],\n\tcounts: { [key in string]: number },"),
+        "{output}"
+    );
+    assert!(
+        output.contains("\treadonly pinned: readonly string[],"),
+        "{output}"
+    );
+}
+
+#[derive(Type, serde::Serialize, serde::Deserialize)]
+#[specta(collect = false, readonly = false)]
+struct DerivedDraft {
+    lines: Vec<String>,
+    #[specta(readonly)]
+    pinned: Vec<String>,
+}
+
+#[test]
+fn typescript_readonly_attribute() {
+    let types = Types::default().register::<DerivedDraft>();
+
+    let output = Typescript::default()
+        .readonly(true)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains(
+            "export type DerivedDraft = {\n\tlines: string[],\n\treadonly pinned: readonly string[],\n};"
+        ),
+        "{output}"
+    );
+}
+
+#[test]
+fn typescript_source_locations() {
+    let mut types = Types::default();
+    let _ = Snapshot::definition(&mut types);
+    let see = |line: u32| format!("@see tests/tests/typescript.rs:{line}");
+
+    let output = Typescript::default()
+        .source_locations(true)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    let line = types
+        .into_unsorted_iter()
+        .find(|ndt| ndt.name == "Draft")
+        .unwrap()
+        .location
+        .line();
+    assert!(
+        output.contains(&format!("/** {} */\nexport type Draft = {{", see(line))),
+        "{output}"
+    );
+
+    let output = specta_typescript::JSDoc::default()
+        .source_locations(true)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains(&format!("\t* {}\n\t* @typedef {{{{\n", see(line))),
+        "{output}"
+    );
+
+    let temp = Path::new(env!("CARGO_MANIFEST_DIR")).join(".temp");
+    std::fs::create_dir_all(&temp).unwrap();
+    let temp = TempDir::new_in(temp).unwrap();
+    let path = temp.path().join("types.d.ts");
+    Typescript::default()
+        .declaration_maps("../..")
+        .export_to(&path, &types, specta_serde::Format)
+        .unwrap();
+    let output = std::fs::read_to_string(&path).unwrap();
+    assert!(!output.contains('\u{E000}'), "{output}");
+    assert!(
+        output.ends_with("};\n//# sourceMappingURL=types.d.ts.map\n"),
+        "{output}"
+    );
+    let map = std::fs::read_to_string(temp.path().join("types.d.ts.map")).unwrap();
+    assert!(
+        map.starts_with(
+            "{\"version\":3,\"file\":\"types.d.ts\",\"sourceRoot\":\"../..\",\"sources\":[\"tests/tests/typescript.rs\"],\"names\":[],\"mappings\":\";AA"
+        ),
+        "{map}"
+    );
+    // One declaration per type, so one segment per line with a mapping.
+    let mappings = map.rsplit_once(":\"").unwrap().1;
+    assert_eq!(
+        mappings.split([';', ',']).filter(|s| !s.is_empty()).count(),
+        3,
+        "{map}"
+    );
+
+    // A string export has no file to map, but must not leave markers behind.
+    let output = Typescript::default()
+        .declaration_maps("../..")
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(!output.contains('\u{E000}'), "{output}");
+
+    let root = temp.path().join("files");
+    specta_typescript::JSDoc::default()
+        .layout(Layout::Files)
+        .declaration_maps("../..")
+        .export_to(&root, &types, specta_serde::Format)
+        .unwrap();
+    let file = std::fs::read_to_string(root.join("test/typescript.js")).unwrap();
+    assert!(
+        file.ends_with("//# sourceMappingURL=typescript.js.map\n"),
+        "{file}"
+    );
+    assert!(root.join("test/typescript.js.map").exists());
+}
+
 #[test]
 fn typescript_export_serde_errors() {
     fn assert_serde_error<T: Type>(failures: &mut Vec<String>, name: &str, expected_error: &str) {
@@ -2147,3 +2147,98 @@ fn valibot_nested_errors_include_the_field_path() {
         "unexpected error: {err}"
     );
 }
+
+specta_typescript::branded!(pub struct AccountId(String) as "accountId");
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Account {
+    id: AccountId,
+    parent: Option<AccountId>,
+}
+
+#[test]
+fn valibot_branded_types() {
+    let types = Types::default().register::<Account>();
+
+    let output = Valibot::default()
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains(
+            "export type Account = {\n\tid: string & v.Brand<\"accountId\">,\n\tparent: string & v.Brand<\"accountId\"> | null,\n};"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains("\tid: v.pipe(v.pipe(v.string(), v.check("),
+        "{output}"
+    );
+    assert!(output.contains("), v.brand(\"accountId\")),\n"), "{output}");
+
+    let output = Valibot::default()
+        .branded_schema_impl(|_, branded| {
+            Ok(Cow::Owned(format!("v.literal(\"{}\")", branded.brand())))
+        })
+        .branded_type_impl(|_, branded| Ok(Cow::Owned(format!("\"{}\"", branded.brand()))))
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(output.contains("\tid: \"accountId\",\n"), "{output}");
+    assert!(
+        output.contains("\tid: v.literal(\"accountId\"),"),
+        "{output}"
+    );
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Event {
+    at: chrono::DateTime<chrono::Utc>,
+    on: Option<chrono::NaiveDate>,
+    links: Vec<url::Url>,
+    sequence: u64,
+}
+
+#[test]
+fn valibot_semantic_types() {
+    let types = Types::default().register::<Event>();
+
+    let output = Valibot::default()
+        .semantic(specta_valibot::semantic::Configuration::default().enable_lossless_bigints())
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains(
+            "export type Event = {\n\tat: Date,\n\ton: Date | null,\n\tlinks: URL[],\n\tsequence: bigint,\n};"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains("export const EventSchema: v.GenericSchema<unknown, Event> = "),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "\tat: v.pipe(v.string(), v.isoTimestamp(), v.transform((value) => new Date(value))),\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "\ton: v.nullable(v.pipe(v.string(), v.isoDate(), v.transform((value) => new Date(value)))),\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "\tlinks: v.array(v.pipe(v.string(), v.url(), v.transform((value) => new URL(value)))),\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "\tsequence: v.pipe(v.string(), v.regex(/^-?\\d+$/), v.transform((value) => BigInt(value))),\n"
+        ),
+        "{output}"
+    );
+}
@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+
+use specta::{
+    Type, Types,
+    datatype::{
+        DataType, Fold, Generic, NamedDataType, PathSegment, Primitive, Visit, VisitContext,
+        VisitMut,
+    },
+};
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Tree {
+    value: u64,
+    children: Vec<Tree>,
+    metadata: Option<BTreeMap<String, Leaf>>,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Leaf {
+    id: u64,
+    tags: (String, i32),
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+enum Event {
+    Created { id: u64 },
+    Renamed(String, String),
+    Deleted,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct UsesWrapper {
+    wrapped: Wrapper<u64>,
+}
+
+#[derive(Default)]
+struct Paths {
+    primitives: Vec<(String, Primitive)>,
+    named: Vec<String>,
+    generics: Vec<String>,
+}
+
+impl<'a> Visit<'a> for Paths {
+    fn visit_primitive(&mut self, primitive: &'a Primitive, cx: &mut VisitContext<'a>) {
+        self.primitives.push((cx.path_string(), primitive.clone()));
+    }
+
+    fn visit_named_data_type(&mut self, ndt: &'a NamedDataType, cx: &mut VisitContext<'a>) {
+        self.named.push(ndt.name.to_string());
+        specta::datatype::visit::visit_named_data_type(self, ndt, cx);
+    }
+
+    fn visit_generic(&mut self, generic: &'a Generic, cx: &mut VisitContext<'a>) {
+        self.generics.push(cx.path_string());
+    }
+}
+
+fn definition<T: Type>(types: &mut Types) -> DataType {
+    T::definition(types)
+}
+
+#[test]
+fn visit_without_resolving_stays_in_the_tree() {
+    let mut types = Types::default();
+    let dt = definition::<Tree>(&mut types);
+
+    let mut paths = Paths::default();
+    paths.visit_datatype(&dt, &mut VisitContext::new());
+
+    assert!(paths.primitives.is_empty());
+    assert!(paths.named.is_empty());
+}
+
+#[test]
+fn visit_resolves_named_references_with_cycle_protection() {
+    let mut types = Types::default();
+    let dt = definition::<Tree>(&mut types);
+
+    let mut paths = Paths::default();
+    paths.visit_datatype(&dt, &mut VisitContext::resolving(&types));
+
+    // `Tree` is recursive through `children`, but is only entered once per path.
+    assert_eq!(paths.named, ["Tree", "Leaf"]);
+    assert_eq!(
+        paths
+            .primitives
+            .iter()
+            .map(|(path, primitive)| format!("{path}: {primitive:?}"))
+            .collect::<Vec<_>>(),
+        [
+            "Tree.value: u64",
+            "Tree.metadata.?.<key>: str",
+            "Tree.metadata.?.<value>.Leaf.id: u64",
+            "Tree.metadata.?.<value>.Leaf.tags.0: str",
+            "Tree.metadata.?.<value>.Leaf.tags.1: i32",
+        ]
+    );
+}
+
+#[test]
+fn visit_paths_through_variants_and_generic_arguments() {
+    let mut types = Types::default();
+    let dt = definition::<Event>(&mut types);
+    let mut paths = Paths::default();
+    paths.visit_datatype(&dt, &mut VisitContext::resolving(&types));
+    assert_eq!(
+        paths
+            .primitives
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>(),
+        ["Event.Created.id", "Event.Renamed.0", "Event.Renamed.1"]
+    );
+
+    let mut types = Types::default();
+    let dt = definition::<UsesWrapper>(&mut types);
+    let mut paths = Paths::default();
+    paths.visit_datatype(&dt, &mut VisitContext::resolving(&types));
+    assert_eq!(paths.named, ["UsesWrapper", "Wrapper"]);
+    assert_eq!(paths.primitives[0].0, "UsesWrapper.wrapped.<T>");
+    assert_eq!(paths.generics, ["UsesWrapper.wrapped.Wrapper.inner"]);
+}
+
+#[test]
+fn visit_mut_rewrites_in_place() {
+    struct Widen;
+
+    impl VisitMut for Widen {
+        fn visit_primitive_mut(&mut self, primitive: &mut Primitive, cx: &mut VisitContext<'_>) {
+            if *primitive == Primitive::i32 {
+                *primitive = Primitive::i64;
+            }
+        }
+    }
+
+    let mut types = Types::default();
+    let mut dt = definition::<Leaf>(&mut types);
+    types.iter_mut(|ndt| Widen.visit_named_data_type_mut(ndt, &mut VisitContext::new()));
+
+    let leaf = types
+        .into_unsorted_iter()
+        .find(|ndt| ndt.name == "Leaf")
+        .unwrap();
+    let mut paths = Paths::default();
+    paths.visit_named_data_type(leaf, &mut VisitContext::new());
+    assert_eq!(paths.primitives[2], ("tags.1".into(), Primitive::i64));
+
+    // Named references are not followed, so the reference itself is unchanged.
+    let before = dt.clone();
+    Widen.visit_datatype_mut(&mut dt, &mut VisitContext::resolving(&types));
+    assert_eq!(dt, before);
+}
+
+#[test]
+fn fold_replaces_nodes_by_value() {
+    struct Nullable;
+
+    impl Fold for Nullable {
+        fn fold_field(
+            &mut self,
+            mut field: specta::datatype::Field,
+            cx: &mut VisitContext<'_>,
+        ) -> specta::datatype::Field {
+            assert!(matches!(cx.path().last(), Some(PathSegment::Field(_))));
+            field.ty = field.ty.map(|ty| DataType::Nullable(Box::new(ty)));
+            field
+        }
+    }
+
+    let mut types = Types::default();
+    definition::<Leaf>(&mut types);
+    let types = types.map(|ndt| Nullable.fold_named_data_type(ndt, &mut VisitContext::new()));
+    let leaf = types
+        .into_unsorted_iter()
+        .find(|ndt| ndt.name == "Leaf")
+        .unwrap();
+
+    let mut paths = Paths::default();
+    paths.visit_named_data_type(leaf, &mut VisitContext::new());
+    assert_eq!(
+        paths
+            .primitives
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>(),
+        ["id.?", "tags.?.0", "tags.?.1"]
+    );
+}
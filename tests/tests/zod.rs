@@ -7,7 +7,7 @@ use specta::{
 };
 use specta_typescript::Typescript;
 use specta_util::Remapper;
-use specta_zod::{Any, Layout, Never, Unknown, Zod, define, primitives};
+use specta_zod::{Any, Layout, Never, Target, Unknown, Zod, define, primitives};
 use tempfile::TempDir;
 
 macro_rules! for_bigint_types {
@@ -1532,3 +1532,200 @@ fn zod_nested_errors_include_the_field_path() {
         "unexpected error: {err}"
     );
 }
+
+specta_typescript::branded!(pub struct AccountId(String) as "accountId");
+specta_typescript::branded!(pub struct Id<T>(T) as "id");
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Account {
+    id: AccountId,
+    previous: Vec<AccountId>,
+    parent: Id<Option<String>>,
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Keyed<T> {
+    id: Id<T>,
+}
+
+#[test]
+fn zod_branded_types() {
+    let types = Types::default()
+        .register::<Account>()
+        .register::<Keyed<u8>>();
+
+    let output = Zod::default().export(&types, specta_serde::Format).unwrap();
+    assert!(
+        output.contains(
+            "export type Account = {\n\tid: string & z.$brand<\"accountId\">,\n\tprevious: (string & z.$brand<\"accountId\">)[],\n\tparent: (string | null) & z.$brand<\"id\">,\n};"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "\tid: z.string().brand<\"accountId\">(),\n\tprevious: z.array(z.string().brand<\"accountId\">()),\n\tparent: z.string().nullable().brand<\"id\">(),"
+        ),
+        "{output}"
+    );
+    assert!(output.contains("\tid: T & z.$brand<\"id\">,"), "{output}");
+    assert!(output.contains("\tid: T.brand<\"id\">(),"), "{output}");
+
+    let output = Zod::default()
+        .branded_schema_impl(|ctx, branded| {
+            Ok(Cow::Owned(format!(
+                "{}.describe(\"{}\")",
+                ctx.inline(branded.ty())?,
+                branded.brand()
+            )))
+        })
+        .branded_type_impl(|ctx, branded| Ok(Cow::Owned(ctx.reference(branded.ty())?)))
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(output.contains("\tid: string,\n"), "{output}");
+    assert!(
+        output.contains("\tid: z.string().describe(\"accountId\"),"),
+        "{output}"
+    );
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Website(String);
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct Event {
+    at: chrono::DateTime<chrono::Utc>,
+    on: Option<chrono::NaiveDate>,
+    links: Vec<url::Url>,
+    site: Website,
+    sequence: u64,
+}
+
+#[test]
+fn zod_semantic_types() {
+    let types = Types::default().register::<Event>();
+
+    let output = Zod::default()
+        .semantic(
+            specta_zod::semantic::Configuration::default()
+                .define::<Website>("z.url().transform((value) => new URL(value))", "URL")
+                .enable_lossless_bigints(),
+        )
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(
+        output.contains(
+            "export type Event = {\n\tat: Date,\n\ton: Date | null,\n\tlinks: URL[],\n\tsite: URL,\n\tsequence: bigint,\n};"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "\tat: z.codec(z.iso.datetime({ offset: true }), z.date(), { decode: (value) => new Date(value), encode: (value) => value.toISOString() }),\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "\ton: z.codec(z.iso.date(), z.date(), { decode: (value) => new Date(value), encode: (value) => value.toISOString().slice(0, 10) }).nullable(),\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains("\tlinks: z.array(z.codec(z.url(), z.instanceof(URL), {"),
+        "{output}"
+    );
+    assert!(
+        output.contains("\tsite: z.url().transform((value) => new URL(value)),\n"),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "\tsequence: z.codec(z.string().regex(/^-?\\d+$/), z.bigint(), { decode: (value) => BigInt(value), encode: (value) => value.toString() }),\n"
+        ),
+        "{output}"
+    );
+
+    let err = Zod::default()
+        .semantic(specta_zod::semantic::Configuration::empty())
+        .export(&types, specta_serde::Format)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Event.sequence"), "unexpected error: {err}");
+}
+
+#[derive(Type)]
+#[specta(collect = false)]
+struct ZodOptionalTarget {
+    #[specta(optional)]
+    value: Option<String>,
+}
+
+#[test]
+#[allow(deprecated)]
+fn zod_targets() {
+    let types = Types::default()
+        .register::<Recursive>()
+        .register::<ZodWireTypes>()
+        .register::<ZodCoverage>()
+        .register::<ZodOptionalFlatten>();
+
+    let mini = Zod::default()
+        .target(Target::Mini)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(mini.starts_with("import { z } from \"zod/mini\";"));
+    assert!(mini.contains("get children() { return z.array(RecursiveSchema); },"));
+    assert!(mini.contains("RecursiveSchema: z.ZodMiniType<Recursive>"));
+    assert!(mini.contains("<T extends z.ZodMiniType>"));
+    assert!(mini.contains("optional: z.nullable(z.boolean()),"));
+    assert!(mini.contains("z.int().check(z.gte(0), z.lte(65535))"));
+    assert!(mini.contains("z.string().check(z.refine((value) => [...value].length === 1))"));
+    assert!(mini.contains("inner: z.optional(z.never()),"));
+    assert!(mini.contains("z.intersection(z.object({"));
+    assert!(!mini.contains(".nullable()") && !mini.contains(".optional()"));
+
+    let zod3 = Zod::default()
+        .target(Target::Zod3)
+        .export(&types, specta_serde::Format)
+        .unwrap();
+    assert!(zod3.starts_with("import { z } from \"zod\";"));
+    assert!(zod3.contains("children: z.array(z.lazy(() => RecursiveSchema)),"));
+    assert!(zod3.contains("z.number().int().min(0).max(65535)"));
+    assert!(zod3.contains("boolean_keys: z.record(z.enum([\"true\", \"false\"]), z.string()),"));
+    assert!(!zod3.contains("partialRecord") && !zod3.contains("z.int()"));
+
+    let err = Zod::default()
+        .target(Target::Zod3)
+        .export(
+            &Types::default().register::<ZodTupleDefault>(),
+            specta_serde::PhasesFormat,
+        )
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Zod 3 has no way to express optional tuple elements")
+    );
+
+    let optional = Types::default().register::<ZodOptionalTarget>();
+    let err = Zod::default()
+        .target(Target::Zod3)
+        .optional_style(specta_zod::OptionalStyle::ExactOptional)
+        .export(&optional, specta_serde::Format)
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Zod 3 has no way to express exact optional properties")
+    );
+    assert!(
+        Zod::default()
+            .target(Target::Mini)
+            .optional_style(specta_zod::OptionalStyle::ExactOptional)
+            .export(&optional, specta_serde::Format)
+            .unwrap()
+            .contains("value: z.exactOptional(z.nullable(z.string())),")
+    );
+}
@@ -0,0 +1,143 @@
+import { expect, test } from "bun:test";
+import { Type, type Static, type TSchema } from "@sinclair/typebox";
+import { TypeCompiler } from "@sinclair/typebox/compiler";
+import { Value } from "@sinclair/typebox/value";
+import * as bindings from "./generated/bindings";
+import {
+  AdjacentEnumSchema,
+  ExternalEnumSchema,
+  FlattenedSchema,
+  GenericSchema,
+  InternalEnumSchema,
+  LinkedNodeSchema,
+  MutualASchema,
+  RecursiveSchema,
+  ReferencesSchema,
+  UntaggedReferencesSchema,
+  WireTypesSchema,
+  type LinkedNode,
+  type References,
+} from "./generated/bindings";
+import * as files from "./generated/files/typebox_typecheck";
+import * as filesNested from "./generated/files/typebox_typecheck/nested";
+import * as prefixed from "./generated/module-prefixed";
+import { typebox_typecheck as namespaced } from "./generated/namespaces";
+
+// Collects every schema with an `$id`, which `Type.Ref` resolves against.
+const schemas = (...modules: object[]): TSchema[] =>
+  modules.flatMap((module) =>
+    Object.values(module).flatMap((value): TSchema[] =>
+      typeof value !== "object" || value === null
+        ? []
+        : "$id" in value
+          ? [value as TSchema]
+          : schemas(value),
+    ),
+  );
+
+const references = schemas(bindings);
+const check = (schema: TSchema, value: unknown, refs = references) =>
+  Value.Check(schema, refs.filter((reference) => reference !== schema), value);
+
+const linked: Static<typeof LinkedNodeSchema> = { value: 1, next: { value: 2, next: null } };
+const linkedType: LinkedNode = linked;
+void linkedType;
+// @ts-expect-error References resolve to the exported TypeScript type.
+const invalidLinked: Static<typeof LinkedNodeSchema> = { value: 1 };
+void invalidLinked;
+
+const NumberGenericSchema = GenericSchema(Type.Number());
+const numberGeneric: Static<typeof NumberGenericSchema> = { value: 1, items: [2] };
+void numberGeneric;
+// @ts-expect-error Generic schemas infer from their schema arguments.
+const invalidNumberGeneric: Static<typeof NumberGenericSchema> = { value: "1", items: [] };
+void invalidNumberGeneric;
+
+const validReferences: References = {
+  recursive: { children: [{ children: [] }] },
+  optional: null,
+  list: [linked],
+  generic: { value: { children: [] }, items: [] },
+  nested: { value: "inner" },
+};
+
+test("every exported schema has a unique `$id`", () => {
+  const ids = references.map((schema) => schema.$id);
+  expect(new Set(ids).size).toBe(ids.length);
+  expect(RecursiveSchema.$id).toBe("Recursive");
+  expect(namespaced.RecursiveSchema.$id).toBe("typebox_typecheck.Recursive");
+  expect(prefixed.typebox_typecheck_RecursiveSchema.$id).toBe("typebox_typecheck_Recursive");
+});
+
+test("references resolve recursively", () => {
+  expect(check(RecursiveSchema, { children: [{ children: [{ children: [] }] }] })).toBe(true);
+  expect(check(RecursiveSchema, { children: [{ children: [{}] }] })).toBe(false);
+  expect(check(LinkedNodeSchema, linked)).toBe(true);
+  expect(check(LinkedNodeSchema, { value: 1, next: { value: 256, next: null } })).toBe(false);
+  expect(check(MutualASchema, { b: { a: [{ b: null }] } })).toBe(true);
+  expect(check(MutualASchema, { b: { a: [{ b: { a: [{}] } }] } })).toBe(false);
+  expect(check(UntaggedReferencesSchema, { children: [] })).toBe(true);
+  expect(check(UntaggedReferencesSchema, { value: 1, next: null })).toBe(true);
+  expect(check(UntaggedReferencesSchema, { value: 1 })).toBe(false);
+  expect(check(ReferencesSchema, validReferences)).toBe(true);
+  expect(check(ReferencesSchema, { ...validReferences, optional: { children: [] } })).toBe(true);
+  expect(check(ReferencesSchema, { ...validReferences, generic: { value: 1, items: [] } })).toBe(
+    false,
+  );
+  expect(check(ReferencesSchema, { ...validReferences, nested: {} })).toBe(false);
+});
+
+test("compiled schemas resolve references", () => {
+  const compiled = TypeCompiler.Compile(
+    ReferencesSchema,
+    references.filter((reference) => reference !== ReferencesSchema),
+  );
+  expect(compiled.Check(validReferences)).toBe(true);
+  expect(compiled.Check({ ...validReferences, list: [{ value: 1, next: { value: -1, next: null } }] })).toBe(
+    false,
+  );
+});
+
+test("generated schemas validate representative wire values", () => {
+  expect(check(ExternalEnumSchema, "Unit")).toBe(true);
+  expect(check(ExternalEnumSchema, { Tuple: [1, true] })).toBe(true);
+  expect(check(ExternalEnumSchema, { Named: { radius: null } })).toBe(true);
+  expect(check(ExternalEnumSchema, { Newtype: "value", Tuple: [1, true] })).toBe(false);
+  expect(check(InternalEnumSchema, { type: "A", value: 1 })).toBe(true);
+  expect(check(InternalEnumSchema, { type: "A" })).toBe(false);
+  expect(check(AdjacentEnumSchema, { t: "Node", c: linked })).toBe(true);
+  expect(check(AdjacentEnumSchema, { t: "Node", c: 1 })).toBe(false);
+  expect(check(FlattenedSchema, { id: "id", Newtype: "value" })).toBe(true);
+  expect(check(FlattenedSchema, { id: "id", Newtype: "value", extra: true })).toBe(false);
+  expect(
+    check(WireTypesSchema, {
+      character: "😀",
+      floating: null,
+      fixed_array: [1, 2],
+      tuple: ["value", true],
+      integer_keys: { "-2": "value" },
+      enum_keys: { First: "value" },
+    }),
+  ).toBe(true);
+  expect(
+    check(WireTypesSchema, {
+      character: "ab",
+      floating: 1,
+      fixed_array: [1, 2],
+      tuple: ["value", true],
+      integer_keys: {},
+      enum_keys: {},
+    }),
+  ).toBe(false);
+});
+
+test("other layouts resolve references by their qualified `$id`", () => {
+  expect(check(namespaced.ReferencesSchema, validReferences, schemas(namespaced))).toBe(true);
+  expect(
+    check(prefixed.typebox_typecheck_ReferencesSchema, validReferences, schemas(prefixed)),
+  ).toBe(true);
+  expect(check(files.ReferencesSchema, validReferences, schemas(files, filesNested))).toBe(true);
+  expect(
+    check(files.ReferencesSchema, { ...validReferences, nested: {} }, schemas(files, filesNested)),
+  ).toBe(false);
+});
@@ -12,6 +12,8 @@ import {
   UntaggedMatchingFieldSchema,
   WireTypesSchema,
 } from "./generated/bindings";
+import * as Mini from "./generated/bindings-mini";
+import * as Zod3 from "./generated/bindings-zod3";
 
 // Explicit schema type arguments must always be backed by runtime schema arguments.
 // @ts-expect-error A custom generic cannot silently retain the default string schema.
@@ -84,3 +86,23 @@ test("generated schemas reject invalid primitive wire values", () => {
     remote_keys: {},
   }).success).toBe(false);
 });
+
+test("every Zod target validates the same wire values", () => {
+  for (const bindings of [Zod3, Mini]) {
+    expect(bindings.RecursiveSchema.safeParse({ children: [{ children: [] }] }).success).toBe(true);
+    expect(bindings.RecursiveSchema.safeParse({ children: [{}] }).success).toBe(false);
+    expect(bindings.GenericSchema().safeParse({ first: "one", second: "two" }).success).toBe(true);
+    expect(bindings.OptionalFlattenSchema.safeParse({ id: "one" }).success).toBe(true);
+    expect(bindings.WireTypesSchema.safeParse({
+      character: "x",
+      integer_keys: { "2147483648": "out of range" },
+      boolean_keys: {},
+      newtype_keys: {},
+      boolean_newtype_keys: {},
+      enum_keys: {},
+      generic_finite_keys: {},
+      nested_generic_finite_keys: {},
+      remote_keys: {},
+    }).success).toBe(false);
+  }
+});